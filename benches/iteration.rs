@@ -0,0 +1,45 @@
+//! Times iterating over schedules whose fire times are dense, sparse or only
+//! found after a long search.
+//!
+//! Run with `cargo bench`. Each case reports the mean time per fire time over
+//! a fixed number of iterations; compare the output before and after changes
+//! to the search.
+
+use std::{
+    hint::black_box,
+    str::FromStr,
+    time::{Duration, Instant},
+};
+
+use jiff::{civil::date, tz::TimeZone};
+use jiff_cron::Schedule;
+
+const ITERATIONS: u32 = 2_000;
+
+fn main() {
+    let time_zone = TimeZone::get("America/New_York").unwrap_or(TimeZone::UTC);
+    let after = date(2024, 12, 30)
+        .at(23, 59, 30, 0)
+        .to_zoned(time_zone)
+        .unwrap();
+
+    for (name, expression) in [
+        ("every second", "* * * * * *"),
+        ("every 15 minutes on weekdays", "0 */15 9-17 * * Mon-Fri"),
+        ("leap days", "0 0 0 29 Feb *"),
+        ("new year's eve on a Friday", "59 59 23 31 Dec Fri"),
+    ] {
+        let schedule = Schedule::from_str(expression).unwrap();
+        let next = time_per_fire(|| schedule.after(&after).take(16).count(), 16);
+        let prev = time_per_fire(|| schedule.after(&after).rev().take(16).count(), 16);
+        println!("{name:<30} next: {next:>10.2?} prev: {prev:>10.2?}");
+    }
+}
+
+fn time_per_fire(mut f: impl FnMut() -> usize, fires: u32) -> Duration {
+    let start = Instant::now();
+    for _ in 0..ITERATIONS {
+        black_box(f());
+    }
+    start.elapsed() / (ITERATIONS * fires)
+}
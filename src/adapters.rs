@@ -0,0 +1,523 @@
+use jiff::{civil::Date, Span, ToSpan, Zoned};
+
+/// Adapters for iterators over fire times, such as [`ScheduleIterator`] and
+/// [`OwnedScheduleIterator`], which reshape the fire times of a schedule, or
+/// of several merged together, without writing another expression.
+///
+/// Spans are added to fire times in their own time zone, so a span of days
+/// stays on the same civil time across DST transitions.
+///
+/// [`ScheduleIterator`]: crate::ScheduleIterator
+/// [`OwnedScheduleIterator`]: crate::OwnedScheduleIterator
+pub trait FireTimes: Iterator<Item = Zoned> + Sized {
+    /// Collapses each burst of fire times, in which every fire time follows
+    /// the previous one by less than `span`, into the burst's first fire
+    /// time.
+    ///
+    /// A schedule firing more often than `span` for good forms a single burst
+    /// which never ends, so the iterator never returns after its first fire
+    /// time.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use std::str::FromStr;
+    ///
+    /// use jiff_cron::{
+    ///     jiff::{civil::date, tz::TimeZone, ToSpan},
+    ///     FireTimes, Schedule,
+    /// };
+    ///
+    /// let schedule = Schedule::from_str("0 0,5,10,30,35 9 * * *").unwrap();
+    /// let after = date(2025, 1, 1)
+    ///     .at(0, 0, 0, 0)
+    ///     .to_zoned(TimeZone::UTC)
+    ///     .unwrap();
+    /// let minutes: Vec<_> = schedule
+    ///     .after(&after)
+    ///     .coalesce_within(10.minutes())
+    ///     .take(3)
+    ///     .map(|fire| fire.minute())
+    ///     .collect();
+    /// assert_eq!(vec![0, 30, 0], minutes);
+    /// ```
+    fn coalesce_within(self, span: Span) -> CoalesceWithin<Self> {
+        CoalesceWithin {
+            iter: self,
+            span,
+            last: None,
+        }
+    }
+
+    /// Shifts each fire time by `span`, which may be negative, e.g. to run a
+    /// dependent job five minutes after each fire time of another schedule.
+    ///
+    /// Hours and smaller units shift fire times by an exact duration, while
+    /// days and larger units shift them by civil days, keeping their time of
+    /// day across DST transitions. Fire times which can't be shifted without
+    /// leaving the supported range are dropped.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use std::str::FromStr;
+    ///
+    /// use jiff_cron::{
+    ///     jiff::{civil::date, tz::TimeZone, ToSpan},
+    ///     FireTimes, Schedule,
+    /// };
+    ///
+    /// let schedule = Schedule::from_str("0 0 2 * * *").unwrap();
+    /// let after = date(2025, 1, 1)
+    ///     .at(0, 0, 0, 0)
+    ///     .to_zoned(TimeZone::UTC)
+    ///     .unwrap();
+    /// let mut trailing = schedule.after(&after).offset_by(5.minutes());
+    /// assert_eq!(
+    ///     date(2025, 1, 1).at(2, 5, 0, 0),
+    ///     trailing.next().unwrap().datetime()
+    /// );
+    /// let mut leading = schedule.after(&after).offset_by(-30.minutes());
+    /// assert_eq!(
+    ///     date(2025, 1, 1).at(1, 30, 0, 0),
+    ///     leading.next().unwrap().datetime()
+    /// );
+    /// ```
+    fn offset_by(self, span: Span) -> OffsetBy<Self> {
+        OffsetBy { iter: self, span }
+    }
+
+    /// Drops each fire time which follows the last one kept by less than
+    /// `span`, e.g. to cap the rate of a noisy union of schedules.
+    ///
+    /// Unlike [`FireTimes::coalesce_within`], a fire time which is dropped
+    /// doesn't extend the window, so fire times are kept at least every
+    /// `span` however densely the schedule fires.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use std::str::FromStr;
+    ///
+    /// use jiff_cron::{
+    ///     jiff::{civil::date, tz::TimeZone, ToSpan},
+    ///     FireTimes, Schedule,
+    /// };
+    ///
+    /// let schedule = Schedule::from_str("0 */10 9 * * *").unwrap();
+    /// let after = date(2025, 1, 1)
+    ///     .at(0, 0, 0, 0)
+    ///     .to_zoned(TimeZone::UTC)
+    ///     .unwrap();
+    /// let minutes: Vec<_> = schedule
+    ///     .after(&after)
+    ///     .at_most_every(25.minutes())
+    ///     .take(3)
+    ///     .map(|fire| fire.minute())
+    ///     .collect();
+    /// assert_eq!(vec![0, 30, 0], minutes);
+    /// ```
+    fn at_most_every(self, span: Span) -> AtMostEvery<Self> {
+        AtMostEvery {
+            iter: self,
+            span,
+            last: None,
+        }
+    }
+
+    /// Keeps the fire times in every `n`th week, counting weeks from Monday
+    /// to Sunday, starting with the week `anchor` is in, e.g. every second
+    /// Tuesday with a schedule firing every Tuesday and `n` of 2.
+    ///
+    /// Weeks are counted continuously from the anchor rather than restarting
+    /// each year, so the pattern carries on unchanged across new years, even
+    /// when a year has 53 weeks.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `n` is zero.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use std::str::FromStr;
+    ///
+    /// use jiff_cron::{
+    ///     jiff::{civil::date, tz::TimeZone},
+    ///     FireTimes, Schedule,
+    /// };
+    ///
+    /// let schedule = Schedule::from_str("0 0 9 * * Tue").unwrap();
+    /// let after = date(2025, 12, 1)
+    ///     .at(0, 0, 0, 0)
+    ///     .to_zoned(TimeZone::UTC)
+    ///     .unwrap();
+    /// let dates: Vec<_> = schedule
+    ///     .after(&after)
+    ///     .every_nth_week(2, date(2025, 12, 1))
+    ///     .take(3)
+    ///     .map(|fire| fire.date())
+    ///     .collect();
+    /// assert_eq!(
+    ///     vec![date(2025, 12, 2), date(2025, 12, 16), date(2025, 12, 30)],
+    ///     dates
+    /// );
+    /// ```
+    fn every_nth_week(self, n: u32, anchor: Date) -> EveryNthWeek<Self> {
+        assert!(n > 0, "n must be at least 1");
+        EveryNthWeek {
+            iter: self,
+            n: i64::from(n),
+            anchor: start_of_week(anchor),
+        }
+    }
+
+    /// Ends the iterator at its first fire time at or after `end`, which is
+    /// excluded, e.g. to take the fire times until midnight.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use std::str::FromStr;
+    ///
+    /// use jiff_cron::{
+    ///     jiff::{civil::date, tz::TimeZone},
+    ///     FireTimes, Schedule,
+    /// };
+    ///
+    /// let schedule = Schedule::from_str("0 0 */6 * * *").unwrap();
+    /// let after = date(2025, 1, 1)
+    ///     .at(0, 0, 0, 0)
+    ///     .to_zoned(TimeZone::UTC)
+    ///     .unwrap();
+    /// let end = date(2025, 1, 2)
+    ///     .at(0, 0, 0, 0)
+    ///     .to_zoned(TimeZone::UTC)
+    ///     .unwrap();
+    /// let hours: Vec<_> = schedule
+    ///     .after(&after)
+    ///     .take_until(&end)
+    ///     .map(|fire| fire.hour())
+    ///     .collect();
+    /// assert_eq!(vec![6, 12, 18], hours);
+    /// ```
+    fn take_until(self, end: &Zoned) -> TakeUntil<Self> {
+        TakeUntil {
+            iter: self,
+            end: Some(end.clone()),
+        }
+    }
+
+    /// Ends the iterator at its first fire time at or after `span` from
+    /// `start`, e.g. to take the fire times in the next 24 hours. The span is
+    /// added in the time zone of `start`. The iterator ends immediately if
+    /// the end can't be computed.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use std::str::FromStr;
+    ///
+    /// use jiff_cron::{
+    ///     jiff::{civil::date, tz::TimeZone, ToSpan},
+    ///     FireTimes, Schedule,
+    /// };
+    ///
+    /// let schedule = Schedule::from_str("0 0 * * * *").unwrap();
+    /// let after = date(2025, 3, 9)
+    ///     .at(0, 0, 0, 0)
+    ///     .to_zoned(TimeZone::get("America/New_York").unwrap())
+    ///     .unwrap();
+    /// // The clocks went forward, so the next 24 hours run until 01:00 the
+    /// // next day, including midnight.
+    /// let fires: Vec<_> = schedule
+    ///     .after(&after)
+    ///     .take_for(&after, 24.hours())
+    ///     .collect();
+    /// assert_eq!(23, fires.len());
+    /// assert_eq!(date(2025, 3, 10).at(0, 0, 0, 0), fires[22].datetime());
+    /// // While the next day starts at midnight.
+    /// assert_eq!(22, schedule.after(&after).take_for(&after, 1.day()).count());
+    /// ```
+    fn take_for(self, start: &Zoned, span: Span) -> TakeUntil<Self> {
+        TakeUntil {
+            iter: self,
+            end: start.checked_add(span).ok(),
+        }
+    }
+}
+
+impl<I> FireTimes for I where I: Iterator<Item = Zoned> {}
+
+/// Returns whether `fire` is less than `span` after `start`.
+fn is_within(start: &Zoned, span: Span, fire: &Zoned) -> bool {
+    start.checked_add(span).is_ok_and(|end| *fire < end)
+}
+
+/// An iterator over the first fire time of each burst, returned by
+/// [`FireTimes::coalesce_within`].
+#[derive(Clone, Debug)]
+pub struct CoalesceWithin<I> {
+    iter: I,
+    span: Span,
+    // The latest fire time of the current burst.
+    last: Option<Zoned>,
+}
+
+impl<I> Iterator for CoalesceWithin<I>
+where
+    I: Iterator<Item = Zoned>,
+{
+    type Item = Zoned;
+
+    fn next(&mut self) -> Option<Zoned> {
+        loop {
+            let fire = self.iter.next()?;
+            let continues_burst = self
+                .last
+                .as_ref()
+                .is_some_and(|last| is_within(last, self.span, &fire));
+            self.last = Some(fire.clone());
+            if !continues_burst {
+                return Some(fire);
+            }
+        }
+    }
+}
+
+/// An iterator over shifted fire times, returned by [`FireTimes::offset_by`].
+#[derive(Clone, Debug)]
+pub struct OffsetBy<I> {
+    iter: I,
+    span: Span,
+}
+
+impl<I> Iterator for OffsetBy<I>
+where
+    I: Iterator<Item = Zoned>,
+{
+    type Item = Zoned;
+
+    fn next(&mut self) -> Option<Zoned> {
+        self.iter.find_map(|fire| fire.checked_add(self.span).ok())
+    }
+}
+
+/// An iterator over fire times spaced at least a span apart, returned by
+/// [`FireTimes::at_most_every`].
+#[derive(Clone, Debug)]
+pub struct AtMostEvery<I> {
+    iter: I,
+    span: Span,
+    // The latest fire time kept.
+    last: Option<Zoned>,
+}
+
+impl<I> Iterator for AtMostEvery<I>
+where
+    I: Iterator<Item = Zoned>,
+{
+    type Item = Zoned;
+
+    fn next(&mut self) -> Option<Zoned> {
+        loop {
+            let fire = self.iter.next()?;
+            let too_soon = self
+                .last
+                .as_ref()
+                .is_some_and(|last| is_within(last, self.span, &fire));
+            if !too_soon {
+                self.last = Some(fire.clone());
+                return Some(fire);
+            }
+        }
+    }
+}
+
+/// An iterator over the fire times in every `n`th week, returned by
+/// [`FireTimes::every_nth_week`].
+#[derive(Clone, Debug)]
+pub struct EveryNthWeek<I> {
+    iter: I,
+    n: i64,
+    // The Monday of the first included week.
+    anchor: Date,
+}
+
+impl<I> Iterator for EveryNthWeek<I>
+where
+    I: Iterator<Item = Zoned>,
+{
+    type Item = Zoned;
+
+    fn next(&mut self) -> Option<Zoned> {
+        let (n, anchor) = (self.n, self.anchor);
+        self.iter.find(|fire| {
+            let days = i64::from((start_of_week(fire.date()) - anchor).get_days());
+            (days / 7).rem_euclid(n) == 0
+        })
+    }
+}
+
+/// An iterator over the fire times before an end, returned by
+/// [`FireTimes::take_until`] and [`FireTimes::take_for`].
+#[derive(Clone, Debug)]
+pub struct TakeUntil<I> {
+    iter: I,
+    // `None` once the end has been passed, or if it couldn't be computed.
+    end: Option<Zoned>,
+}
+
+impl<I> Iterator for TakeUntil<I>
+where
+    I: Iterator<Item = Zoned>,
+{
+    type Item = Zoned;
+
+    fn next(&mut self) -> Option<Zoned> {
+        let end = self.end.as_ref()?;
+        match self.iter.next() {
+            Some(fire) if fire < *end => Some(fire),
+            _ => {
+                self.end = None;
+                None
+            }
+        }
+    }
+}
+
+fn start_of_week(date: Date) -> Date {
+    let days_since_monday = date.weekday().to_monday_zero_offset();
+    date.checked_sub(i64::from(days_since_monday).days())
+        .unwrap_or(date)
+}
+
+#[cfg(test)]
+mod test {
+    use std::str::FromStr;
+
+    use jiff::{civil::date, tz::TimeZone, ToSpan};
+
+    use super::*;
+    use crate::{Schedule, ScheduleUnion};
+
+    fn after() -> Zoned {
+        date(2025, 1, 1)
+            .at(0, 0, 0, 0)
+            .to_zoned(TimeZone::UTC)
+            .unwrap()
+    }
+
+    #[test]
+    fn test_coalesce_within() {
+        // Bursts chain, so 09:00 to 09:20 is a single burst.
+        let schedule = Schedule::from_str("0 0,10,20,45 9 * * *").unwrap();
+        let fires: Vec<_> = schedule
+            .after(&after())
+            .coalesce_within(10.minutes().seconds(1))
+            .take(2)
+            .map(|fire| fire.datetime())
+            .collect();
+        assert_eq!(
+            vec![
+                date(2025, 1, 1).at(9, 0, 0, 0),
+                date(2025, 1, 1).at(9, 45, 0, 0)
+            ],
+            fires
+        );
+
+        // Fire times exactly `span` apart are separate bursts.
+        let schedule = Schedule::from_str("0 */10 9 * * *").unwrap();
+        let coalesced = schedule.after(&after()).coalesce_within(10.minutes());
+        assert_eq!(6, coalesced.take_while(|fire| fire.day() == 1).count());
+
+        let schedule = Schedule::from_str("0 0 9 1 1 * 2025").unwrap();
+        assert_eq!(
+            1,
+            schedule.after(&after()).coalesce_within(1.year()).count()
+        );
+    }
+
+    #[test]
+    fn test_every_nth_week() {
+        // Parity carries on across a year with 53 ISO weeks.
+        let schedule = Schedule::from_str("0 0 9 * * Tue").unwrap();
+        let after = date(2026, 12, 1).in_tz("UTC").unwrap();
+        let dates: Vec<_> = schedule
+            .after(&after)
+            .every_nth_week(2, date(2026, 12, 6))
+            .take(4)
+            .map(|fire| fire.date())
+            .collect();
+        assert_eq!(
+            vec![
+                date(2026, 12, 1),
+                date(2026, 12, 15),
+                date(2026, 12, 29),
+                date(2027, 1, 12)
+            ],
+            dates
+        );
+
+        // Anchors after the fire times count back in the same pattern.
+        let odd: Vec<_> = schedule
+            .after(&after)
+            .every_nth_week(2, date(2030, 1, 2))
+            .take(4)
+            .collect();
+        let even: Vec<_> = schedule
+            .after(&after)
+            .every_nth_week(2, date(2030, 1, 9))
+            .take(4)
+            .collect();
+        assert!(odd.iter().all(|fire| !even.contains(fire)));
+
+        let every_week = schedule.after(&after).every_nth_week(1, date(2000, 1, 1));
+        assert!(every_week.take(10).eq(schedule.after(&after).take(10)));
+    }
+
+    #[test]
+    fn test_take_until() {
+        let schedule = Schedule::from_str("0 0 9 * * *").unwrap();
+        let after = date(2025, 1, 1).in_tz("UTC").unwrap();
+        let end = date(2025, 1, 4).at(9, 0, 0, 0).in_tz("UTC").unwrap();
+        // The end itself is excluded.
+        assert_eq!(3, schedule.after(&after).take_until(&end).count());
+        assert_eq!(
+            3,
+            schedule.after_owned(after.clone()).take_until(&end).count()
+        );
+        assert_eq!(0, schedule.after(&after).take_until(&after).count());
+
+        assert_eq!(7, schedule.after(&after).take_for(&after, 1.week()).count());
+        assert_eq!(
+            0,
+            schedule.after(&after).take_for(&after, (-1).week()).count()
+        );
+        assert_eq!(
+            2,
+            schedule
+                .after_owned(after.clone())
+                .take_for(&after, 48.hours())
+                .count()
+        );
+
+        // The iterator is done once it passes the end.
+        let mut bounded = schedule
+            .after(&date(2025, 1, 3).in_tz("UTC").unwrap())
+            .take_until(&end);
+        assert!(bounded.next().is_some());
+        assert!(bounded.next().is_none());
+        assert!(bounded.next().is_none());
+
+        // Any iterator over fire times can be bounded.
+        let union = ScheduleUnion::from_exprs(&["0 0 9 * * *", "0 0 21 * * *"]).unwrap();
+        assert_eq!(6, union.after(&after).take_until(&end).count());
+        let bursts = Schedule::from_str("0 0,30 9 * * *").unwrap();
+        let coalesced = bursts
+            .after(&after)
+            .coalesce_within(1.hour())
+            .take_for(&after, 1.week());
+        assert_eq!(7, coalesced.count());
+    }
+}
@@ -0,0 +1,116 @@
+use std::{
+    fmt::{self, Display, Formatter},
+    time::{Duration, Instant},
+};
+
+/// A limit on the work of a bounded search, such as
+/// [`Schedule::try_next_after`](crate::Schedule::try_next_after), given as a
+/// number of candidate fire times considered, a time spent searching, or
+/// both.
+///
+/// A search exceeding its budget returns
+/// [`ErrorKind::SearchExhausted`](crate::error::ErrorKind::SearchExhausted)
+/// with the budget it was given.
+///
+/// # Example
+///
+/// ```rust
+/// use std::time::Duration;
+///
+/// use jiff_cron::SearchBudget;
+///
+/// let budget = SearchBudget::candidates(1_000).with_time(Duration::from_millis(5));
+/// assert_eq!(Some(1_000), budget.max_candidates());
+/// assert_eq!(Some(Duration::from_millis(5)), budget.max_time());
+/// assert_eq!("1000 candidates or 5ms", budget.to_string());
+/// ```
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct SearchBudget {
+    candidates: Option<usize>,
+    time: Option<Duration>,
+}
+
+impl SearchBudget {
+    /// A budget without limits, for the searches which can't fail.
+    pub(crate) const UNLIMITED: SearchBudget = SearchBudget {
+        candidates: None,
+        time: None,
+    };
+
+    /// Creates a budget of `max` candidate fire times, each of which is found
+    /// with a bounded amount of work, however sparse the schedule.
+    pub fn candidates(max: usize) -> SearchBudget {
+        SearchBudget::UNLIMITED.with_candidates(max)
+    }
+
+    /// Creates a budget of `max` time spent searching, measured with
+    /// [`Instant`] as each candidate fire time is found, so a search can run
+    /// over it by the time to find one candidate.
+    pub fn time(max: Duration) -> SearchBudget {
+        SearchBudget::UNLIMITED.with_time(max)
+    }
+
+    /// Also limits the search to `max` candidate fire times.
+    pub fn with_candidates(mut self, max: usize) -> SearchBudget {
+        self.candidates = Some(max);
+        self
+    }
+
+    /// Also limits the search to `max` time spent searching.
+    pub fn with_time(mut self, max: Duration) -> SearchBudget {
+        self.time = Some(max);
+        self
+    }
+
+    /// Returns the maximum number of candidate fire times considered, if
+    /// limited.
+    pub fn max_candidates(&self) -> Option<usize> {
+        self.candidates
+    }
+
+    /// Returns the maximum time spent searching, if limited.
+    pub fn max_time(&self) -> Option<Duration> {
+        self.time
+    }
+
+    /// Starts spending the budget, from now on.
+    pub(crate) fn start(&self) -> Spending {
+        Spending {
+            candidates: self.candidates,
+            deadline: self.time.and_then(|time| Instant::now().checked_add(time)),
+        }
+    }
+}
+
+impl Display for SearchBudget {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        match (self.candidates, self.time) {
+            (Some(candidates), Some(time)) => write!(f, "{} candidates or {:?}", candidates, time),
+            (Some(candidates), None) => write!(f, "{} candidates", candidates),
+            (None, Some(time)) => write!(f, "{:?}", time),
+            (None, None) => write!(f, "an unlimited budget"),
+        }
+    }
+}
+
+/// What is left of a [`SearchBudget`] during a search.
+pub(crate) struct Spending {
+    candidates: Option<usize>,
+    deadline: Option<Instant>,
+}
+
+impl Spending {
+    /// Spends the budget for one candidate fire time, failing if none is left.
+    pub(crate) fn spend(&mut self) -> Result<(), Exhausted> {
+        if let Some(candidates) = &mut self.candidates {
+            *candidates = candidates.checked_sub(1).ok_or(Exhausted)?;
+        }
+        match self.deadline {
+            Some(deadline) if Instant::now() >= deadline => Err(Exhausted),
+            _ => Ok(()),
+        }
+    }
+}
+
+/// Returned by searches which ran out of budget.
+pub(crate) struct Exhausted;
@@ -0,0 +1,260 @@
+use std::{
+    collections::{HashMap, VecDeque},
+    str::FromStr,
+    sync::{Arc, Mutex, MutexGuard, PoisonError, RwLock},
+};
+
+use jiff::Zoned;
+
+use crate::{error::Error, schedule::Schedule};
+
+/// A thread-safe cache of parsed schedules, keyed by their expression.
+///
+/// Expressions differing only in whitespace share a cache entry. Once the
+/// cache holds `capacity` schedules, further expressions are parsed without
+/// being cached, so it can't grow without bound when fed untrusted input.
+///
+/// # Example
+///
+/// ```rust
+/// use std::sync::Arc;
+///
+/// use jiff_cron::ScheduleCache;
+///
+/// let cache = ScheduleCache::new(256);
+/// let first = cache.get("0 30 9 * * Mon-Fri").unwrap();
+/// let second = cache.get(" 0  30 9 * * Mon-Fri ").unwrap();
+/// assert!(Arc::ptr_eq(&first, &second));
+/// assert_eq!(1, cache.len());
+/// assert!(cache.get("0 30 9 * * Caturday").is_err());
+/// ```
+#[derive(Debug)]
+pub struct ScheduleCache {
+    schedules: RwLock<HashMap<String, Arc<Schedule>>>,
+    capacity: usize,
+}
+
+impl ScheduleCache {
+    /// Creates an empty cache holding up to `capacity` schedules.
+    pub fn new(capacity: usize) -> ScheduleCache {
+        ScheduleCache {
+            schedules: RwLock::new(HashMap::new()),
+            capacity,
+        }
+    }
+
+    /// Returns the schedule for `expression`, parsing it if it isn't cached.
+    /// Invalid expressions are never cached.
+    pub fn get(&self, expression: &str) -> Result<Arc<Schedule>, Error> {
+        let key = normalize(expression);
+        {
+            let schedules = self
+                .schedules
+                .read()
+                .unwrap_or_else(PoisonError::into_inner);
+            if let Some(schedule) = schedules.get(&key) {
+                return Ok(Arc::clone(schedule));
+            }
+        }
+        let schedule = Arc::new(Schedule::from_str(expression)?);
+        let mut schedules = self
+            .schedules
+            .write()
+            .unwrap_or_else(PoisonError::into_inner);
+        if let Some(schedule) = schedules.get(&key) {
+            // Another thread parsed the same expression in the meantime.
+            return Ok(Arc::clone(schedule));
+        }
+        if schedules.len() < self.capacity {
+            schedules.insert(key, Arc::clone(&schedule));
+        }
+        Ok(schedule)
+    }
+
+    /// Returns the number of cached schedules.
+    pub fn len(&self) -> usize {
+        self.schedules
+            .read()
+            .unwrap_or_else(PoisonError::into_inner)
+            .len()
+    }
+
+    /// Returns whether no schedules are cached.
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// Returns the maximum number of cached schedules.
+    pub fn capacity(&self) -> usize {
+        self.capacity
+    }
+
+    /// Removes every cached schedule.
+    pub fn clear(&self) {
+        self.schedules
+            .write()
+            .unwrap_or_else(PoisonError::into_inner)
+            .clear();
+    }
+}
+
+/// The most recently used schedules, evicting the least recently used one once
+/// `capacity` are held. Backs the one-shot functions, whose callers don't keep
+/// the schedules they parse around.
+#[derive(Debug)]
+pub(crate) struct RecentSchedules {
+    schedules: Mutex<VecDeque<(String, Arc<Schedule>)>>,
+    capacity: usize,
+}
+
+impl RecentSchedules {
+    pub const fn new(capacity: usize) -> RecentSchedules {
+        RecentSchedules {
+            schedules: Mutex::new(VecDeque::new()),
+            capacity,
+        }
+    }
+
+    /// Returns the schedule for `expression`, parsing it if it isn't one of
+    /// the most recently used. Invalid expressions are never cached.
+    pub fn get(&self, expression: &str) -> Result<Arc<Schedule>, Error> {
+        let key = normalize(expression);
+        if let Some(schedule) = self.touch(&key) {
+            return Ok(schedule);
+        }
+        let schedule = Arc::new(Schedule::from_str(expression)?);
+        let mut schedules = self.lock();
+        if !schedules.iter().any(|(cached, _)| *cached == key) {
+            schedules.push_front((key, Arc::clone(&schedule)));
+            schedules.truncate(self.capacity);
+        }
+        Ok(schedule)
+    }
+
+    /// Moves the schedule for `key` to the front, if it's cached.
+    fn touch(&self, key: &str) -> Option<Arc<Schedule>> {
+        let mut schedules = self.lock();
+        let index = schedules.iter().position(|(cached, _)| cached == key)?;
+        let entry = schedules.remove(index)?;
+        let schedule = Arc::clone(&entry.1);
+        schedules.push_front(entry);
+        Some(schedule)
+    }
+
+    fn lock(&self) -> MutexGuard<'_, VecDeque<(String, Arc<Schedule>)>> {
+        self.schedules
+            .lock()
+            .unwrap_or_else(PoisonError::into_inner)
+    }
+}
+
+/// Collapses runs of whitespace in `expression` into single spaces.
+fn normalize(expression: &str) -> String {
+    let mut normalized = String::with_capacity(expression.len());
+    for word in expression.split_whitespace() {
+        if !normalized.is_empty() {
+            normalized.push(' ');
+        }
+        normalized.push_str(word);
+    }
+    normalized
+}
+
+/// Remembers the most recently computed fire time of a schedule, so polling it
+/// with a slowly advancing `now` doesn't search for the same fire time again.
+#[derive(Debug, Default)]
+pub struct NextFireCache {
+    entry: Mutex<Option<Entry>>,
+}
+
+#[derive(Debug)]
+struct Entry {
+    after: Zoned,
+    next: Zoned,
+}
+
+impl NextFireCache {
+    /// Returns the cached fire time if it's also the next one after `after`,
+    /// i.e. if `after` hasn't moved backwards or passed it.
+    pub fn get(&self, after: &Zoned) -> Option<Zoned> {
+        let entry = self.entry.lock().unwrap_or_else(PoisonError::into_inner);
+        entry
+            .as_ref()
+            .filter(|entry| {
+                entry.after.time_zone() == after.time_zone()
+                    && entry.after <= *after
+                    && *after < entry.next
+            })
+            .map(|entry| entry.next.clone())
+    }
+
+    pub fn insert(&self, after: &Zoned, next: &Zoned) {
+        let mut entry = self.entry.lock().unwrap_or_else(PoisonError::into_inner);
+        *entry = Some(Entry {
+            after: after.clone(),
+            next: next.clone(),
+        });
+    }
+}
+
+// The cache never affects which times a schedule fires at.
+impl PartialEq for NextFireCache {
+    fn eq(&self, _other: &NextFireCache) -> bool {
+        true
+    }
+}
+
+impl Eq for NextFireCache {}
+
+#[cfg(test)]
+mod test {
+    use std::thread;
+
+    use super::*;
+
+    #[test]
+    fn test_schedule_cache_capacity() {
+        let cache = ScheduleCache::new(1);
+        let daily = cache.get("@daily").unwrap();
+        let hourly = cache.get("@hourly").unwrap();
+        assert_eq!(1, cache.len());
+        assert!(Arc::ptr_eq(&daily, &cache.get("\t@daily\n").unwrap()));
+        assert!(!Arc::ptr_eq(&hourly, &cache.get("@hourly").unwrap()));
+
+        cache.clear();
+        assert!(cache.is_empty());
+        assert!(cache.get("@fortnightly").is_err());
+        assert!(cache.is_empty());
+    }
+
+    #[test]
+    fn test_recent_schedules_evict_least_recently_used() {
+        let recent = RecentSchedules::new(2);
+        let daily = recent.get("@daily").unwrap();
+        let hourly = recent.get("@hourly").unwrap();
+        assert!(Arc::ptr_eq(&daily, &recent.get(" @daily ").unwrap()));
+        recent.get("@weekly").unwrap();
+        assert!(Arc::ptr_eq(&daily, &recent.get("@daily").unwrap()));
+        assert!(!Arc::ptr_eq(&hourly, &recent.get("@hourly").unwrap()));
+        assert!(recent.get("@fortnightly").is_err());
+        assert_eq!(2, recent.lock().len());
+    }
+
+    #[test]
+    fn test_schedule_cache_shared_between_threads() {
+        let cache = ScheduleCache::new(16);
+        let schedules: Vec<_> = thread::scope(|scope| {
+            let handles: Vec<_> = (0..4)
+                .map(|_| scope.spawn(|| cache.get("0 */5 * * * *").unwrap()))
+                .collect();
+            handles
+                .into_iter()
+                .map(|handle| handle.join().unwrap())
+                .collect()
+        });
+        assert_eq!(1, cache.len());
+        assert!(schedules
+            .windows(2)
+            .all(|pair| Arc::ptr_eq(&pair[0], &pair[1])));
+    }
+}
@@ -0,0 +1,139 @@
+use jiff::civil::{date, Date};
+
+use crate::schedule::Schedule;
+
+/// The number of `u64` words needed for a bit per day of a leap year.
+const WORDS: usize = 6;
+
+/// Answers date-level queries about a [`Schedule`] from a precomputed bitmap
+/// of the dates it fires on, one year at a time.
+///
+/// The bitmap for a year is computed the first time a date in it is queried,
+/// replacing the previous year's. Queries within a year, such as rendering a
+/// calendar, are then answered with a single bit test per date.
+///
+/// Like [`Schedule::fires_on`], the calendar only considers the schedule's
+/// date fields, not whether its times of day exist on a date in some time
+/// zone.
+///
+/// # Example
+///
+/// ```rust
+/// use std::str::FromStr;
+///
+/// use jiff_cron::{jiff::civil::date, Schedule};
+///
+/// let schedule = Schedule::from_str("0 0 9 1-7 * Mon").unwrap();
+/// let mut calendar = schedule.calendar();
+/// assert!(calendar.fires_on(date(2025, 9, 1)));
+/// assert!(!calendar.fires_on(date(2025, 9, 8)));
+/// assert_eq!(vec![date(2025, 12, 1)], calendar.dates_in_month(2025, 12));
+/// assert_eq!(12, calendar.dates_in_year(2025).count());
+/// ```
+#[derive(Clone, Debug)]
+pub struct FireCalendar {
+    schedule: Schedule,
+    year: Option<YearBitmap>,
+}
+
+#[derive(Clone, Debug)]
+struct YearBitmap {
+    year: i16,
+    // Bit `n` is set if the schedule fires on the `n`th day of the year,
+    // counting from zero.
+    days: [u64; WORDS],
+}
+
+impl FireCalendar {
+    /// Creates a calendar for `schedule`. No dates are computed until the
+    /// first query.
+    pub fn new(schedule: Schedule) -> FireCalendar {
+        FireCalendar {
+            schedule,
+            year: None,
+        }
+    }
+
+    /// Returns the schedule the calendar was created for.
+    pub fn schedule(&self) -> &Schedule {
+        &self.schedule
+    }
+
+    /// Returns true if the schedule fires at least once on the provided date.
+    pub fn fires_on(&mut self, date: Date) -> bool {
+        let index = date.day_of_year() as usize - 1;
+        self.bitmap(date.year()).days[index / 64] & (1 << (index % 64)) != 0
+    }
+
+    /// Returns each date of the provided month on which the schedule fires.
+    /// Returns no dates if the year or month is invalid.
+    pub fn dates_in_month(&mut self, year: i16, month: i8) -> Vec<Date> {
+        let Ok(first) = Date::new(year, month, 1) else {
+            return Vec::new();
+        };
+        (1..=first.days_in_month())
+            .map(|day| date(year, month, day))
+            .filter(|&date| self.fires_on(date))
+            .collect()
+    }
+
+    /// Returns an iterator over each date of the provided year on which the
+    /// schedule fires, in ascending order. Yields no dates if the year is
+    /// invalid.
+    pub fn dates_in_year(&mut self, year: i16) -> impl Iterator<Item = Date> {
+        let (first, days) = match Date::new(year, 1, 1) {
+            Ok(first) => (first, self.bitmap(year).days),
+            Err(_) => (Date::MIN, [0; WORDS]),
+        };
+        (0..WORDS * 64)
+            .filter(move |&index| days[index / 64] & (1 << (index % 64)) != 0)
+            .filter_map(move |index| first.with().day_of_year(index as i16 + 1).build().ok())
+    }
+
+    fn bitmap(&mut self, year: i16) -> &YearBitmap {
+        match self.year {
+            Some(ref bitmap) if bitmap.year == year => {}
+            _ => self.year = Some(YearBitmap::new(&self.schedule, year)),
+        }
+        self.year.as_ref().expect("the bitmap was just computed")
+    }
+}
+
+impl YearBitmap {
+    fn new(schedule: &Schedule, year: i16) -> YearBitmap {
+        let mut days = [0; WORDS];
+        let mut next = date(year, 1, 1);
+        for index in 0..next.days_in_year() as usize {
+            if schedule.fires_on(next) {
+                days[index / 64] |= 1 << (index % 64);
+            }
+            next = next.tomorrow().unwrap_or(next);
+        }
+        YearBitmap { year, days }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use std::str::FromStr;
+
+    use super::*;
+
+    #[test]
+    fn test_calendar_matches_fires_on() {
+        let schedule = Schedule::from_str("0 0 12 29-31 Feb,Dec Sun-Tue 2023-2025").unwrap();
+        let mut calendar = schedule.calendar();
+        let mut day = date(2022, 12, 25);
+        while day < date(2026, 1, 5) {
+            assert_eq!(schedule.fires_on(day), calendar.fires_on(day), "{}", day);
+            day = day.tomorrow().unwrap();
+        }
+        let dates: Vec<_> = calendar.dates_in_year(2024).collect();
+        assert_eq!(
+            vec![date(2024, 12, 29), date(2024, 12, 30), date(2024, 12, 31)],
+            dates
+        );
+        assert_eq!(Vec::<Date>::new(), calendar.dates_in_month(2024, 13));
+        assert_eq!(0, calendar.dates_in_year(i16::MAX).count());
+    }
+}
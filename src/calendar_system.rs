@@ -0,0 +1,369 @@
+use jiff::{
+    civil::{date, Date},
+    tz::TimeZone,
+    SignedDuration, ToSpan, Zoned,
+};
+
+use crate::{
+    ordinal::Ordinal,
+    schedule::{Schedule, ScheduleIterator},
+    time_unit::{DaysOfMonth, DaysOfWeek, Months, TimeUnitField, TimeUnitSpec, Years},
+};
+
+// Matching days are searched for one at a time within the years a schedule
+// fires in, so searching gives up after about a century without one, as a
+// schedule such as `0 0 0 30 2 *` never fires.
+const MAX_DAYS_WITHOUT_MATCH: usize = 36_525;
+
+/// A calendar system which the months and days of the month of a
+/// [`CalendarSchedule`] are interpreted in.
+///
+/// Only the tabular Islamic civil calendar is supported so far. Calendars
+/// with leap months, such as the Hebrew calendar with its 13 months in leap
+/// years, aren't, as the months field only has the months 1 to 12.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum CalendarSystem {
+    /// The tabular Islamic civil calendar, whose months alternate between 30
+    /// and 29 days, with a leap day ending 11 of every 30 years. Its epoch is
+    /// Friday, July 16th 622 in the Julian calendar.
+    ///
+    /// This is an arithmetical calendar, so it may differ by a day or two
+    /// from calendars based on sightings of the moon.
+    IslamicCivil,
+}
+
+impl CalendarSystem {
+    /// Returns the year, month and day of the month of `date` in this
+    /// calendar system.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use jiff_cron::{jiff::civil::date, CalendarSystem};
+    ///
+    /// let islamic = CalendarSystem::IslamicCivil;
+    /// assert_eq!((1445, 9, 1), islamic.from_gregorian(date(2024, 3, 11)));
+    /// ```
+    pub fn from_gregorian(self, date: Date) -> (i32, i8, i8) {
+        match self {
+            CalendarSystem::IslamicCivil => {
+                let days = (date - islamic_epoch()).get_days() as i64;
+                let year = ((30 * days + 10646).div_euclid(10631)) as i32;
+                let months_elapsed = (days - islamic_days(year, 1, 1) - 29) as f64 / 29.5;
+                let month = (months_elapsed.ceil() as i64 + 1).clamp(1, 12) as i8;
+                let day = (days - islamic_days(year, month, 1) + 1) as i8;
+                (year, month, day)
+            }
+        }
+    }
+
+    /// Returns the Gregorian date of `day` of `month` in `year` of this
+    /// calendar system, if there's such a day.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use jiff_cron::{jiff::civil::date, CalendarSystem};
+    ///
+    /// let islamic = CalendarSystem::IslamicCivil;
+    /// assert_eq!(Some(date(2024, 3, 11)), islamic.to_gregorian(1445, 9, 1));
+    /// assert_eq!(None, islamic.to_gregorian(1445, 10, 30));
+    /// ```
+    pub fn to_gregorian(self, year: i32, month: i8, day: i8) -> Option<Date> {
+        match self {
+            CalendarSystem::IslamicCivil => {
+                if !(1..=12).contains(&month)
+                    || !(1..=islamic_days_in_month(year, month)).contains(&day)
+                {
+                    return None;
+                }
+                let days = islamic_days(year, month, day);
+                islamic_epoch().checked_add(days.days()).ok()
+            }
+        }
+    }
+}
+
+impl CalendarSystem {
+    fn days_in_month(self, year: i32, month: i8) -> i8 {
+        match self {
+            CalendarSystem::IslamicCivil => islamic_days_in_month(year, month),
+        }
+    }
+}
+
+fn islamic_epoch() -> Date {
+    date(622, 7, 19)
+}
+
+// Days from the epoch to the given date.
+fn islamic_days(year: i32, month: i8, day: i8) -> i64 {
+    let year = i64::from(year);
+    (year - 1) * 354
+        + (3 + 11 * year).div_euclid(30)
+        + (29.5 * f64::from(month - 1)).ceil() as i64
+        + i64::from(day - 1)
+}
+
+fn islamic_days_in_month(year: i32, month: i8) -> i8 {
+    let is_leap_year = (11 * i64::from(year) + 14).rem_euclid(30) < 11;
+    match month {
+        12 if is_leap_year => 30,
+        month if month % 2 == 1 => 30,
+        _ => 29,
+    }
+}
+
+/// A [`Schedule`] whose months and days of the month are interpreted in
+/// another [`CalendarSystem`], while its years, days of the week and times of
+/// day are as usual. Its fire times are still Gregorian [`Zoned`] values.
+///
+/// Years stay Gregorian as the years field only supports 1970 to 2100.
+///
+/// This requires the `calendars` feature.
+///
+/// # Example
+///
+/// ```rust
+/// use std::str::FromStr;
+///
+/// use jiff_cron::{
+///     jiff::{civil::date, tz::TimeZone},
+///     CalendarSchedule, CalendarSystem, Schedule,
+/// };
+///
+/// // 18:00 on the first of Ramadan.
+/// let schedule = Schedule::from_str("0 0 18 1 9 *").unwrap();
+/// let schedule = CalendarSchedule::new(schedule, CalendarSystem::IslamicCivil);
+///
+/// let now = date(2024, 1, 1)
+///     .at(0, 0, 0, 0)
+///     .to_zoned(TimeZone::UTC)
+///     .unwrap();
+/// let fires: Vec<_> = schedule.after(&now).take(2).collect();
+/// assert_eq!(date(2024, 3, 11).at(18, 0, 0, 0), fires[0].datetime());
+/// assert_eq!(date(2025, 3, 1).at(18, 0, 0, 0), fires[1].datetime());
+/// ```
+#[derive(Clone, Debug)]
+pub struct CalendarSchedule {
+    schedule: Schedule,
+    calendar: CalendarSystem,
+    times_of_day: Schedule,
+}
+
+impl CalendarSchedule {
+    /// Interprets the months and days of the month of `schedule` in
+    /// `calendar`.
+    pub fn new(schedule: Schedule, calendar: CalendarSystem) -> CalendarSchedule {
+        let times_of_day = Schedule::from_fields(
+            schedule.seconds().clone(),
+            schedule.minutes().clone(),
+            schedule.hours().clone(),
+            DaysOfMonth::all(),
+            Months::all(),
+            DaysOfWeek::all(),
+            Years::all(),
+        );
+        let times_of_day = match schedule.is_year_bounded() {
+            true => times_of_day,
+            false => times_of_day.with_unbounded_years(),
+        };
+        CalendarSchedule {
+            schedule,
+            calendar,
+            times_of_day,
+        }
+    }
+
+    /// Returns the schedule as written.
+    pub fn schedule(&self) -> &Schedule {
+        &self.schedule
+    }
+
+    /// Returns the calendar system the schedule's dates are interpreted in.
+    pub fn calendar(&self) -> CalendarSystem {
+        self.calendar
+    }
+
+    /// Returns an iterator over the fire times after `after`, in its time
+    /// zone.
+    pub fn after(&self, after: &Zoned) -> CalendarIterator<'_> {
+        CalendarIterator {
+            schedule: self,
+            day: Some(after.date()),
+            previous: after.clone(),
+            times: None,
+        }
+    }
+
+    /// Like [`CalendarSchedule::after`], starting from the current time.
+    pub fn upcoming(&self, time_zone: TimeZone) -> CalendarIterator<'_> {
+        self.after(&Zoned::now().with_time_zone(time_zone))
+    }
+
+    /// Returns whether the schedule fires at `date_time`.
+    pub fn includes(&self, date_time: &Zoned) -> bool {
+        self.includes_date(date_time.date()) && self.times_of_day.includes(date_time.clone())
+    }
+
+    fn includes_date(&self, date: Date) -> bool {
+        let (year, month, day) = self.calendar.from_gregorian(date);
+        let days_in_month = self.calendar.days_in_month(year, month);
+        self.schedule.includes_year(date.year())
+            && self.schedule.months().includes(month as Ordinal)
+            && self
+                .schedule
+                .days_of_month()
+                .includes_day_in(day, date.weekday(), days_in_month)
+            && self
+                .schedule
+                .days_of_week()
+                .includes_day_in(day, date.weekday(), days_in_month)
+    }
+}
+
+/// An iterator over the fire times of a [`CalendarSchedule`], returned by
+/// [`CalendarSchedule::after`].
+#[derive(Clone, Debug)]
+pub struct CalendarIterator<'a> {
+    schedule: &'a CalendarSchedule,
+    day: Option<Date>,
+    previous: Zoned,
+    times: Option<(Date, ScheduleIterator<'a>)>,
+}
+
+impl Iterator for CalendarIterator<'_> {
+    type Item = Zoned;
+
+    fn next(&mut self) -> Option<Zoned> {
+        let mut days_without_match = 0;
+        loop {
+            if let Some((day, times)) = &mut self.times {
+                if let Some(fire) = times.next().filter(|fire| fire.date() == *day) {
+                    self.previous = fire.clone();
+                    return Some(fire);
+                }
+                self.times = None;
+            }
+            if days_without_match > MAX_DAYS_WITHOUT_MATCH {
+                self.day = None;
+            }
+            let day = self.day?;
+            // Years stay Gregorian, so the years the schedule doesn't fire in
+            // are skipped at once, and iteration ends after the last one.
+            match self.schedule.schedule.next_year_from(day.year()) {
+                Some(year) if year == day.year() => {}
+                year => {
+                    self.day = year.and_then(|year| Date::new(year, 1, 1).ok());
+                    continue;
+                }
+            }
+            self.day = day.tomorrow().ok();
+            if !self.schedule.includes_date(day) {
+                days_without_match += 1;
+                continue;
+            }
+            days_without_match = 0;
+            let start = if day == self.previous.date() {
+                self.previous.clone()
+            } else {
+                let time_zone = self.previous.time_zone().clone();
+                let Ok(midnight) = day.to_zoned(time_zone) else {
+                    continue;
+                };
+                let Ok(start) = midnight.checked_sub(SignedDuration::from_nanos(1)) else {
+                    continue;
+                };
+                start
+            };
+            self.times = Some((day, self.schedule.times_of_day.after(&start)));
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use std::str::FromStr;
+
+    use super::*;
+
+    #[test]
+    fn test_islamic_civil_round_trip() {
+        let islamic = CalendarSystem::IslamicCivil;
+        assert_eq!((1, 1, 1), islamic.from_gregorian(date(622, 7, 19)));
+        assert_eq!((1446, 1, 1), islamic.from_gregorian(date(2024, 7, 8)));
+        assert_eq!((1445, 12, 30), islamic.from_gregorian(date(2024, 7, 7)));
+        let mut day = date(2000, 1, 1);
+        while day < date(2040, 1, 1) {
+            let (year, month, day_of_month) = islamic.from_gregorian(day);
+            assert_eq!(Some(day), islamic.to_gregorian(year, month, day_of_month));
+            day = day.tomorrow().unwrap();
+        }
+    }
+
+    #[test]
+    fn test_islamic_year_lengths() {
+        let islamic = CalendarSystem::IslamicCivil;
+        let leap_years = [2, 5, 7, 10, 13, 16, 18, 21, 24, 26, 29];
+        for year in 1..=30 {
+            let start = islamic.to_gregorian(year, 1, 1).unwrap();
+            let end = islamic.to_gregorian(year + 1, 1, 1).unwrap();
+            let expected = if leap_years.contains(&year) { 355 } else { 354 };
+            assert_eq!(expected, (end - start).get_days(), "year {}", year);
+        }
+    }
+
+    #[test]
+    fn test_calendar_schedule() {
+        let schedule = Schedule::from_str("0 30 9,17 1,30 * Fri 2024-2025").unwrap();
+        let schedule = CalendarSchedule::new(schedule, CalendarSystem::IslamicCivil);
+        let start = date(2023, 7, 1).in_tz("Asia/Riyadh").unwrap();
+        let fires: Vec<Zoned> = schedule.after(&start).collect();
+        assert!(!fires.is_empty());
+        assert!(fires.windows(2).all(|pair| pair[0] < pair[1]));
+        for fire in &fires {
+            assert!(schedule.includes(fire));
+            let (_, _, day) = CalendarSystem::IslamicCivil.from_gregorian(fire.date());
+            assert!((2024..=2025).contains(&fire.year()));
+            assert!(day == 1 || day == 30);
+            assert_eq!(jiff::civil::Weekday::Friday, fire.weekday());
+        }
+
+        // Starting within a matching day only skips the earlier times.
+        let start = date(2024, 3, 11).at(12, 0, 0, 0).in_tz("UTC").unwrap();
+        let schedule = Schedule::from_str("0 0 9,18 1 9 *").unwrap();
+        let schedule = CalendarSchedule::new(schedule, CalendarSystem::IslamicCivil);
+        let next = schedule.after(&start).next().unwrap();
+        assert_eq!(date(2024, 3, 11).at(18, 0, 0, 0), next.datetime());
+    }
+
+    #[test]
+    fn test_calendar_schedule_years() {
+        let first_of_month = Schedule::from_str("0 0 0 1 * *").unwrap();
+        let start = date(2100, 12, 1).in_tz("UTC").unwrap();
+        let bounded = CalendarSchedule::new(first_of_month.clone(), CalendarSystem::IslamicCivil);
+        assert!(bounded.after(&start).all(|fire| fire.year() == 2100));
+        let unbounded = CalendarSchedule::new(
+            first_of_month.with_unbounded_years(),
+            CalendarSystem::IslamicCivil,
+        );
+        let fires: Vec<Zoned> = unbounded.after(&start).take(3).collect();
+        assert_eq!(2101, fires[2].year());
+        assert!(unbounded.includes(&fires[2]));
+
+        // Iteration ends with the last year, and starts with the first.
+        let schedule = Schedule::from_str("0 0 0 1 1 * 2030").unwrap();
+        let schedule = CalendarSchedule::new(schedule, CalendarSystem::IslamicCivil);
+        let start = date(2000, 1, 1).in_tz("UTC").unwrap();
+        let fires: Vec<Zoned> = schedule.after(&start).collect();
+        assert_eq!(1, fires.len());
+        assert_eq!(
+            (1452, 1, 1),
+            CalendarSystem::IslamicCivil.from_gregorian(fires[0].date())
+        );
+        let mut iter = schedule.after(&fires[0]);
+        assert_eq!(None, iter.next());
+        assert_eq!(None, iter.next());
+    }
+}
@@ -0,0 +1,406 @@
+use std::fmt::{self, Display, Formatter};
+
+use crate::{
+    ordinal::Ordinal,
+    parsing::parse_words,
+    schedule::Schedule,
+    specifier::{RootSpecifier, Specifier},
+    time_unit::TimeUnitSpec,
+};
+
+// The nicknames most crons accept, unlike `@weekday` and `@weekend`.
+const PORTABLE_NICKNAMES: [&str; 5] = ["@yearly", "@monthly", "@weekly", "@daily", "@hourly"];
+
+const STEP_OFFSETS: &str = "step offsets such as `*/15+5`";
+const NTH_WEEKDAYS_FROM_LAST: &str =
+    "occurrences counted from the end of the month other than the last, such as `Fri#-2`";
+
+// The years Quartz accepts in its years field.
+const QUARTZ_MIN_YEAR: Ordinal = 1970;
+const QUARTZ_MAX_YEAR: Ordinal = 2099;
+
+/// Another cron implementation which a schedule may be carried over to.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum Dialect {
+    /// Classic Unix crons such as Vixie cron and cronie, with five fields
+    /// from minutes to days of the week, numbering Sunday 0.
+    Crond,
+    /// Quartz, with a seconds field and an optional years field, requiring
+    /// `?` for one of the days of the month and the days of the week.
+    Quartz,
+    /// Amazon EventBridge, with six fields from minutes to years, requiring
+    /// `?` for one of the days of the month and the days of the week.
+    EventBridge,
+}
+
+impl Display for Dialect {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        f.write_str(match self {
+            Dialect::Crond => "crond",
+            Dialect::Quartz => "Quartz",
+            Dialect::EventBridge => "EventBridge",
+        })
+    }
+}
+
+/// The features of the cron syntax an expression uses, returned by
+/// [`Schedule::capabilities`], for telling whether it can be carried over to
+/// another cron implementation.
+///
+/// Wrapping ranges such as `22-2` aren't accepted by this crate, so they
+/// never come up.
+///
+/// # Example
+///
+/// ```rust
+/// use std::str::FromStr;
+///
+/// use jiff_cron::{Dialect, Schedule};
+///
+/// let schedule = Schedule::from_str("0 */15 9-17 ? * Mon-Fri").unwrap();
+/// let capabilities = schedule.capabilities();
+/// assert!(capabilities.uses_steps());
+/// assert!(capabilities.uses_names());
+/// assert!(capabilities.uses_question_marks());
+/// assert!(capabilities.is_portable_to(Dialect::Quartz));
+/// assert!(capabilities.is_portable_to(Dialect::EventBridge));
+/// // Classic crons don't accept `?`.
+/// assert!(!capabilities.is_portable_to(Dialect::Crond));
+///
+/// let schedule = Schedule::from_str("30 * * * * *").unwrap();
+/// assert!(schedule.capabilities().uses_seconds());
+/// assert!(!schedule.capabilities().is_portable_to(Dialect::Crond));
+/// ```
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct Capabilities {
+    seconds: bool,
+    years: bool,
+    year_range: Option<(Ordinal, Ordinal)>,
+    names: bool,
+    steps: bool,
+    step_offsets: bool,
+    question_marks: bool,
+    one_day_question_mark: bool,
+    fields_run_together: bool,
+    nickname: Option<String>,
+    numeric_days_of_week: bool,
+    both_days: bool,
+    nth_weekdays: bool,
+    nth_weekdays_from_last: bool,
+    last_days: bool,
+    nearest_weekdays: bool,
+}
+
+impl Capabilities {
+    pub(crate) fn of(schedule: &Schedule) -> Capabilities {
+        let mut capabilities = Capabilities {
+            seconds: schedule.seconds().iter().any(|second| second != 0),
+            years: !schedule.years().is_all(),
+            both_days: !schedule.days_of_month().is_all() && !schedule.days_of_week().is_all(),
+            ..Capabilities::default()
+        };
+        let years = schedule.years();
+        if !years.is_all() {
+            capabilities.year_range = years.iter().next().zip(years.iter().next_back());
+        }
+        let source = schedule.source().trim();
+        if source.starts_with('@') {
+            capabilities.nickname = Some(source.to_owned());
+            return capabilities;
+        }
+        let words: Vec<&str> = source.split_whitespace().collect();
+        // Classic five-field expressions have no seconds field.
+        let days_of_week = if words.len() == 5 { 4 } else { 5 };
+        capabilities.question_marks = words.iter().any(|word| word.contains('?'));
+        let is_question_mark = |index| words.get(index) == Some(&"?");
+        capabilities.one_day_question_mark =
+            is_question_mark(days_of_week - 2) != is_question_mark(days_of_week);
+        // The expression parsed, so a word which isn't a field holds several
+        // of them, e.g. `?*MON`.
+        let Some(fields) = parse_words(&words) else {
+            capabilities.fields_run_together = true;
+            return capabilities;
+        };
+        for (index, field) in fields.iter().enumerate() {
+            for specifier in field.specifiers() {
+                let base = match specifier {
+                    RootSpecifier::Specifier(base) => base,
+                    RootSpecifier::Period(base, _) => {
+                        capabilities.steps = true;
+                        base
+                    }
+                    RootSpecifier::OffsetPeriod(base, _, _) => {
+                        capabilities.steps = true;
+                        capabilities.step_offsets = true;
+                        base
+                    }
+                    RootSpecifier::NamedPoint(_) => {
+                        capabilities.names = true;
+                        continue;
+                    }
+                    RootSpecifier::NthWeekday(weekday, nth) => {
+                        capabilities.nth_weekdays = true;
+                        // The last occurrence can be written as `FriL`.
+                        capabilities.nth_weekdays_from_last |= *nth < -1;
+                        match **weekday {
+                            RootSpecifier::NamedPoint(_) => capabilities.names = true,
+                            _ => capabilities.numeric_days_of_week = true,
+                        }
+                        continue;
+                    }
+                    RootSpecifier::LastDay(_) => {
+                        capabilities.last_days = true;
+                        continue;
+                    }
+                    RootSpecifier::NearestWeekday(_) => {
+                        capabilities.nearest_weekdays = true;
+                        continue;
+                    }
+                    // Resolved before a schedule is created.
+                    RootSpecifier::Hashed(..) => continue,
+                    RootSpecifier::LastWeekday => {
+                        capabilities.last_days = true;
+                        capabilities.nearest_weekdays = true;
+                        continue;
+                    }
+                };
+                match base {
+                    Specifier::NamedRange(..) => capabilities.names = true,
+                    Specifier::Point(_) | Specifier::Range(..) if index == days_of_week => {
+                        capabilities.numeric_days_of_week = true
+                    }
+                    _ => {}
+                }
+            }
+        }
+        capabilities
+    }
+
+    /// Returns whether the schedule fires at seconds other than the first of
+    /// the minute.
+    pub fn uses_seconds(&self) -> bool {
+        self.seconds
+    }
+
+    /// Returns whether the schedule is limited to some years.
+    pub fn uses_years(&self) -> bool {
+        self.years
+    }
+
+    /// Returns whether months or days of the week are written as names, e.g.
+    /// `Mon-Fri`.
+    pub fn uses_names(&self) -> bool {
+        self.names
+    }
+
+    /// Returns whether any field has a step, e.g. `*/15`.
+    pub fn uses_steps(&self) -> bool {
+        self.steps
+    }
+
+    /// Returns whether any step has an offset, e.g. `*/15+5`, which other
+    /// crons don't accept.
+    pub fn uses_step_offsets(&self) -> bool {
+        self.step_offsets
+    }
+
+    /// Returns whether any field is written as `?`.
+    pub fn uses_question_marks(&self) -> bool {
+        self.question_marks
+    }
+
+    /// Returns the nickname the expression is written as, e.g. `@daily`.
+    pub fn nickname(&self) -> Option<&str> {
+        self.nickname.as_deref()
+    }
+
+    /// Returns whether days of the week are written as numbers, which other
+    /// crons may count differently: this crate and Quartz number Sunday 1,
+    /// while classic crons number it 0.
+    pub fn uses_numeric_days_of_week(&self) -> bool {
+        self.numeric_days_of_week
+    }
+
+    /// Returns whether both the days of the month and the days of the week
+    /// are restricted, which this crate requires both of, while classic crons
+    /// require either and Quartz and EventBridge don't support.
+    pub fn restricts_both_days(&self) -> bool {
+        self.both_days
+    }
+
+    /// Returns whether days of the week are written as occurrences within
+    /// the month, e.g. `Mon#2`, which classic crons don't accept. Quartz and
+    /// EventBridge don't accept occurrences counted from the end of the
+    /// month other than the last, e.g. `Fri#-2`, either.
+    pub fn uses_nth_weekdays(&self) -> bool {
+        self.nth_weekdays
+    }
+
+    /// Returns whether days of the month are counted from the end of the
+    /// month, e.g. `L`, `L-3` or `LW`, which classic crons don't accept.
+    pub fn uses_last_days(&self) -> bool {
+        self.last_days
+    }
+
+    /// Returns whether days of the month are moved to the nearest weekday,
+    /// e.g. `15W` or `LW`, which classic crons don't accept.
+    pub fn uses_nearest_weekdays(&self) -> bool {
+        self.nearest_weekdays
+    }
+
+    /// Returns whether the schedule means the same in `dialect`, once its
+    /// fields are laid out as `dialect` expects them, e.g. without seconds.
+    /// Quartz and EventBridge also require `?` for exactly one of the days of
+    /// the month and the days of the week, as in
+    /// [`ParseOptions::with_dialect`](crate::ParseOptions::with_dialect).
+    pub fn is_portable_to(&self, dialect: Dialect) -> bool {
+        self.unsupported_in(dialect).is_none()
+    }
+
+    /// Returns the first feature of the schedule which `dialect` doesn't
+    /// accept or would read differently, described for error messages.
+    pub(crate) fn unsupported_in(&self, dialect: Dialect) -> Option<&'static str> {
+        if self.fields_run_together {
+            return Some("fields which aren't separated by whitespace");
+        }
+        if dialect == Dialect::Crond {
+            return self.unsupported_in_crond();
+        }
+        if self.nickname.is_some() {
+            return Some("nicknames such as `@daily`");
+        }
+        if dialect == Dialect::EventBridge && self.seconds {
+            return Some("seconds");
+        }
+        if self.step_offsets {
+            return Some(STEP_OFFSETS);
+        }
+        if self.nth_weekdays_from_last {
+            return Some(NTH_WEEKDAYS_FROM_LAST);
+        }
+        if !self.one_day_question_mark {
+            return Some(
+                "days of the month and days of the week unless exactly one of them is `?`",
+            );
+        }
+        match self.year_range {
+            Some((first, last))
+                if dialect == Dialect::Quartz
+                    && (first < QUARTZ_MIN_YEAR || last > QUARTZ_MAX_YEAR) =>
+            {
+                Some("years before 1970 or after 2099")
+            }
+            _ => None,
+        }
+    }
+
+    fn unsupported_in_crond(&self) -> Option<&'static str> {
+        let portable_nickname = self
+            .nickname()
+            .into_iter()
+            .all(|nickname| PORTABLE_NICKNAMES.contains(&nickname));
+        [
+            (self.seconds, "seconds"),
+            (self.years, "years"),
+            (self.question_marks, "`?`"),
+            (self.step_offsets, STEP_OFFSETS),
+            (
+                self.both_days,
+                "both days of the month and days of the week, either of which classic crons match",
+            ),
+            (
+                self.numeric_days_of_week,
+                "days of the week as numbers, which classic crons number from Sunday 0",
+            ),
+            (self.nth_weekdays_from_last, NTH_WEEKDAYS_FROM_LAST),
+            (
+                self.nth_weekdays,
+                "occurrences of days of the week such as `Mon#2`",
+            ),
+            (
+                self.last_days,
+                "days counted from the end of the month such as `L`",
+            ),
+            (self.nearest_weekdays, "nearest weekdays such as `15W`"),
+            (
+                !portable_nickname,
+                "nicknames other than `@yearly`, `@monthly`, `@weekly`, `@daily` and `@hourly`",
+            ),
+        ]
+        .into_iter()
+        .find(|&(used, _)| used)
+        .map(|(_, feature)| feature)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use std::str::FromStr;
+
+    use super::*;
+    use crate::{
+        options::ParseOptions,
+        time_unit::{Seconds, TimeUnitField},
+    };
+
+    #[test]
+    fn test_capabilities() {
+        let capabilities = |expression| Schedule::from_str(expression).unwrap().capabilities();
+
+        let plain = capabilities("0 30 9 * * *");
+        assert!(!plain.uses_seconds() && !plain.uses_question_marks());
+        assert!(plain.is_portable_to(Dialect::Crond));
+        // Quartz and EventBridge require `?` for one of the days.
+        assert!(!plain.is_portable_to(Dialect::Quartz));
+        let question_mark = capabilities("0 30 9 ? * *");
+        assert!(question_mark.is_portable_to(Dialect::Quartz));
+        assert!(question_mark.is_portable_to(Dialect::EventBridge));
+        assert!(!question_mark.is_portable_to(Dialect::Crond));
+        assert!(!capabilities("0 0 12 1 1 ? 2100").is_portable_to(Dialect::Quartz));
+        assert!(capabilities("0 0 12 1 1 ? 2100").is_portable_to(Dialect::EventBridge));
+
+        assert!(capabilities("0 0 0 1 Jan *").uses_names());
+        assert!(capabilities("0 0 0 * * Sun").uses_names());
+        assert!(capabilities("0 0 0 * * 1-5").uses_numeric_days_of_week());
+        assert!(!capabilities("0 0 0 * * 1-5").is_portable_to(Dialect::Crond));
+        assert!(!capabilities("0 0 0 1-5 * *").uses_numeric_days_of_week());
+
+        let offset = capabilities("0 */15+5 * * * *");
+        assert!(offset.uses_steps() && offset.uses_step_offsets());
+        assert!(!offset.is_portable_to(Dialect::Quartz));
+
+        let years = capabilities("0 0 0 1 1 ? 2030");
+        assert!(years.uses_years() && years.uses_question_marks());
+        assert!(years.is_portable_to(Dialect::EventBridge));
+        assert!(!years.is_portable_to(Dialect::Crond));
+
+        let nth = capabilities("0 0 9 ? * Mon#2");
+        assert!(nth.uses_nth_weekdays() && nth.uses_names());
+        assert!(nth.is_portable_to(Dialect::Quartz));
+        assert!(!nth.is_portable_to(Dialect::Crond));
+        assert!(!capabilities("0 0 9 ? * Fri#-2").is_portable_to(Dialect::Quartz));
+        assert!(capabilities("0 0 9 ? * FriL").is_portable_to(Dialect::Quartz));
+
+        let last = capabilities("0 0 9 L * ?");
+        assert!(last.uses_last_days());
+        assert!(last.is_portable_to(Dialect::EventBridge));
+        assert!(!last.is_portable_to(Dialect::Crond));
+        let nearest = capabilities("0 0 9 15W * ?");
+        assert!(nearest.uses_nearest_weekdays() && nearest.is_portable_to(Dialect::Quartz));
+        assert!(!nearest.is_portable_to(Dialect::Crond));
+
+        assert_eq!(Some("@daily"), capabilities("@daily").nickname());
+        assert!(capabilities("@daily").is_portable_to(Dialect::Crond));
+        assert!(!capabilities("@weekday").is_portable_to(Dialect::Crond));
+        assert!(!capabilities("@daily").is_portable_to(Dialect::Quartz));
+
+        let five_fields = ParseOptions::new()
+            .with_omitted_seconds(Seconds::from_ordinal(0))
+            .parse("0 9 * * 1")
+            .unwrap()
+            .capabilities();
+        assert!(five_fields.uses_numeric_days_of_week());
+        assert!(!five_fields.uses_seconds());
+    }
+}
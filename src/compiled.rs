@@ -0,0 +1,338 @@
+use std::fmt::{self, Formatter};
+
+use serde::{
+    de::{self, SeqAccess, Visitor},
+    ser::SerializeTuple,
+    Deserialize, Deserializer, Serialize, Serializer,
+};
+
+use crate::{
+    error::{Error, ErrorKind},
+    ordinal::{Ordinal, OrdinalSet},
+    specifier::RootSpecifier,
+    time_unit::*,
+    Schedule,
+};
+
+/// The version of the compiled format, written ahead of the fields so that
+/// schedules compiled by an incompatible version are rejected.
+///
+/// Version 2 adds the days depending on the layout of their month, such as
+/// for `Fri#-2` or `L`, and is only written for schedules including any, so
+/// that other schedules can still be loaded by releases only reading version 1.
+const FORMAT_VERSION: u8 = 2;
+
+/// The number of elements in version 1 of the compiled format: its version,
+/// each field's ordinals and whether the years are unbounded.
+const V1_LEN: usize = 9;
+
+/// The number of elements in the compiled format, with its month days.
+const LEN: usize = V1_LEN + 1;
+
+/// A [`Schedule`] which serializes its fields' ordinals rather than its source
+/// expression, so it can be loaded again without parsing.
+///
+/// With compact formats such as postcard, each ordinal set takes a few bytes:
+/// wildcards are stored as ranges and other fields as bitmasks. Ordinals are
+/// still validated when deserializing, and the source expression of a loaded
+/// schedule is generated from its fields.
+///
+/// Requires the `serde` feature.
+///
+/// # Example
+///
+/// ```rust
+/// use std::str::FromStr;
+///
+/// use jiff_cron::{CompiledSchedule, Schedule};
+///
+/// let schedule = Schedule::from_str("0 */15 9-17 * * Mon-Fri").unwrap();
+/// let bytes = postcard::to_stdvec(&CompiledSchedule::from(schedule.clone())).unwrap();
+/// let compiled: CompiledSchedule = postcard::from_bytes(&bytes).unwrap();
+/// assert_eq!(schedule, compiled.into_schedule());
+/// ```
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct CompiledSchedule {
+    schedule: Schedule,
+}
+
+impl CompiledSchedule {
+    /// Wraps `schedule` for serialization in the compiled format.
+    pub fn new(schedule: Schedule) -> CompiledSchedule {
+        CompiledSchedule { schedule }
+    }
+
+    /// Returns the wrapped schedule.
+    pub fn schedule(&self) -> &Schedule {
+        &self.schedule
+    }
+
+    /// Unwraps the schedule.
+    pub fn into_schedule(self) -> Schedule {
+        self.schedule
+    }
+}
+
+impl From<Schedule> for CompiledSchedule {
+    fn from(schedule: Schedule) -> Self {
+        CompiledSchedule::new(schedule)
+    }
+}
+
+impl From<CompiledSchedule> for Schedule {
+    fn from(compiled: CompiledSchedule) -> Self {
+        compiled.into_schedule()
+    }
+}
+
+impl Serialize for CompiledSchedule {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let schedule = &self.schedule;
+        let month_days: Vec<_> = schedule
+            .days_of_month()
+            .month_days()
+            .iter()
+            .chain(schedule.days_of_week().month_days())
+            .map(|&day| month_day_parts(day))
+            .collect();
+        let (version, len) = if month_days.is_empty() {
+            (1, V1_LEN)
+        } else {
+            (FORMAT_VERSION, LEN)
+        };
+        let mut tuple = serializer.serialize_tuple(len)?;
+        tuple.serialize_element(&version)?;
+        tuple.serialize_element(schedule.seconds().ordinals())?;
+        tuple.serialize_element(schedule.minutes().ordinals())?;
+        tuple.serialize_element(schedule.hours().ordinals())?;
+        tuple.serialize_element(schedule.days_of_month().ordinals())?;
+        tuple.serialize_element(schedule.months().ordinals())?;
+        tuple.serialize_element(schedule.days_of_week().ordinals())?;
+        tuple.serialize_element(schedule.years().ordinals())?;
+        tuple.serialize_element(&!schedule.is_year_bounded())?;
+        if version == FORMAT_VERSION {
+            tuple.serialize_element(&month_days)?;
+        }
+        tuple.end()
+    }
+}
+
+impl<'de> Deserialize<'de> for CompiledSchedule {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        deserializer.deserialize_tuple(LEN, CompiledScheduleVisitor)
+    }
+}
+
+struct CompiledScheduleVisitor;
+
+impl<'de> Visitor<'de> for CompiledScheduleVisitor {
+    type Value = CompiledSchedule;
+
+    fn expecting(&self, formatter: &mut Formatter<'_>) -> fmt::Result {
+        formatter.write_str("a compiled schedule")
+    }
+
+    fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error>
+    where
+        A: SeqAccess<'de>,
+    {
+        let version: u8 = next_element(&mut seq, 0)?;
+        if !(1..=FORMAT_VERSION).contains(&version) {
+            return Err(de::Error::custom(format!(
+                "unsupported compiled schedule version {} (expected at most {})",
+                version, FORMAT_VERSION
+            )));
+        }
+        let seconds: Seconds = next_field(&mut seq, 1)?;
+        let minutes: Minutes = next_field(&mut seq, 2)?;
+        let hours: Hours = next_field(&mut seq, 3)?;
+        let mut days_of_month: DaysOfMonth = next_field(&mut seq, 4)?;
+        let months: Months = next_field(&mut seq, 5)?;
+        let mut days_of_week: DaysOfWeek = next_field(&mut seq, 6)?;
+        let years: Years = next_field(&mut seq, 7)?;
+        let unbounded_years: bool = next_element(&mut seq, 8)?;
+        if version == FORMAT_VERSION {
+            let parts: Vec<(u8, i8, i8)> = next_element(&mut seq, 9)?;
+            let month_days = parts
+                .into_iter()
+                .map(month_day_from_parts)
+                .collect::<Result<Vec<_>, _>>()
+                .map_err(de::Error::custom)?;
+            let (of_month, of_week) = month_days
+                .into_iter()
+                .partition(|month_day| month_day.unit() == TimeUnit::DaysOfMonth);
+            days_of_month = days_of_month.with_month_days(of_month);
+            days_of_week = days_of_week.with_month_days(of_week);
+        }
+        let schedule = Schedule::from_fields(
+            seconds,
+            minutes,
+            hours,
+            days_of_month,
+            months,
+            days_of_week,
+            years,
+        );
+        let schedule = if unbounded_years {
+            schedule.with_unbounded_years()
+        } else {
+            schedule
+        };
+        Ok(CompiledSchedule::new(schedule))
+    }
+}
+
+fn next_element<'de, A, T>(seq: &mut A, index: usize) -> Result<T, A::Error>
+where
+    A: SeqAccess<'de>,
+    T: Deserialize<'de>,
+{
+    seq.next_element()?
+        .ok_or_else(|| de::Error::invalid_length(index, &CompiledScheduleVisitor))
+}
+
+// Month days are written as a kind followed by two values, so that more kinds
+// can be added without changing the format.
+fn month_day_parts(month_day: MonthDay) -> (u8, i8, i8) {
+    match month_day {
+        MonthDay::NthWeekday { weekday, nth } => (0, weekday as i8, nth),
+        MonthDay::LastDay { offset } => (1, offset, 0),
+        MonthDay::NearestWeekday { day } => (2, day, 0),
+        MonthDay::LastWeekday => (3, 0, 0),
+    }
+}
+
+fn month_day_from_parts((kind, first, second): (u8, i8, i8)) -> Result<MonthDay, Error> {
+    match kind {
+        0 => DaysOfWeek::month_day_from_root_specifier(&RootSpecifier::nth_weekday(
+            Ordinal::try_from(first).map_err(|_| DaysOfWeek::invalid_ordinal(first.into()))?,
+            second.into(),
+        )),
+        1 => DaysOfMonth::month_day_from_root_specifier(&RootSpecifier::LastDay(
+            u32::try_from(first).map_err(|_| DaysOfMonth::invalid_ordinal(first.into()))?,
+        )),
+        2 => DaysOfMonth::month_day_from_root_specifier(&RootSpecifier::NearestWeekday(
+            Ordinal::try_from(first).map_err(|_| DaysOfMonth::invalid_ordinal(first.into()))?,
+        )),
+        3 => DaysOfMonth::month_day_from_root_specifier(&RootSpecifier::LastWeekday),
+        _ => Err(ErrorKind::Expression(format!("Unknown kind of month day {}", kind)).into()),
+    }
+}
+
+/// Reads a field's ordinals, checking that the unit supports each of them.
+fn next_field<'de, A, T>(seq: &mut A, index: usize) -> Result<T, A::Error>
+where
+    A: SeqAccess<'de>,
+    T: TimeUnitField,
+{
+    let ordinals: OrdinalSet = next_element(seq, index)?;
+    // Sets are ordered, so only their bounds need checking.
+    for ordinal in [ordinals.iter().next(), ordinals.iter().next_back()]
+        .into_iter()
+        .flatten()
+    {
+        T::validate_ordinal(ordinal).map_err(de::Error::custom)?;
+    }
+    Ok(T::from_ordinal_set(ordinals))
+}
+
+#[cfg(test)]
+mod test {
+    use std::str::FromStr;
+
+    use jiff::{civil::date, tz::TimeZone};
+    use serde_test::{assert_de_tokens_error, Compact, Token};
+
+    use super::*;
+
+    #[test]
+    fn test_compiled_bytes() {
+        let schedule = Schedule::from_str("0 0 12 * * 2,4 2030-2040").unwrap();
+        let bytes = postcard::to_stdvec(&CompiledSchedule::from(schedule)).unwrap();
+        #[rustfmt::skip]
+        assert_eq!(
+            vec![
+                // Version 1, as there are no month days.
+                1,
+                // Seconds, minutes and hours as bitmasks.
+                0, 1,
+                0, 1,
+                0, 0x80, 0x20,
+                // Days of the month and months as ranges.
+                1, 1, 31,
+                1, 1, 12,
+                // Days of the week as a bitmask.
+                0, 0b10100,
+                // Years as a range.
+                1, 0xee, 0x0f, 0xf8, 0x0f,
+                // Whether the years are unbounded.
+                0,
+            ],
+            bytes
+        );
+    }
+
+    #[test]
+    fn test_compiled_postcard() {
+        for expression in [
+            "@hourly",
+            "0 30 9,12,15 1,15 May-Aug Mon,Wed,Fri 2018/2",
+            "0 0 0 29 2 * 1990,2000,2096",
+            "0 0 9 * * Mon#1,Fri#-2",
+            "0 0 9 1,L,L-3 * Fri#-1",
+            "0 0 9 15W,LW * *",
+        ] {
+            let schedule = Schedule::from_str(expression).unwrap();
+            let bytes = postcard::to_stdvec(&CompiledSchedule::from(schedule.clone())).unwrap();
+            let compiled: CompiledSchedule = postcard::from_bytes(&bytes).unwrap();
+            assert_eq!(schedule, *compiled.schedule());
+            assert_eq!(schedule.fingerprint(), compiled.schedule().fingerprint());
+        }
+
+        let schedule = Schedule::from_str("@daily").unwrap().with_unbounded_years();
+        let bytes = postcard::to_stdvec(&CompiledSchedule::from(schedule)).unwrap();
+        let schedule = postcard::from_bytes::<CompiledSchedule>(&bytes)
+            .unwrap()
+            .into_schedule();
+        let after = date(2100, 12, 31)
+            .at(12, 0, 0, 0)
+            .to_zoned(TimeZone::UTC)
+            .unwrap();
+        assert_eq!(
+            date(2101, 1, 1),
+            schedule.after(&after).next().unwrap().date()
+        );
+    }
+
+    #[test]
+    fn test_invalid_compiled_tokens() {
+        let mut tokens = vec![
+            Token::Tuple { len: 9 },
+            Token::U8(1),
+            Token::NewtypeVariant {
+                name: "OrdinalSet",
+                variant: "Range",
+            },
+            Token::Tuple { len: 2 },
+            Token::U32(0),
+            Token::U32(60),
+            Token::TupleEnd,
+        ];
+        assert_de_tokens_error::<Compact<CompiledSchedule>>(
+            &tokens,
+            "Invalid expression: Seconds must be less than 59. ('60' specified.)",
+        );
+
+        tokens[1] = Token::U8(3);
+        assert_de_tokens_error::<Compact<CompiledSchedule>>(
+            &tokens[..2],
+            "unsupported compiled schedule version 3 (expected at most 2)",
+        );
+    }
+}
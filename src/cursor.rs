@@ -0,0 +1,236 @@
+use std::{
+    fmt::{self, Display, Formatter},
+    str::FromStr,
+};
+
+use jiff::Zoned;
+#[cfg(feature = "serde")]
+use serde::{
+    de::{self, Visitor},
+    Deserialize, Serialize, Serializer,
+};
+
+use crate::error::{Error, ErrorKind};
+
+/// A checkpoint of a schedule iterator's position, from which iteration can
+/// be resumed with [`Schedule::resume`](crate::Schedule::resume), e.g. after
+/// a restart.
+///
+/// A cursor is written as the [fingerprint](crate::Schedule::fingerprint) of
+/// the schedule it was taken from, in hexadecimal, followed by a space and
+/// the last date-time the iterator returned (or started from) in jiff's
+/// RFC 9557 format. A `-` takes the place of the date-time once the iterator
+/// is exhausted. With the `serde` feature, cursors serialize to this string.
+///
+/// # Example
+///
+/// ```rust
+/// use std::str::FromStr;
+///
+/// use jiff_cron::{
+///     jiff::{civil::date, tz::TimeZone},
+///     IteratorCursor, Schedule,
+/// };
+///
+/// let schedule = Schedule::from_str("@daily").unwrap();
+/// let after = date(2025, 1, 1)
+///     .at(12, 0, 0, 0)
+///     .to_zoned(TimeZone::UTC)
+///     .unwrap();
+/// let mut iter = schedule.after(&after);
+/// iter.next();
+///
+/// let checkpoint = iter.cursor().to_string();
+/// assert_eq!(
+///     "c435d764c705f0d2 2025-01-02T00:00:00+00:00[UTC]",
+///     checkpoint
+/// );
+///
+/// let cursor = IteratorCursor::from_str(&checkpoint).unwrap();
+/// let mut resumed = schedule.resume(&cursor).unwrap();
+/// assert_eq!(date(2025, 1, 3), resumed.next().unwrap().date());
+/// ```
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct IteratorCursor {
+    fingerprint: u64,
+    position: Option<Zoned>,
+}
+
+impl IteratorCursor {
+    pub(crate) fn new(fingerprint: u64, position: Option<Zoned>) -> IteratorCursor {
+        IteratorCursor {
+            fingerprint,
+            position,
+        }
+    }
+
+    /// Returns the fingerprint of the schedule the cursor was taken from.
+    pub fn fingerprint(&self) -> u64 {
+        self.fingerprint
+    }
+
+    /// Returns the date-time iteration resumes from, or `None` if the
+    /// iterator was exhausted.
+    pub fn position(&self) -> Option<&Zoned> {
+        self.position.as_ref()
+    }
+}
+
+impl Display for IteratorCursor {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        match self.position {
+            Some(ref position) => write!(f, "{:016x} {}", self.fingerprint, position),
+            None => write!(f, "{:016x} -", self.fingerprint),
+        }
+    }
+}
+
+impl FromStr for IteratorCursor {
+    type Err = Error;
+
+    fn from_str(cursor: &str) -> Result<Self, Self::Err> {
+        let invalid = || -> Error {
+            ErrorKind::Expression(format!("Invalid iterator cursor: '{}'", cursor)).into()
+        };
+        let (fingerprint, position) = cursor.split_once(' ').ok_or_else(invalid)?;
+        if fingerprint.len() != 16 {
+            return Err(invalid());
+        }
+        let fingerprint = u64::from_str_radix(fingerprint, 16).map_err(|_| invalid())?;
+        let position = match position {
+            "-" => None,
+            position => Some(position.parse().map_err(|_| invalid())?),
+        };
+        Ok(IteratorCursor::new(fingerprint, position))
+    }
+}
+
+#[cfg(feature = "serde")]
+struct IteratorCursorVisitor;
+
+#[cfg(feature = "serde")]
+impl Visitor<'_> for IteratorCursorVisitor {
+    type Value = IteratorCursor;
+
+    fn expecting(&self, formatter: &mut Formatter<'_>) -> fmt::Result {
+        formatter.write_str("a schedule iterator cursor")
+    }
+
+    fn visit_str<E>(self, v: &str) -> Result<Self::Value, E>
+    where
+        E: de::Error,
+    {
+        IteratorCursor::from_str(v).map_err(de::Error::custom)
+    }
+}
+
+#[cfg(feature = "serde")]
+impl Serialize for IteratorCursor {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        serializer.collect_str(self)
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<'de> Deserialize<'de> for IteratorCursor {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        deserializer.deserialize_str(IteratorCursorVisitor)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use jiff::{civil::date, tz::TimeZone};
+    #[cfg(feature = "serde")]
+    use serde_test::{assert_tokens, Token};
+
+    use super::*;
+    use crate::Schedule;
+
+    #[test]
+    fn test_resume_from_cursor() {
+        let schedule = Schedule::from_str("0 */20 9-10 * * Mon-Fri").unwrap();
+        let after = date(2025, 3, 7)
+            .at(10, 30, 0, 0)
+            .to_zoned(TimeZone::get("Europe/London").unwrap())
+            .unwrap();
+        let expected: Vec<_> = schedule.after(&after).take(6).collect();
+
+        let mut iter = schedule.after(&after);
+        let mut fires: Vec<_> = iter.by_ref().take(3).collect();
+        let cursor = IteratorCursor::from_str(&iter.cursor().to_string()).unwrap();
+        assert_eq!(iter.cursor(), cursor);
+        fires.extend(schedule.resume(&cursor).unwrap().take(3));
+        assert_eq!(expected, fires);
+
+        let owned: Vec<_> = schedule
+            .clone()
+            .resume_owned(&cursor)
+            .unwrap()
+            .rev()
+            .take(2)
+            .collect();
+        assert_eq!(vec![fires[1].clone(), fires[0].clone()], owned);
+
+        let other = Schedule::from_str("0 */20 9-10 * * Mon-Thu").unwrap();
+        assert!(other.resume(&cursor).is_none());
+    }
+
+    #[test]
+    fn test_exhausted_cursor() {
+        let schedule = Schedule::from_str("0 0 0 1 1 * 2020").unwrap();
+        let after = date(2019, 6, 1)
+            .at(0, 0, 0, 0)
+            .to_zoned(TimeZone::UTC)
+            .unwrap();
+        let mut iter = schedule.after(&after);
+        assert!(iter.next().is_some());
+        assert!(iter.next().is_none());
+
+        let cursor = iter.cursor();
+        assert_eq!(None, cursor.position());
+        assert!(cursor.to_string().ends_with(" -"));
+        assert_eq!(
+            cursor,
+            IteratorCursor::from_str(&cursor.to_string()).unwrap()
+        );
+        assert_eq!(None, schedule.resume(&cursor).unwrap().next());
+    }
+
+    #[test]
+    fn test_invalid_cursors() {
+        for cursor in [
+            "",
+            "-",
+            "c435d764c705f0d2",
+            "c435d764 -",
+            "c435d764c705f0dz -",
+            "c435d764c705f0d2 2025-01-02",
+        ] {
+            assert!(IteratorCursor::from_str(cursor).is_err(), "{cursor}");
+        }
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn test_ser_de_cursor() {
+        let cursor =
+            IteratorCursor::from_str("c435d764c705f0d2 2025-01-02T00:00:00+00:00[UTC]").unwrap();
+        assert_tokens(
+            &cursor,
+            &[Token::Str(
+                "c435d764c705f0d2 2025-01-02T00:00:00+00:00[UTC]",
+            )],
+        );
+
+        let serialized = postcard::to_stdvec(&cursor).unwrap();
+        let deserialized: IteratorCursor = postcard::from_bytes(&serialized).unwrap();
+        assert_eq!(cursor, deserialized);
+    }
+}
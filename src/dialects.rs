@@ -0,0 +1,314 @@
+use std::{borrow::Cow, str::FromStr};
+
+use crate::{
+    capabilities::Dialect,
+    error::{Error, ErrorKind},
+    hashed,
+    ordinal::Ordinal,
+    schedule::Schedule,
+    time_unit::TimeUnit,
+};
+
+/// Parses `expression` as `dialect` does, rejecting features it doesn't
+/// accept or would read differently, resolving `H` with `hash_key` if given.
+pub(crate) fn parse(
+    expression: &str,
+    dialect: Dialect,
+    hash_key: Option<&[u8]>,
+) -> Result<Schedule, Error> {
+    match dialect {
+        Dialect::Quartz => parse_quartz(expression, hash_key),
+        Dialect::EventBridge => parse_event_bridge(expression, hash_key),
+        // Classic crons fire when either of the days matches, which the
+        // fields of a schedule can't describe.
+        Dialect::Crond => Err(Error::from(ErrorKind::Expression(format!(
+            "{} expressions can't be parsed",
+            dialect
+        )))
+        .with_expression(expression)),
+    }
+}
+
+fn parse_quartz(expression: &str, hash_key: Option<&[u8]>) -> Result<Schedule, Error> {
+    let saturday = last_day_of_week(expression, 5);
+    let resolved = match hash_key {
+        Some(key) => hashed::resolve(&saturday, key),
+        None => Cow::Borrowed(&*saturday),
+    };
+    let schedule: Schedule = resolved.parse()?;
+    check(expression, Dialect::Quartz, &schedule)?;
+    Ok(schedule)
+}
+
+// EventBridge writes its expressions as `cron(fields)` or `rate(value unit)`,
+// though the fields are accepted on their own too, as they're often copied
+// without the wrapping.
+fn parse_event_bridge(expression: &str, hash_key: Option<&[u8]>) -> Result<Schedule, Error> {
+    let not_in_event_bridge = |feature| not_in_dialect(expression, Dialect::EventBridge, feature);
+    let trimmed = expression.trim();
+    if let Some(rate) = unwrap_call(trimmed, "rate") {
+        return parse_rate(expression, rate);
+    }
+    let fields = unwrap_call(trimmed, "cron").unwrap_or(trimmed);
+    if fields.trim_start().starts_with('@') {
+        return Err(not_in_event_bridge("nicknames such as `@daily`"));
+    }
+    let words: Vec<&str> = fields.split_whitespace().collect();
+    if words.len() != 6 {
+        return Err(not_in_event_bridge(
+            "expressions other than six fields from the minutes to the years",
+        ));
+    }
+    let saturday = last_day_of_week(fields, 4);
+    let fields = match hash_key {
+        Some(key) => hashed::resolve_fields(&saturday, key, &TimeUnit::ALL[1..]),
+        None => Cow::Borrowed(&*saturday),
+    };
+    // EventBridge fires at the start of each minute. Errors of the fields are
+    // reported without spans, which would point into the expression with
+    // its seconds.
+    let schedule = Schedule::from_str(&format!("0 {}", fields))
+        .map_err(|error| Error::from(error.kind().clone()).with_expression(expression))?;
+    check(expression, Dialect::EventBridge, &schedule)?;
+    Ok(schedule)
+}
+
+// `rate(value unit)`, which fires every `value` minutes, hours or days. The
+// fire times are aligned to the clock rather than to when a rule was created,
+// so only rates dividing the next larger unit are accepted.
+fn parse_rate(expression: &str, rate: &str) -> Result<Schedule, Error> {
+    let invalid =
+        |message: String| Error::from(ErrorKind::Expression(message)).with_expression(expression);
+    let (value, unit) = rate
+        .trim()
+        .split_once(' ')
+        .ok_or_else(|| invalid(format!("'{}' must be a value and a unit", rate)))?;
+    let value: Ordinal = value
+        .parse()
+        .ok()
+        .filter(|&value| value > 0)
+        .ok_or_else(|| invalid(format!("'{}' must be a positive number", value)))?;
+    // EventBridge requires the singular for a value of 1 only.
+    let unit = unit.trim();
+    let (name, plural) = match unit.strip_suffix('s') {
+        Some(name) => (name, true),
+        None => (unit, false),
+    };
+    if !matches!(name, "minute" | "hour" | "day") || plural == (value == 1) {
+        return Err(invalid(format!(
+            "'{}' must be `minute`, `hour` or `day` for 1, and plural otherwise",
+            unit
+        )));
+    }
+    // Steps must fit within their field, so whole hours and days are written
+    // in the next larger unit.
+    let (name, value) = match (name, value) {
+        ("minute", 60) => ("hour", 1),
+        ("hour", 24) => ("day", 1),
+        unit => unit,
+    };
+    let fields = match name {
+        "minute" if 60 % value == 0 => format!("0 */{} * * * *", value),
+        "hour" if 24 % value == 0 => format!("0 0 */{} * * *", value),
+        "day" if value == 1 => "0 0 0 * * *".to_owned(),
+        _ => {
+            return Err(invalid(format!(
+                "'{}' isn't aligned to the clock, so no schedule fires at its rate",
+                rate.trim()
+            )))
+        }
+    };
+    Schedule::from_str(&fields)
+}
+
+// Quartz and EventBridge read a bare `L` in the days of the week, the field
+// at `index`, as the last day of the week, Saturday.
+fn last_day_of_week(fields: &str, index: usize) -> Cow<'_, str> {
+    let mut words: Vec<&str> = fields.split_whitespace().collect();
+    match words.get(index) {
+        Some(word) if word.eq_ignore_ascii_case("L") => {
+            words[index] = "7";
+            Cow::Owned(words.join(" "))
+        }
+        _ => Cow::Borrowed(fields),
+    }
+}
+
+// Rejects the features the capabilities of `schedule` report `dialect` doesn't
+// accept, so that parsing agrees with `Capabilities::is_portable_to`.
+fn check(expression: &str, dialect: Dialect, schedule: &Schedule) -> Result<(), Error> {
+    match schedule.capabilities().unsupported_in(dialect) {
+        Some(feature) => Err(not_in_dialect(expression, dialect, feature)),
+        None => Ok(()),
+    }
+}
+
+// The arguments of `name(arguments)`, in any case.
+fn unwrap_call<'a>(expression: &'a str, name: &str) -> Option<&'a str> {
+    let (call, arguments) = expression.split_once('(')?;
+    if !call.trim_end().eq_ignore_ascii_case(name) {
+        return None;
+    }
+    arguments.strip_suffix(')')
+}
+
+fn not_in_dialect(expression: &str, dialect: Dialect, feature: &str) -> Error {
+    Error::from(ErrorKind::NotInDialect {
+        dialect,
+        feature: feature.to_owned(),
+    })
+    .with_expression(expression)
+}
+
+#[cfg(test)]
+mod test {
+    use jiff::civil::date;
+
+    use super::*;
+
+    fn feature(expression: &str) -> String {
+        match parse(expression, Dialect::Quartz, None).unwrap_err().kind() {
+            ErrorKind::NotInDialect { feature, .. } => feature.clone(),
+            kind => panic!("unexpected error {:?}", kind),
+        }
+    }
+
+    #[test]
+    fn test_quartz() {
+        for expression in [
+            "0 0 12 ? * MON-FRI",
+            "0 15 10 L * ?",
+            "0 15 10 ? * 6L 2025-2030",
+            "0 0/5 14,18 * * ?",
+            "0 15 10 ? * 6#3",
+            "0 0 12 LW * ?",
+        ] {
+            assert!(
+                parse(expression, Dialect::Quartz, None).is_ok(),
+                "{}",
+                expression
+            );
+        }
+        // Quartz and this crate both number Sunday 1.
+        let schedule = parse("0 0 12 ? * 1", Dialect::Quartz, None).unwrap();
+        let start = date(2025, 1, 1).in_tz("UTC").unwrap();
+        assert_eq!(
+            date(2025, 1, 5),
+            schedule.after(&start).next().unwrap().date()
+        );
+        // A bare `L` is the last day of the week.
+        let schedule = parse("0 0 0 ? * L", Dialect::Quartz, None).unwrap();
+        assert_eq!(
+            parse("0 0 0 ? * SAT", Dialect::Quartz, None).unwrap(),
+            schedule
+        );
+        assert_eq!(
+            date(2025, 1, 4),
+            schedule.after(&start).next().unwrap().date()
+        );
+
+        assert!(feature("0 0 12 * * MON").contains("exactly one"));
+        assert!(feature("0 0 12 ? * ?").contains("exactly one"));
+        assert!(feature("0 0 12 1 * MON").contains("exactly one"));
+        assert!(feature("@daily").contains("nicknames"));
+        assert!(feature("0 */15+5 * ? * *").contains("step offsets"));
+        assert!(feature("0 0 12 ? * Fri#-2").contains("`Fri#-2`"));
+        assert!(feature("0 0 12 1 1 ? 2100").contains("2099"));
+        assert!(feature("0 0 12 ?*MON").contains("separated by whitespace"));
+
+        let error = parse("0 0 12 * * MON", Dialect::Quartz, None).unwrap_err();
+        assert_eq!("JC0016", error.code());
+        assert_eq!(Some("0 0 12 * * MON"), error.expression());
+        assert_eq!(
+            "Invalid expression: Quartz doesn't accept days of the month and days of the week \
+             unless exactly one of them is `?`.",
+            error.to_string()
+        );
+        // Errors of the expression itself are reported as usual.
+        assert_eq!(
+            "JC0003",
+            parse("0 0 25 ? * *", Dialect::Quartz, None)
+                .unwrap_err()
+                .code()
+        );
+    }
+
+    #[test]
+    fn test_event_bridge() {
+        let start = date(2025, 1, 1).in_tz("UTC").unwrap();
+        let next = |expression| {
+            let schedule = parse(expression, Dialect::EventBridge, None).unwrap();
+            schedule.after(&start).next().unwrap().datetime()
+        };
+        assert_eq!(date(2025, 1, 1).at(10, 15, 0, 0), next("15 10 * * ? *"));
+        assert_eq!(
+            date(2025, 1, 1).at(10, 15, 0, 0),
+            next("cron(15 10 * * ? *)")
+        );
+        // The last Friday of 2026.
+        assert_eq!(
+            date(2026, 1, 30).at(8, 0, 0, 0),
+            next("cron(0 8 ? * 6L 2026)")
+        );
+        assert_eq!(date(2025, 1, 31).at(0, 0, 0, 0), next("0 0 LW * ? *"));
+        assert_eq!(date(2025, 1, 4).at(12, 0, 0, 0), next("cron(0 12 ? * L *)"));
+        assert_eq!(date(2025, 1, 1).at(0, 5, 0, 0), next("rate(5 minutes)"));
+        assert_eq!(date(2025, 1, 1).at(1, 0, 0, 0), next("rate(1 hour)"));
+        assert_eq!(date(2025, 1, 1).at(1, 0, 0, 0), next("rate(60 minutes)"));
+        assert_eq!(date(2025, 1, 2).at(0, 0, 0, 0), next("rate(1 day)"));
+
+        let error = |expression| parse(expression, Dialect::EventBridge, None).unwrap_err();
+        let feature = |expression| match error(expression).kind() {
+            ErrorKind::NotInDialect { feature, .. } => feature.clone(),
+            kind => panic!("unexpected error {:?}", kind),
+        };
+        assert!(feature("0 0 12 ? * MON *").contains("six fields"));
+        assert!(feature("cron(0 12 * * * *)").contains("exactly one"));
+        assert!(feature("@daily").contains("nicknames"));
+        assert!(feature("*/15+5 * ? * * *").contains("step offsets"));
+        for expression in [
+            "rate(1 minutes)",
+            "rate(5 minute)",
+            "rate(7 minutes)",
+            "rate(2 days)",
+            "rate(0 hours)",
+        ] {
+            assert_eq!("JC0001", error(expression).code(), "{}", expression);
+        }
+        let error = error("cron(0 25 * * ? *)");
+        assert_eq!("JC0003", error.code());
+        assert_eq!(Some("cron(0 25 * * ? *)"), error.expression());
+        assert_eq!(None, error.span());
+    }
+
+    #[test]
+    fn test_parsing_agrees_with_capabilities() {
+        for expression in [
+            "0 0 12 ? * MON-FRI",
+            "0 0 12 * * MON",
+            "0 0 12 1 * MON",
+            "0 0 12 ? * ?",
+            "0 0 12 1 1 ? 2100",
+            "0 0 12 1 1 ? 2025-2030",
+            "0 0 12 ? * MON *",
+            "0 0 12 ?*MON",
+            "0 */15+5 * ? * *",
+            "0 0 12 ? * Fri#-2",
+            "0 0 12 ? * FriL",
+            "0 0 12 LW * ?",
+            "@daily",
+            "@weekday",
+        ] {
+            let portable = Schedule::from_str(expression)
+                .unwrap()
+                .capabilities()
+                .is_portable_to(Dialect::Quartz);
+            assert_eq!(
+                portable,
+                parse(expression, Dialect::Quartz, None).is_ok(),
+                "{}",
+                expression
+            );
+        }
+    }
+}
@@ -0,0 +1,44 @@
+use jiff::{civil::DateTime, Zoned};
+
+/// How a fire time affected by a DST transition was resolved, see
+/// [`DstOccurrence`].
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum DstResolution {
+    /// The local time was skipped when the clocks went forward, so the
+    /// schedule fires as much later as the clocks moved.
+    Shifted,
+    /// The local time was skipped when the clocks went forward, and moving it
+    /// later landed on a time the schedule fires at anyway, so it only fires
+    /// once then.
+    Merged,
+    /// The local time happened twice when the clocks went back, and the
+    /// schedule only fires the first time.
+    Earlier,
+}
+
+/// A fire time affected by a DST transition, returned by
+/// [`Schedule::dst_audit`](crate::Schedule::dst_audit).
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct DstOccurrence {
+    pub(crate) scheduled: DateTime,
+    pub(crate) fire: Zoned,
+    pub(crate) resolution: DstResolution,
+}
+
+impl DstOccurrence {
+    /// Returns the local time the schedule describes.
+    pub fn scheduled(&self) -> DateTime {
+        self.scheduled
+    }
+
+    /// Returns when the schedule actually fires for the local time.
+    pub fn fire(&self) -> &Zoned {
+        &self.fire
+    }
+
+    /// Returns how the fire time was resolved.
+    pub fn resolution(&self) -> DstResolution {
+        self.resolution
+    }
+}
@@ -1,30 +1,638 @@
-use std::{error, fmt};
+use std::{error, fmt, ops::Range};
+
+use jiff::{SignedDuration, Zoned};
+
+use crate::{
+    budget::SearchBudget,
+    capabilities::Dialect,
+    ordinal::Ordinal,
+    time_unit::{
+        DaysOfMonth, DaysOfWeek, Hours, Minutes, Months, Seconds, TimeUnit, TimeUnitField,
+    },
+    transpose::TransposeObstacle,
+};
 
 /// A cron error
 #[derive(Debug)]
 pub struct Error {
     kind: ErrorKind,
+    // Boxed to keep `Result<_, Error>` small, since most errors are only
+    // formatted or discarded.
+    context: Option<Box<Context>>,
+}
+
+/// Where in the parsed text an error was found.
+#[derive(Debug, Default)]
+struct Context {
+    expression: Option<String>,
+    field_index: Option<usize>,
+    span: Option<Range<usize>>,
+    token_span: Option<Range<usize>>,
+}
+
+impl Error {
+    /// Returns the kind of error that occurred.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use std::str::FromStr;
+    ///
+    /// use jiff_cron::{error::ErrorKind, Schedule, TimeUnit};
+    ///
+    /// let error = Schedule::from_str("0 0 25 * * *").unwrap_err();
+    /// assert_eq!(
+    ///     &ErrorKind::InvalidOrdinal {
+    ///         unit: TimeUnit::Hours,
+    ///         value: 25,
+    ///         min: 0,
+    ///         max: 23,
+    ///     },
+    ///     error.kind()
+    /// );
+    /// assert_eq!(Some(TimeUnit::Hours), error.kind().unit());
+    /// ```
+    pub fn kind(&self) -> &ErrorKind {
+        &self.kind
+    }
+
+    /// Returns the byte range of the invalid field within the parsed text, if
+    /// the error concerns a single field, for pointing it out to users.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use std::str::FromStr;
+    ///
+    /// use jiff_cron::Schedule;
+    ///
+    /// let expression = "0 0 9-17 * Jun-Aux Mon-Fri";
+    /// let error = Schedule::from_str(expression).unwrap_err();
+    /// assert_eq!("Jun-Aux", &expression[error.span().unwrap()]);
+    /// ```
+    pub fn span(&self) -> Option<Range<usize>> {
+        self.context.as_ref()?.span.clone()
+    }
+
+    /// Returns the text which failed to parse, i.e. the whole expression, or
+    /// the field for errors from a single unit's `FromStr` implementation.
+    pub fn expression(&self) -> Option<&str> {
+        self.context.as_ref()?.expression.as_deref()
+    }
+
+    /// Returns the zero-based position of the invalid field within the
+    /// expression, counting the seconds as the first field.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use std::str::FromStr;
+    ///
+    /// use jiff_cron::Schedule;
+    ///
+    /// let error = Schedule::from_str("0 0 9-17 * * Mon-Fri 1999,20x5").unwrap_err();
+    /// assert_eq!(Some(6), error.field_index());
+    /// ```
+    pub fn field_index(&self) -> Option<usize> {
+        self.context.as_ref()?.field_index
+    }
+
+    /// Returns the exact text responsible for the error within
+    /// [`Error::expression`]: the element of the field's comma-separated list
+    /// which was rejected, or the whole field if it couldn't be split up.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use std::str::FromStr;
+    ///
+    /// use jiff_cron::Schedule;
+    ///
+    /// let error = Schedule::from_str("0 0 9-17,21-19 * * Mon-Fri").unwrap_err();
+    /// assert_eq!(Some("21-19"), error.token());
+    /// assert_eq!(Some(9..14), error.token_span());
+    /// assert_eq!(Some("0 0 9-17,21-19 * * Mon-Fri"), error.expression());
+    /// ```
+    pub fn token(&self) -> Option<&str> {
+        let context = self.context.as_ref()?;
+        context
+            .expression
+            .as_deref()?
+            .get(context.token_span.clone()?)
+    }
+
+    /// Returns the byte range of [`Error::token`] within
+    /// [`Error::expression`].
+    pub fn token_span(&self) -> Option<Range<usize>> {
+        self.context.as_ref()?.token_span.clone()
+    }
+
+    /// Returns the stable code of the error's kind, e.g. `JC0003`. See
+    /// [`ErrorKind::code`].
+    pub fn code(&self) -> &'static str {
+        self.kind.code()
+    }
+
+    /// Returns a hint at how to fix the expression, if there's a likely fix,
+    /// such as the name closest to an unknown one. The hint is also included
+    /// when the error is displayed, so command line tools parsing arguments
+    /// with [`FromStr`](std::str::FromStr) show it to users.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use std::str::FromStr;
+    ///
+    /// use jiff_cron::Schedule;
+    ///
+    /// let error = Schedule::from_str("0 0 9 * * Mun").unwrap_err();
+    /// assert_eq!(Some("Did you mean 'Mon'?".to_owned()), error.help());
+    /// assert_eq!(
+    ///     "Invalid expression: 'Mun' is not a valid name for Days of Week. Did you mean 'Mon'?",
+    ///     error.to_string()
+    /// );
+    ///
+    /// let error = Schedule::from_str("0 22-2 * * *").unwrap_err();
+    /// assert_eq!(
+    ///     Some("Did you mean '0 0 22-2 * * *'?".to_owned()),
+    ///     error.help()
+    /// );
+    ///
+    /// let error = Schedule::from_str("0 0 22-2 * * *").unwrap_err();
+    /// assert_eq!(Some("Did you mean '22-23,0-2'?".to_owned()), error.help());
+    /// ```
+    pub fn help(&self) -> Option<String> {
+        let suggestion = match self.kind {
+            ErrorKind::UnknownName { unit, ref name } => closest_name(unit, name)?.to_owned(),
+            // Ranges of `H` are picked from rather than iterated, so they
+            // don't wrap.
+            ErrorKind::InvalidRange {
+                unit,
+                ref start,
+                ref end,
+            } if !self.token().is_some_and(|token| token.starts_with('H')) => {
+                wrapped_range(unit, start, end)?
+            }
+            // Five fields are most likely a classic cron expression without
+            // seconds.
+            ErrorKind::WrongFieldCount { found: 5 } => format!("0 {}", self.expression()?.trim()),
+            _ => return None,
+        };
+        Some(format!("Did you mean '{}'?", suggestion))
+    }
+
+    fn context(&mut self) -> &mut Context {
+        self.context.get_or_insert_with(Box::default)
+    }
+
+    pub(crate) fn with_span(mut self, span: Range<usize>) -> Error {
+        self.context().span = Some(span);
+        self
+    }
+
+    pub(crate) fn with_token_span(mut self, span: Range<usize>) -> Error {
+        self.context().token_span = Some(span);
+        self
+    }
+
+    pub(crate) fn with_field_index(mut self, index: usize) -> Error {
+        self.context().field_index = Some(index);
+        self
+    }
+
+    /// Moves the error's spans from a part of `expression`, starting at
+    /// `offset`, to `expression` itself.
+    pub(crate) fn within(mut self, expression: &str, offset: usize) -> Error {
+        let shift = |span: &mut Option<Range<usize>>| {
+            if let Some(span) = span {
+                *span = span.start + offset..span.end + offset;
+            }
+        };
+        let context = self.context();
+        shift(&mut context.span);
+        shift(&mut context.token_span);
+        self.with_expression(expression)
+    }
+
+    pub(crate) fn with_expression(mut self, expression: &str) -> Error {
+        self.context().expression = Some(expression.to_owned());
+        self
+    }
 }
 
 /// The kind of cron error that occurred
-#[derive(Debug)]
+#[derive(Clone, Debug, PartialEq, Eq)]
+#[non_exhaustive]
 pub enum ErrorKind {
     /// Failed to parse an expression
     Expression(String),
+    /// A field's text isn't a valid list of specifiers, e.g. `1-` or `*/`
+    InvalidField {
+        /// The unit of time of the field
+        unit: TimeUnit,
+        /// The field as written
+        field: String,
+    },
+    /// An ordinal is outside the range the unit supports, e.g. the hour `25`
+    InvalidOrdinal {
+        /// The unit of time of the field
+        unit: TimeUnit,
+        /// The ordinal as specified
+        value: i64,
+        /// The smallest supported ordinal
+        min: Ordinal,
+        /// The largest supported ordinal
+        max: Ordinal,
+    },
+    /// A range ends before it starts, e.g. `5-3` or `Fri-Mon`
+    InvalidRange {
+        /// The unit of time of the field
+        unit: TimeUnit,
+        /// The start of the range as written
+        start: String,
+        /// The end of the range as written
+        end: String,
+    },
+    /// A step of zero, e.g. `*/0`
+    ZeroStep {
+        /// The unit of time of the field
+        unit: TimeUnit,
+    },
+    /// A step larger than the unit's largest ordinal, e.g. the minutes `*/60`
+    InvalidStep {
+        /// The unit of time of the field
+        unit: TimeUnit,
+        /// The step as specified
+        step: u32,
+        /// The largest supported step
+        max: Ordinal,
+    },
+    /// A step offset which isn't smaller than its step, e.g. `*/15+15`
+    InvalidOffset {
+        /// The unit of time of the field
+        unit: TimeUnit,
+        /// The offset as specified
+        offset: u32,
+        /// The step the offset applies to
+        step: u32,
+    },
+    /// A name the unit doesn't define, e.g. `Mon` for hours or `Mun` for days
+    /// of the week
+    UnknownName {
+        /// The unit of time of the field
+        unit: TimeUnit,
+        /// The name as written
+        name: String,
+    },
+    /// An expression has neither six nor seven fields
+    WrongFieldCount {
+        /// The number of fields found
+        found: usize,
+    },
+    /// A bounded search spent its [`SearchBudget`] without finding a fire time
+    SearchExhausted(SearchBudget),
+    /// The opening and closing times of a
+    /// [`WindowSchedule`](crate::WindowSchedule) don't alternate: another
+    /// opening time or closing time than expected, or both at once, fall on
+    /// the provided time
+    NotAlternating {
+        /// When the opening or closing time out of turn falls
+        at: Zoned,
+    },
+    /// A schedule fires more often than [`ParseOptions`](crate::ParseOptions)
+    /// allow
+    IntervalTooShort {
+        /// The shortest time between the schedule's fire times
+        interval: SignedDuration,
+        /// The shortest time allowed
+        min: SignedDuration,
+    },
+    /// A schedule iterator ran out of fire times, as the years field's range
+    /// ended, returned by
+    /// [`ScheduleIterator::with_end_marker`](crate::ScheduleIterator::with_end_marker)
+    EndOfSchedule {
+        /// The last fire time returned, if any
+        last: Option<Zoned>,
+    },
+    /// A schedule can't be expressed in another time zone by
+    /// [`Schedule::transpose`](crate::Schedule::transpose), for the provided
+    /// reason
+    NotTransposable(TransposeObstacle),
+    /// An occurrence of a day of the week within its month which is zero or
+    /// further from it than any month has, e.g. `Fri#6`
+    InvalidOccurrence {
+        /// The unit of time of the field
+        unit: TimeUnit,
+        /// The occurrence as specified
+        nth: i32,
+    },
+    /// An expression uses a feature which the dialect it's parsed as, with
+    /// [`ParseOptions::with_dialect`](crate::ParseOptions::with_dialect),
+    /// doesn't accept or would read differently
+    NotInDialect {
+        /// The dialect the expression is parsed as
+        dialect: Dialect,
+        /// The feature, e.g. "step offsets such as `*/15+5`"
+        feature: String,
+    },
+    /// A field uses Jenkins' `H` without a key to derive its value from, set
+    /// with [`ParseOptions::with_hash_key`](crate::ParseOptions::with_hash_key)
+    HashWithoutKey {
+        /// The unit of time of the field
+        unit: TimeUnit,
+        /// The specifier as written, e.g. `H/15`
+        field: String,
+    },
+}
+
+impl ErrorKind {
+    /// Returns a code identifying the kind of error, which unlike the error's
+    /// message never changes between releases, and is never reused for
+    /// another kind.
+    ///
+    /// | Code     | Kind                                |
+    /// |----------|-------------------------------------|
+    /// | `JC0001` | [`ErrorKind::Expression`]           |
+    /// | `JC0002` | [`ErrorKind::InvalidField`]         |
+    /// | `JC0003` | [`ErrorKind::InvalidOrdinal`]       |
+    /// | `JC0004` | [`ErrorKind::InvalidRange`]         |
+    /// | `JC0005` | [`ErrorKind::ZeroStep`]             |
+    /// | `JC0006` | [`ErrorKind::InvalidStep`]          |
+    /// | `JC0007` | [`ErrorKind::UnknownName`]          |
+    /// | `JC0008` | [`ErrorKind::WrongFieldCount`]      |
+    /// | `JC0009` | [`ErrorKind::SearchExhausted`]      |
+    /// | `JC0010` | [`ErrorKind::NotAlternating`]       |
+    /// | `JC0011` | [`ErrorKind::IntervalTooShort`]     |
+    /// | `JC0012` | [`ErrorKind::InvalidOffset`]        |
+    /// | `JC0013` | [`ErrorKind::EndOfSchedule`]        |
+    /// | `JC0014` | [`ErrorKind::NotTransposable`]      |
+    /// | `JC0015` | [`ErrorKind::InvalidOccurrence`]    |
+    /// | `JC0016` | [`ErrorKind::NotInDialect`]         |
+    /// | `JC0017` | [`ErrorKind::HashWithoutKey`]       |
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use std::str::FromStr;
+    ///
+    /// use jiff_cron::Schedule;
+    ///
+    /// let error = Schedule::from_str("0 0 9 * Mun *").unwrap_err();
+    /// assert_eq!("JC0007", error.code());
+    /// ```
+    pub fn code(&self) -> &'static str {
+        match *self {
+            ErrorKind::Expression(_) => "JC0001",
+            ErrorKind::InvalidField { .. } => "JC0002",
+            ErrorKind::InvalidOrdinal { .. } => "JC0003",
+            ErrorKind::InvalidRange { .. } => "JC0004",
+            ErrorKind::ZeroStep { .. } => "JC0005",
+            ErrorKind::InvalidStep { .. } => "JC0006",
+            ErrorKind::UnknownName { .. } => "JC0007",
+            ErrorKind::WrongFieldCount { .. } => "JC0008",
+            ErrorKind::SearchExhausted(_) => "JC0009",
+            ErrorKind::NotAlternating { .. } => "JC0010",
+            ErrorKind::IntervalTooShort { .. } => "JC0011",
+            ErrorKind::InvalidOffset { .. } => "JC0012",
+            ErrorKind::EndOfSchedule { .. } => "JC0013",
+            ErrorKind::NotTransposable(_) => "JC0014",
+            ErrorKind::InvalidOccurrence { .. } => "JC0015",
+            ErrorKind::NotInDialect { .. } => "JC0016",
+            ErrorKind::HashWithoutKey { .. } => "JC0017",
+        }
+    }
+
+    /// Returns the unit of time of the field the error was found in, if it
+    /// concerns a single field.
+    pub fn unit(&self) -> Option<TimeUnit> {
+        match *self {
+            ErrorKind::InvalidField { unit, .. }
+            | ErrorKind::InvalidOrdinal { unit, .. }
+            | ErrorKind::InvalidRange { unit, .. }
+            | ErrorKind::ZeroStep { unit }
+            | ErrorKind::InvalidStep { unit, .. }
+            | ErrorKind::InvalidOffset { unit, .. }
+            | ErrorKind::UnknownName { unit, .. }
+            | ErrorKind::InvalidOccurrence { unit, .. }
+            | ErrorKind::HashWithoutKey { unit, .. } => Some(unit),
+            ErrorKind::Expression(_)
+            | ErrorKind::WrongFieldCount { .. }
+            | ErrorKind::SearchExhausted(_)
+            | ErrorKind::NotAlternating { .. }
+            | ErrorKind::IntervalTooShort { .. }
+            | ErrorKind::EndOfSchedule { .. }
+            | ErrorKind::NotTransposable(_)
+            | ErrorKind::NotInDialect { .. } => None,
+        }
+    }
 }
 
 impl fmt::Display for Error {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.fmt_kind(f)?;
+        if let Some(help) = self.help() {
+            write!(f, " {}", help)?;
+        }
+        Ok(())
+    }
+}
+
+/// Formats the error as `JC0001: ` followed by its message.
+#[cfg(feature = "defmt")]
+impl defmt::Format for Error {
+    fn format(&self, f: defmt::Formatter<'_>) {
+        defmt::write!(f, "{=str}: {}", self.code(), defmt::Display2Format(self))
+    }
+}
+
+impl Error {
+    fn fmt_kind(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         match self.kind {
             ErrorKind::Expression(ref expr) => write!(f, "Invalid expression: {}", expr),
+            ErrorKind::InvalidField { unit, ref field } => {
+                write!(
+                    f,
+                    "Invalid expression: Invalid field for {}: '{}'",
+                    unit, field
+                )
+            }
+            ErrorKind::InvalidOrdinal {
+                unit, value, min, ..
+            } if value < i64::from(min) => write!(
+                f,
+                "Invalid expression: {} must be greater than or equal to {}. ('{}' specified.)",
+                unit, min, value
+            ),
+            ErrorKind::InvalidOrdinal {
+                unit, value, max, ..
+            } => write!(
+                f,
+                "Invalid expression: {} must be less than {}. ('{}' specified.)",
+                unit, max, value
+            ),
+            ErrorKind::InvalidRange {
+                unit,
+                ref start,
+                ref end,
+            } => write!(
+                f,
+                "Invalid expression: Invalid range for {}: {}-{}",
+                unit, start, end
+            ),
+            ErrorKind::ZeroStep { unit } => {
+                write!(f, "Invalid expression: {} step cannot be zero", unit)
+            }
+            ErrorKind::InvalidStep { unit, step, max } => write!(
+                f,
+                "Invalid expression: {} step must be between 1 and {}. ('{}' specified.)",
+                unit, max, step
+            ),
+            ErrorKind::InvalidOffset { unit, offset, step } => write!(
+                f,
+                "Invalid expression: {} step offset must be less than the step of {}. ('{}' \
+                 specified.)",
+                unit, step, offset
+            ),
+            ErrorKind::UnknownName { unit, ref name } => write!(
+                f,
+                "Invalid expression: '{}' is not a valid name for {}.",
+                name, unit
+            ),
+            ErrorKind::WrongFieldCount { found } => write!(
+                f,
+                "Invalid expression: Expected 6 or 7 fields, found {}.",
+                found
+            ),
+            ErrorKind::SearchExhausted(budget) => {
+                write!(f, "No fire time found within {}", budget)
+            }
+            ErrorKind::NotAlternating { ref at } => {
+                write!(f, "Opening and closing times don't alternate at {}", at)
+            }
+            ErrorKind::IntervalTooShort { interval, min } => write!(
+                f,
+                "Invalid expression: Fires {:#} apart, but must fire at least {:#} apart",
+                interval, min
+            ),
+            ErrorKind::EndOfSchedule {
+                last: Some(ref last),
+            } => {
+                write!(f, "The schedule doesn't fire after {}", last)
+            }
+            ErrorKind::EndOfSchedule { last: None } => {
+                write!(f, "The schedule doesn't fire again")
+            }
+            ErrorKind::NotTransposable(ref obstacle) => write!(
+                f,
+                "The schedule can't be expressed in the other time zone: {}",
+                obstacle
+            ),
+            ErrorKind::InvalidOccurrence { unit, nth } => write!(
+                f,
+                "Invalid expression: {} occurrence must be between 1 and 5 or -5 and -1. ('{}' \
+                 specified.)",
+                unit, nth
+            ),
+            ErrorKind::NotInDialect {
+                dialect,
+                ref feature,
+            } => write!(
+                f,
+                "Invalid expression: {} doesn't accept {}.",
+                dialect, feature
+            ),
+            ErrorKind::HashWithoutKey { unit, ref field } => write!(
+                f,
+                "Invalid expression: '{}' in {} requires a hash key, set with \
+                 `ParseOptions::with_hash_key`.",
+                field, unit
+            ),
         }
     }
 }
 
 impl error::Error for Error {}
 
+// Names further than this many edits from the unknown name aren't suggested.
+const MAX_SUGGESTION_DISTANCE: usize = 2;
+
+fn closest_name(unit: TimeUnit, name: &str) -> Option<&'static str> {
+    let names = match unit {
+        TimeUnit::DaysOfWeek => DaysOfWeek::names(),
+        TimeUnit::Months => Months::names(),
+        _ => return None,
+    };
+    let name = name.to_ascii_lowercase();
+    names
+        .iter()
+        .map(|&(long, short)| {
+            let distance = |candidate: &str| edit_distance(&name, &candidate.to_ascii_lowercase());
+            (distance(long).min(distance(short)), short)
+        })
+        .filter(|&(distance, _)| distance <= MAX_SUGGESTION_DISTANCE && distance < name.len())
+        // Ties go to names with the same first letter, as in `Mon` for `Mun`
+        // rather than `Sun`.
+        .min_by_key(|&(distance, short)| {
+            let same_initial = name.chars().next()
+                == short
+                    .chars()
+                    .next()
+                    .map(|initial| initial.to_ascii_lowercase());
+            (distance, !same_initial)
+        })
+        .map(|(_, short)| short)
+}
+
+fn edit_distance(a: &str, b: &str) -> usize {
+    let b: Vec<char> = b.chars().collect();
+    let mut row: Vec<usize> = (0..=b.len()).collect();
+    for (i, a) in a.chars().enumerate() {
+        let mut diagonal = row[0];
+        row[0] = i + 1;
+        for (j, &b) in b.iter().enumerate() {
+            let substitution = diagonal + usize::from(a != b);
+            diagonal = row[j + 1];
+            row[j + 1] = substitution.min(row[j] + 1).min(diagonal + 1);
+        }
+    }
+    row[b.len()]
+}
+
+// Suggests the range wrapping around the end of the unit which was most likely
+// meant, e.g. `22-23,0-2` for the hours `22-2`, or `Sat,Sun` for `Sat-Sun`.
+fn wrapped_range(unit: TimeUnit, start: &str, end: &str) -> Option<String> {
+    let (first, last) = if start.parse::<Ordinal>().is_ok() {
+        let (min, max) = match unit {
+            TimeUnit::Seconds => (Seconds::inclusive_min(), Seconds::inclusive_max()),
+            TimeUnit::Minutes => (Minutes::inclusive_min(), Minutes::inclusive_max()),
+            TimeUnit::Hours => (Hours::inclusive_min(), Hours::inclusive_max()),
+            TimeUnit::DaysOfMonth => (DaysOfMonth::inclusive_min(), DaysOfMonth::inclusive_max()),
+            TimeUnit::Months => (Months::inclusive_min(), Months::inclusive_max()),
+            TimeUnit::DaysOfWeek => (DaysOfWeek::inclusive_min(), DaysOfWeek::inclusive_max()),
+            // Years don't repeat.
+            TimeUnit::Years => return None,
+        };
+        (min.to_string(), max.to_string())
+    } else {
+        let names = match unit {
+            TimeUnit::DaysOfWeek => DaysOfWeek::names(),
+            TimeUnit::Months => Months::names(),
+            _ => return None,
+        };
+        (names[0].1.to_owned(), names[names.len() - 1].1.to_owned())
+    };
+    let half = |start: &str, end: &str| match start.eq_ignore_ascii_case(end) {
+        true => start.to_owned(),
+        false => format!("{}-{}", start, end),
+    };
+    Some(format!("{},{}", half(start, &last), half(&first, end)))
+}
+
 impl From<ErrorKind> for Error {
     fn from(kind: ErrorKind) -> Error {
-        Error { kind }
+        Error {
+            kind,
+            context: None,
+        }
     }
 }
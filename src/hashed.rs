@@ -0,0 +1,146 @@
+use std::borrow::Cow;
+
+use crate::{
+    fnv::fnv1a,
+    ordinal::Ordinal,
+    parsing::parse_words,
+    specifier::{RootSpecifier, Specifier},
+    time_unit::{
+        DaysOfMonth, DaysOfWeek, Hours, Minutes, Months, Seconds, TimeUnit, TimeUnitField,
+    },
+};
+
+/// Replaces each `H` of `expression` with the value derived from `key` for its
+/// field, e.g. `H/15` with `7-59/15` for the minutes.
+///
+/// Each field hashes the key along with its unit, so that the fields of an
+/// expression such as `H H * * *` are independent. Invalid specifiers are
+/// left as written for the parser to reject.
+pub(crate) fn resolve<'a>(expression: &'a str, key: &[u8]) -> Cow<'a, str> {
+    let units = match expression.split_whitespace().count() {
+        5 => &TimeUnit::ALL[1..6],
+        count @ (6 | 7) => &TimeUnit::ALL[..count],
+        _ => return Cow::Borrowed(expression),
+    };
+    resolve_fields(expression, key, units)
+}
+
+/// Replaces each `H` of `expression` as [`resolve`] does, given the unit of
+/// each of its fields, for dialects laying their fields out differently.
+pub(crate) fn resolve_fields<'a>(
+    expression: &'a str,
+    key: &[u8],
+    units: &[TimeUnit],
+) -> Cow<'a, str> {
+    let words: Vec<&str> = expression.split_whitespace().collect();
+    if words.len() != units.len() || !words.iter().any(|word| word.contains('H')) {
+        return Cow::Borrowed(expression);
+    }
+    let words: Vec<String> = words
+        .iter()
+        .zip(units)
+        .map(|(word, &unit)| {
+            word.split(',')
+                .map(|part| resolve_part(part, unit, key))
+                .collect::<Vec<_>>()
+                .join(",")
+        })
+        .collect();
+    Cow::Owned(words.join(" "))
+}
+
+fn resolve_part<'a>(part: &'a str, unit: TimeUnit, key: &[u8]) -> Cow<'a, str> {
+    if !part.contains('H') {
+        return Cow::Borrowed(part);
+    }
+    let Some(fields) = parse_words(&[part]) else {
+        return Cow::Borrowed(part);
+    };
+    let (range, step) = match fields[0].specifiers() {
+        [RootSpecifier::Hashed(range, step)] => (range, *step),
+        _ => return Cow::Borrowed(part),
+    };
+    let Some((unit_min, unit_max, default_max)) = bounds(unit) else {
+        return Cow::Borrowed(part);
+    };
+    let (min, max) = match *range {
+        Specifier::Range(min, max) => (min, max),
+        _ => (unit_min, default_max),
+    };
+    if min > max || min < unit_min || max > unit_max || step == Some(0) {
+        return Cow::Borrowed(part);
+    }
+    let mut hashed = key.to_vec();
+    hashed.push(
+        TimeUnit::ALL
+            .iter()
+            .position(|&other| other == unit)
+            .unwrap_or(0) as u8,
+    );
+    let hash = fnv1a(&hashed);
+    let values = max - min + 1;
+    let value = |count: Ordinal| min + (hash % u64::from(count)) as Ordinal;
+    Cow::Owned(match step {
+        Some(step) => format!("{}-{}/{}", value(step.min(values)), max, step),
+        None => value(values).to_string(),
+    })
+}
+
+// The unit's bounds, and the largest value `H` picks without a range, which
+// for the days of the month is the 28th, so that it falls in every month.
+fn bounds(unit: TimeUnit) -> Option<(Ordinal, Ordinal, Ordinal)> {
+    let bounds = match unit {
+        TimeUnit::Seconds => (Seconds::inclusive_min(), Seconds::inclusive_max()),
+        TimeUnit::Minutes => (Minutes::inclusive_min(), Minutes::inclusive_max()),
+        TimeUnit::Hours => (Hours::inclusive_min(), Hours::inclusive_max()),
+        TimeUnit::DaysOfMonth => {
+            return Some((
+                DaysOfMonth::inclusive_min(),
+                DaysOfMonth::inclusive_max(),
+                28,
+            ))
+        }
+        TimeUnit::Months => (Months::inclusive_min(), Months::inclusive_max()),
+        TimeUnit::DaysOfWeek => (DaysOfWeek::inclusive_min(), DaysOfWeek::inclusive_max()),
+        // Years have no cycle to spread values over.
+        TimeUnit::Years => return None,
+    };
+    Some((bounds.0, bounds.1, bounds.1))
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_resolve() {
+        let resolved = resolve("0 H H(0-5) H/10 * H(2-6) *", b"nightly-backup");
+        let words: Vec<&str> = resolved.split_whitespace().collect();
+        let minute: Ordinal = words[1].parse().unwrap();
+        assert!(minute < 60);
+        let hour: Ordinal = words[2].parse().unwrap();
+        assert!(hour <= 5);
+        let (start, rest) = words[3].split_once('-').unwrap();
+        assert!(start.parse::<Ordinal>().unwrap() < 10);
+        assert_eq!("28/10", rest);
+        let weekday: Ordinal = words[5].parse().unwrap();
+        assert!((2..=6).contains(&weekday));
+        // Stable across calls and releases.
+        assert_eq!(
+            resolved,
+            resolve("0 H H(0-5) H/10 * H(2-6) *", b"nightly-backup")
+        );
+        assert_eq!("0 30 5 9-28/10 * 3 *", resolved);
+
+        // Other keys spread out.
+        assert_ne!(
+            resolved,
+            resolve("0 H H(0-5) H/10 * H(2-6) *", b"weekly-report")
+        );
+        // Other specifiers are kept as written.
+        assert_eq!("0 0 9 ? * Mon-Fri", resolve("0 0 9 ? * Mon-Fri", b"key"));
+        // Invalid ranges are left for the parser to reject.
+        assert_eq!("0 H(10-5) * * * *", resolve("0 H(10-5) * * * *", b"key"));
+        assert_eq!("0 H(0-60) * * * *", resolve("0 H(0-60) * * * *", b"key"));
+    }
+}
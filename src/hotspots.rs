@@ -0,0 +1,275 @@
+use std::collections::BTreeMap;
+
+use jiff::{SignedDuration, Span, Timestamp, Zoned};
+
+use crate::{adapters::FireTimes, recurrence::Recurrence, schedule::Schedule};
+
+/// Finds the times at which many schedules of a fleet fire together, such as
+/// every job of a team written as `0 0 0 * * *`, which cause load spikes on
+/// whatever the jobs share.
+///
+/// Time is cut into buckets of a fixed length, aligned to the Unix epoch, and
+/// a bucket in which enough different schedules fire is reported as a
+/// [`Hotspot`], along with offsets which would spread its schedules out.
+///
+/// # Example
+///
+/// ```rust
+/// use std::str::FromStr;
+///
+/// use jiff_cron::{
+///     jiff::{civil::date, tz::TimeZone, SignedDuration, ToSpan},
+///     HotspotAnalyzer, Schedule,
+/// };
+///
+/// let schedules: Vec<_> = ["@daily", "0 0 0 * * *", "0 0 0,12 * * *", "0 17 3 * * *"]
+///     .into_iter()
+///     .map(|expression| Schedule::from_str(expression).unwrap())
+///     .collect();
+/// let from = date(2024, 12, 31)
+///     .at(12, 0, 0, 0)
+///     .to_zoned(TimeZone::UTC)
+///     .unwrap();
+/// let hotspots = HotspotAnalyzer::new()
+///     .with_min_schedules(3)
+///     .with_spread(SignedDuration::from_mins(30))
+///     .analyze(&schedules, &from, 1.day());
+/// assert_eq!(1, hotspots.len());
+/// let hotspot = &hotspots[0];
+/// assert_eq!(date(2025, 1, 1).at(0, 0, 0, 0), hotspot.start().datetime());
+/// assert_eq!(&[0, 1, 2], hotspot.schedules());
+/// assert_eq!(
+///     vec!["@daily", "0 0 0 * * *", "0 0 0,12 * * *"],
+///     hotspot.expressions(&schedules).collect::<Vec<_>>()
+/// );
+/// assert_eq!(
+///     &[
+///         SignedDuration::ZERO,
+///         SignedDuration::from_mins(15),
+///         SignedDuration::from_mins(30)
+///     ],
+///     hotspot.suggested_offsets()
+/// );
+/// ```
+#[derive(Clone, Debug)]
+pub struct HotspotAnalyzer {
+    bucket: SignedDuration,
+    min_schedules: usize,
+    spread: SignedDuration,
+}
+
+impl Default for HotspotAnalyzer {
+    fn default() -> Self {
+        HotspotAnalyzer {
+            bucket: SignedDuration::from_mins(1),
+            min_schedules: 2,
+            spread: SignedDuration::from_mins(10),
+        }
+    }
+}
+
+impl HotspotAnalyzer {
+    /// Creates an analyzer reporting each minute in which at least two
+    /// schedules fire, spreading them over ten minutes.
+    pub fn new() -> HotspotAnalyzer {
+        HotspotAnalyzer::default()
+    }
+
+    /// Sets the length of the buckets time is cut into, rounded down to whole
+    /// seconds, and at least one second.
+    pub fn with_bucket(mut self, bucket: SignedDuration) -> HotspotAnalyzer {
+        self.bucket = SignedDuration::from_secs(bucket.as_secs().max(1));
+        self
+    }
+
+    /// Sets how many different schedules must fire within a bucket for it to
+    /// be reported.
+    pub fn with_min_schedules(mut self, min_schedules: usize) -> HotspotAnalyzer {
+        self.min_schedules = min_schedules.max(1);
+        self
+    }
+
+    /// Sets how far apart the first and last schedules of a hotspot would fire
+    /// once offset as suggested.
+    pub fn with_spread(mut self, spread: SignedDuration) -> HotspotAnalyzer {
+        self.spread = spread.max(SignedDuration::ZERO);
+        self
+    }
+
+    /// Returns the hotspots of `schedules` after `from` and within `horizon`,
+    /// in chronological order, in the time zone of `from`. The schedules may
+    /// be any [`Recurrence`].
+    pub fn analyze<R>(&self, schedules: &[R], from: &Zoned, horizon: Span) -> Vec<Hotspot>
+    where
+        R: Recurrence,
+    {
+        let Ok(end) = from.checked_add(horizon) else {
+            return Vec::new();
+        };
+        let bucket = self.bucket.as_secs();
+        // The schedules firing within each bucket, in ascending order as
+        // they're visited in order.
+        let mut buckets: BTreeMap<i64, Vec<usize>> = BTreeMap::new();
+        for (index, schedule) in schedules.iter().enumerate() {
+            for fire in schedule.iter_after(from).take_until(&end) {
+                let indices = buckets
+                    .entry(fire.timestamp().as_second().div_euclid(bucket))
+                    .or_default();
+                if indices.last() != Some(&index) {
+                    indices.push(index);
+                }
+            }
+        }
+        buckets
+            .into_iter()
+            .filter(|(_, indices)| indices.len() >= self.min_schedules)
+            .filter_map(|(bucket_index, indices)| {
+                let start = Timestamp::from_second(bucket_index * bucket).ok()?;
+                let start = start.to_zoned(from.time_zone().clone());
+                let end = start.checked_add(self.bucket).ok()?;
+                let offsets = self.offsets(indices.len());
+                Some(Hotspot {
+                    start,
+                    end,
+                    schedules: indices,
+                    offsets,
+                })
+            })
+            .collect()
+    }
+
+    // Spreads `count` schedules evenly, whole seconds apart, the first one
+    // staying where it is and the last one delayed by the spread.
+    fn offsets(&self, count: usize) -> Vec<SignedDuration> {
+        let spread = self.spread.as_secs();
+        let gaps = (count as i64 - 1).max(1);
+        (0..count as i64)
+            .map(|index| SignedDuration::from_secs(spread * index / gaps))
+            .collect()
+    }
+}
+
+/// A bucket of time in which many schedules fire, returned by
+/// [`HotspotAnalyzer::analyze`].
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Hotspot {
+    start: Zoned,
+    end: Zoned,
+    schedules: Vec<usize>,
+    offsets: Vec<SignedDuration>,
+}
+
+impl Hotspot {
+    /// Returns the start of the bucket.
+    pub fn start(&self) -> &Zoned {
+        &self.start
+    }
+
+    /// Returns the end of the bucket, which is excluded from it.
+    pub fn end(&self) -> &Zoned {
+        &self.end
+    }
+
+    /// Returns the indices of the schedules firing within the bucket, in
+    /// the order they were provided.
+    pub fn schedules(&self) -> &[usize] {
+        &self.schedules
+    }
+
+    /// Returns the source expressions of the schedules firing within the
+    /// bucket, out of the `schedules` which were analyzed.
+    pub fn expressions<'a>(&'a self, schedules: &'a [Schedule]) -> impl Iterator<Item = &'a str> {
+        self.schedules
+            .iter()
+            .filter_map(|&index| schedules.get(index))
+            .map(Schedule::source)
+    }
+
+    /// Returns the offset to delay each of [`Hotspot::schedules`] by, in the
+    /// same order, so that they're spread out. Offsets are whole seconds, so
+    /// they can be applied by editing the seconds and minutes fields.
+    pub fn suggested_offsets(&self) -> &[SignedDuration] {
+        &self.offsets
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use std::str::FromStr;
+
+    use jiff::{
+        civil::{date, time},
+        tz::TimeZone,
+        ToSpan,
+    };
+
+    use super::*;
+
+    #[test]
+    fn test_hotspots() {
+        let schedules: Vec<_> = ["0 */15 * * * *", "30 0 * * * *", "0 0 */2 * * *"]
+            .into_iter()
+            .map(|expression| Schedule::from_str(expression).unwrap())
+            .collect();
+        let from = date(2025, 1, 1)
+            .at(0, 30, 0, 0)
+            .to_zoned(TimeZone::UTC)
+            .unwrap();
+        let hotspots = HotspotAnalyzer::new().analyze(&schedules, &from, 2.hours());
+        let starts: Vec<_> = hotspots
+            .iter()
+            .map(|hotspot| hotspot.start().time())
+            .collect();
+        assert_eq!(vec![time(1, 0, 0, 0), time(2, 0, 0, 0)], starts);
+        assert_eq!(&[0, 1], hotspots[0].schedules());
+        assert_eq!(&[0, 1, 2], hotspots[1].schedules());
+        assert_eq!(
+            date(2025, 1, 1).at(2, 1, 0, 0),
+            hotspots[1].end().datetime()
+        );
+        // 10 minutes spread over 3 schedules.
+        assert_eq!(
+            &[
+                SignedDuration::ZERO,
+                SignedDuration::from_mins(5),
+                SignedDuration::from_mins(10)
+            ],
+            hotspots[1].suggested_offsets()
+        );
+        // In whole seconds.
+        let hotspots = HotspotAnalyzer::new()
+            .with_spread(SignedDuration::from_secs(10))
+            .analyze(&schedules, &from, 2.hours());
+        assert_eq!(
+            &[
+                SignedDuration::ZERO,
+                SignedDuration::from_secs(5),
+                SignedDuration::from_secs(10)
+            ],
+            hotspots[1].suggested_offsets()
+        );
+        let hotspots = HotspotAnalyzer::new()
+            .with_spread(SignedDuration::from_secs(10))
+            .analyze(&schedules[1..], &from, 2.hours());
+        assert_eq!(
+            &[SignedDuration::ZERO, SignedDuration::from_secs(10)],
+            hotspots[0].suggested_offsets()
+        );
+
+        // A schedule firing several times in a bucket counts once.
+        let hotspots = HotspotAnalyzer::new()
+            .with_bucket(SignedDuration::from_hours(1))
+            .with_min_schedules(2)
+            .analyze(&schedules[..1], &from, 2.hours());
+        assert!(hotspots.is_empty());
+
+        // Buckets are whole seconds, and so are their ends.
+        let hotspots = HotspotAnalyzer::new()
+            .with_bucket(SignedDuration::from_millis(1_500))
+            .analyze(&schedules, &from, 2.hours());
+        assert_eq!(
+            date(2025, 1, 1).at(2, 0, 1, 0),
+            hotspots[0].end().datetime()
+        );
+    }
+}
@@ -0,0 +1,154 @@
+use jiff::{tz::TimeZone, SignedDuration, Zoned};
+
+use crate::{
+    fnv::fnv1a,
+    schedule::{Schedule, ScheduleIterator},
+};
+
+/// A [`Schedule`] whose fire times are each delayed by the same jitter,
+/// derived from a key, returned by [`Schedule::with_jitter_key`].
+///
+/// The jitter is a whole number of seconds less than the maximum jitter,
+/// taken from the 64-bit FNV-1a hash of the key, so it's the same for a key
+/// on every machine and with every release.
+///
+/// # Example
+///
+/// ```rust
+/// use std::str::FromStr;
+///
+/// use jiff_cron::{
+///     jiff::{civil::date, tz::TimeZone, SignedDuration},
+///     Schedule,
+/// };
+///
+/// let schedule = Schedule::from_str("@hourly").unwrap();
+/// let max = SignedDuration::from_mins(10);
+/// let web_1 = schedule.clone().with_jitter_key("web-1", max);
+/// let web_2 = schedule.with_jitter_key("web-2", max);
+/// assert_ne!(web_1.jitter(), web_2.jitter());
+///
+/// let after = date(2025, 1, 1)
+///     .at(0, 0, 0, 0)
+///     .to_zoned(TimeZone::UTC)
+///     .unwrap();
+/// let next = web_1.after(&after).next().unwrap();
+/// assert_eq!(0, next.hour());
+/// assert!(next.minute() < 10);
+/// assert!(web_1.includes(&next));
+/// ```
+#[derive(Clone, Debug)]
+pub struct JitteredSchedule {
+    schedule: Schedule,
+    jitter: SignedDuration,
+}
+
+impl JitteredSchedule {
+    pub(crate) fn new(schedule: Schedule, key: &[u8], max: SignedDuration) -> JitteredSchedule {
+        let hash = fnv1a(key);
+        let seconds = max.as_secs().max(0) as u64;
+        let jitter = if seconds == 0 {
+            SignedDuration::ZERO
+        } else {
+            SignedDuration::from_secs((hash % seconds) as i64)
+        };
+        JitteredSchedule { schedule, jitter }
+    }
+
+    /// Returns the schedule without jitter.
+    pub fn schedule(&self) -> &Schedule {
+        &self.schedule
+    }
+
+    /// Returns the delay added to each of the schedule's fire times.
+    pub fn jitter(&self) -> SignedDuration {
+        self.jitter
+    }
+
+    /// Returns an iterator over the delayed fire times after `after`, in its
+    /// time zone.
+    pub fn after(&self, after: &Zoned) -> JitteredIterator<'_> {
+        let start = after
+            .checked_sub(self.jitter)
+            .unwrap_or_else(|_| after.clone());
+        JitteredIterator {
+            iter: self.schedule.after(&start),
+            jitter: self.jitter,
+        }
+    }
+
+    /// Like [`JitteredSchedule::after`], starting from the current time.
+    pub fn upcoming(&self, time_zone: TimeZone) -> JitteredIterator<'_> {
+        self.after(&Zoned::now().with_time_zone(time_zone))
+    }
+
+    /// Returns whether the schedule fires at `date_time` once delayed.
+    pub fn includes(&self, date_time: &Zoned) -> bool {
+        date_time
+            .checked_sub(self.jitter)
+            .is_ok_and(|date_time| self.schedule.includes(date_time))
+    }
+}
+
+/// An iterator over the fire times of a [`JitteredSchedule`], returned by
+/// [`JitteredSchedule::after`].
+#[derive(Clone, Debug)]
+pub struct JitteredIterator<'a> {
+    iter: ScheduleIterator<'a>,
+    jitter: SignedDuration,
+}
+
+impl Iterator for JitteredIterator<'_> {
+    type Item = Zoned;
+
+    fn next(&mut self) -> Option<Zoned> {
+        self.iter
+            .find_map(|fire| fire.checked_add(self.jitter).ok())
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use std::str::FromStr;
+
+    use jiff::civil::date;
+
+    use super::*;
+
+    #[test]
+    fn test_jitter_is_stable() {
+        let schedule = Schedule::from_str("0 0 3 * * *").unwrap();
+        let max = SignedDuration::from_hours(1);
+        let jittered = schedule.clone().with_jitter_key("backup", max);
+        assert_eq!(
+            jittered.jitter(),
+            schedule.clone().with_jitter_key("backup", max).jitter()
+        );
+        // Pinned, as jitter must not change between releases.
+        assert_eq!(SignedDuration::from_secs(1647), jittered.jitter());
+        assert_eq!(
+            SignedDuration::ZERO,
+            schedule
+                .with_jitter_key("backup", SignedDuration::ZERO)
+                .jitter()
+        );
+    }
+
+    #[test]
+    fn test_jittered_after() {
+        let schedule = Schedule::from_str("0 0 3 * * *").unwrap();
+        let jittered = schedule.with_jitter_key("backup", SignedDuration::from_hours(1));
+        let jitter = jittered.jitter();
+        let fire = date(2025, 1, 1).at(3, 0, 0, 0).in_tz("UTC").unwrap();
+        let delayed = fire.checked_add(jitter).unwrap();
+
+        // A fire time is still returned if only its delayed time is after
+        // the start.
+        let next = jittered.after(&fire).next().unwrap();
+        assert_eq!(delayed, next);
+        let next = jittered.after(&delayed).next().unwrap();
+        assert_eq!(date(2025, 1, 2), next.date());
+        assert!(jittered.includes(&delayed));
+        assert!(!jittered.includes(&fire));
+    }
+}
@@ -45,20 +45,83 @@
 //!
 //! You can enable optional [`serde`](https://docs.rs/crate/serde) support
 //! via [crate feature toggle](https://docs.rs/crate/jiff-cron/latest/features).
+//! The `solar` feature adds schedules firing at sunrise or sunset, and the
+//! `calendars` feature schedules in calendar systems other than the Gregorian.
+//! The `defmt` feature implements [`defmt::Format`](https://docs.rs/defmt) for
+//! schedules, their fields and errors, for logging them from firmware.
 
 /// Error types used by this crate.
 pub mod error;
 
-mod ordinal;
+mod adapters;
+mod budget;
+mod cache;
+mod calendar;
+#[cfg(feature = "calendars")]
+mod calendar_system;
+mod capabilities;
+#[cfg(feature = "serde")]
+mod compiled;
+mod cursor;
+mod dialects;
+mod dst;
+mod fnv;
+mod hashed;
+mod hotspots;
+mod jitter;
+mod occupancy;
+mod oneshot;
+mod options;
+pub mod ordinal;
 mod parsing;
+mod per_period;
+mod phase;
 mod queries;
+mod recurrence;
 mod schedule;
+#[cfg(feature = "serde")]
+pub mod serde;
+mod simulation;
+#[cfg(feature = "solar")]
+mod solar;
 mod specifier;
+mod systemd;
 mod time_unit;
+mod transpose;
+mod union;
 
 pub use jiff;
 
+#[cfg(feature = "calendars")]
+pub use crate::calendar_system::{CalendarIterator, CalendarSchedule, CalendarSystem};
+#[cfg(feature = "serde")]
+pub use crate::compiled::CompiledSchedule;
+#[cfg(feature = "solar")]
+pub use crate::solar::{SolarEvent, SolarIterator, SolarSchedule};
 pub use crate::{
+    adapters::{AtMostEvery, CoalesceWithin, EveryNthWeek, FireTimes, OffsetBy, TakeUntil},
+    budget::SearchBudget,
+    cache::ScheduleCache,
+    calendar::FireCalendar,
+    capabilities::{Capabilities, Dialect},
+    cursor::IteratorCursor,
+    dst::{DstOccurrence, DstResolution},
+    hotspots::{Hotspot, HotspotAnalyzer},
+    jitter::{JitteredIterator, JitteredSchedule},
+    occupancy::{Occupancy, Transition, Window, WindowSchedule},
+    oneshot::{matches, next_occurrence},
+    options::ParseOptions,
+    ordinal::{Ordinal, OrdinalSet},
+    per_period::{EachPeriod, LimitPerDay, WithEndMarker},
+    phase::Phase,
+    recurrence::{Recurrence, RecurrenceIterator},
     schedule::{OwnedScheduleIterator, Schedule, ScheduleIterator},
-    time_unit::TimeUnitSpec,
+    simulation::Simulation,
+    specifier::{RootSpecifier, Specifier},
+    time_unit::{
+        DaysOfMonth, DaysOfWeek, Hours, Minutes, Months, OrdinalIter, OrdinalRangeIter, Seconds,
+        TimeUnit, TimeUnitSpec, Years,
+    },
+    transpose::TransposeObstacle,
+    union::{ScheduleUnion, UnionIterator},
 };
@@ -0,0 +1,465 @@
+use std::iter::Peekable;
+
+use jiff::{SignedDuration, Span, Zoned};
+
+use crate::{
+    error::{Error, ErrorKind},
+    recurrence::Recurrence,
+    schedule::Schedule,
+};
+
+/// A schedule of windows of time, each opening when the schedule fires and
+/// lasting for a fixed span, such as a maintenance window running from 02:00
+/// to 04:00 nightly. The schedule may be any [`Recurrence`].
+///
+/// The span is added to each fire time in its own time zone, so a window of
+/// two hours lasts two hours even across a DST transition, while a window of
+/// a day closes at the same time of day as it opened.
+///
+/// # Example
+///
+/// ```rust
+/// use std::str::FromStr;
+///
+/// use jiff_cron::{
+///     jiff::{civil::date, tz::TimeZone, ToSpan},
+///     Occupancy, Schedule,
+/// };
+///
+/// let schedule = Schedule::from_str("0 0 2 * * *").unwrap();
+/// let maintenance = Occupancy::new(schedule, 2.hours());
+///
+/// let now = date(2025, 1, 1)
+///     .at(3, 0, 0, 0)
+///     .to_zoned(TimeZone::UTC)
+///     .unwrap();
+/// assert!(maintenance.contains(&now));
+/// let window = maintenance.current_window(&now).unwrap();
+/// assert_eq!(date(2025, 1, 1).at(4, 0, 0, 0), window.end().datetime());
+///
+/// let now = date(2025, 1, 1)
+///     .at(4, 0, 0, 0)
+///     .to_zoned(TimeZone::UTC)
+///     .unwrap();
+/// assert!(!maintenance.contains(&now));
+/// let window = maintenance.next_window(&now).unwrap();
+/// assert_eq!(date(2025, 1, 2).at(2, 0, 0, 0), window.start().datetime());
+/// ```
+#[derive(Clone, Debug)]
+pub struct Occupancy<R = Schedule> {
+    schedule: R,
+    duration: Span,
+}
+
+impl<R> Occupancy<R>
+where
+    R: Recurrence,
+{
+    /// Creates windows opening at each fire time of `schedule` and lasting
+    /// for `duration`.
+    pub fn new(schedule: R, duration: Span) -> Occupancy<R> {
+        Occupancy { schedule, duration }
+    }
+
+    /// Returns the schedule the windows open at.
+    pub fn schedule(&self) -> &R {
+        &self.schedule
+    }
+
+    /// Returns how long each window lasts.
+    pub fn duration(&self) -> Span {
+        self.duration
+    }
+
+    /// Returns whether `now` is within a window.
+    pub fn contains(&self, now: &Zoned) -> bool {
+        self.current_window(now).is_some()
+    }
+
+    /// Returns the window `now` is within, if any. Of overlapping windows, the
+    /// one opened last is returned.
+    pub fn current_window(&self, now: &Zoned) -> Option<Window> {
+        let start = last_at_or_before(&self.schedule, now)?;
+        self.window(start).filter(|window| window.contains(now))
+    }
+
+    /// Returns the first window opening after `now`.
+    pub fn next_window(&self, now: &Zoned) -> Option<Window> {
+        self.window(self.schedule.next_after(now)?)
+    }
+
+    fn window(&self, start: Zoned) -> Option<Window> {
+        let end = start.checked_add(self.duration).ok()?;
+        Some(Window { start, end })
+    }
+}
+
+/// A span of time from its start up to, but excluding, its end.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Window {
+    start: Zoned,
+    end: Zoned,
+}
+
+impl Window {
+    /// Returns when the window opens.
+    pub fn start(&self) -> &Zoned {
+        &self.start
+    }
+
+    /// Returns when the window closes.
+    pub fn end(&self) -> &Zoned {
+        &self.end
+    }
+
+    /// Returns whether `time` is within the window.
+    pub fn contains(&self, time: &Zoned) -> bool {
+        self.start <= *time && *time < self.end
+    }
+}
+
+/// Windows of time opening whenever one schedule fires and closing whenever
+/// another one does, such as a window opening at 22:00 on weekdays and
+/// closing at 06:00 the following day.
+///
+/// A window contains its opening time but not its closing time. The
+/// schedules are expected to alternate, which
+/// [`WindowSchedule::validate`] checks for. They may be any [`Recurrence`].
+///
+/// # Example
+///
+/// ```rust
+/// use std::str::FromStr;
+///
+/// use jiff_cron::{
+///     jiff::{civil::date, tz::TimeZone},
+///     Schedule, Transition, WindowSchedule,
+/// };
+///
+/// let windows = WindowSchedule::new(
+///     Schedule::from_str("0 0 22 * * Mon-Fri").unwrap(),
+///     Schedule::from_str("0 0 6 * * Tue-Sat").unwrap(),
+/// );
+/// let now = date(2025, 1, 7)
+///     .at(3, 0, 0, 0)
+///     .to_zoned(TimeZone::UTC)
+///     .unwrap();
+/// windows.validate(&now, 100).unwrap();
+/// assert!(windows.contains(&now));
+/// assert_eq!(
+///     date(2025, 1, 6).at(22, 0, 0, 0),
+///     windows.current_window(&now).unwrap().start().datetime()
+/// );
+/// match windows.next_transition(&now) {
+///     Some(Transition::Close(at)) => assert_eq!(6, at.hour()),
+///     transition => panic!("unexpected transition: {:?}", transition),
+/// }
+/// ```
+#[derive(Clone, Debug)]
+pub struct WindowSchedule<R = Schedule> {
+    open: R,
+    close: R,
+}
+
+/// A window of a [`WindowSchedule`] opening or closing.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum Transition {
+    /// A window opens at the provided time
+    Open(Zoned),
+    /// A window closes at the provided time
+    Close(Zoned),
+}
+
+impl<R> WindowSchedule<R>
+where
+    R: Recurrence,
+{
+    /// Creates windows opening at each fire time of `open` and closing at the
+    /// following fire time of `close`.
+    pub fn new(open: R, close: R) -> WindowSchedule<R> {
+        WindowSchedule { open, close }
+    }
+
+    /// Returns the schedule the windows open at.
+    pub fn open(&self) -> &R {
+        &self.open
+    }
+
+    /// Returns the schedule the windows close at.
+    pub fn close(&self) -> &R {
+        &self.close
+    }
+
+    /// Returns whether `now` is within a window, i.e. whether the latest
+    /// opening time at or before it follows the latest closing time.
+    pub fn contains(&self, now: &Zoned) -> bool {
+        self.last_open(now).is_some()
+    }
+
+    /// Returns the window `now` is within, if any. A window which never
+    /// closes isn't returned.
+    pub fn current_window(&self, now: &Zoned) -> Option<Window> {
+        let start = self.last_open(now)?;
+        let end = self.close.next_after(now)?;
+        Some(Window { start, end })
+    }
+
+    /// Returns the first window opening after `now`.
+    pub fn next_window(&self, now: &Zoned) -> Option<Window> {
+        let start = self.open.next_after(now)?;
+        let end = self.close.next_after(&start)?;
+        Some(Window { start, end })
+    }
+
+    /// Returns the next time after `now` at which a window opens, if `now`
+    /// isn't within one, or closes, if it is.
+    pub fn next_transition(&self, now: &Zoned) -> Option<Transition> {
+        if self.contains(now) {
+            self.close.next_after(now).map(Transition::Close)
+        } else {
+            self.open.next_after(now).map(Transition::Open)
+        }
+    }
+
+    /// Checks that the next `windows` windows after `after` open and close in
+    /// turn, returning [`ErrorKind::NotAlternating`] for the first opening or
+    /// closing time out of turn, or falling on both schedules at once.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use std::str::FromStr;
+    ///
+    /// use jiff_cron::{
+    ///     error::ErrorKind,
+    ///     jiff::{civil::date, tz::TimeZone},
+    ///     Schedule, WindowSchedule,
+    /// };
+    ///
+    /// // Windows open every day but only close on weekdays.
+    /// let windows = WindowSchedule::new(
+    ///     Schedule::from_str("0 0 22 * * *").unwrap(),
+    ///     Schedule::from_str("0 0 6 * * Mon-Fri").unwrap(),
+    /// );
+    /// let after = date(2025, 1, 6)
+    ///     .at(12, 0, 0, 0)
+    ///     .to_zoned(TimeZone::UTC)
+    ///     .unwrap();
+    /// let error = windows.validate(&after, 7).unwrap_err();
+    /// match error.kind() {
+    ///     ErrorKind::NotAlternating { at } => {
+    ///         assert_eq!(date(2025, 1, 11).at(22, 0, 0, 0), at.datetime())
+    ///     }
+    ///     kind => panic!("unexpected error: {:?}", kind),
+    /// }
+    /// ```
+    pub fn validate(&self, after: &Zoned, windows: usize) -> Result<(), Error> {
+        let mut opens = self.open.iter_after(after).peekable();
+        let mut closes = self.close.iter_after(after).peekable();
+        let mut open = self.contains(after);
+        let mut remaining = windows;
+        while remaining > 0 {
+            let (expected, other) = if open {
+                (&mut closes, &mut opens)
+            } else {
+                (&mut opens, &mut closes)
+            };
+            match (expected.peek(), other.peek()) {
+                (None, None) => return Ok(()),
+                // A window may never close, but must not open again first.
+                (None, Some(_)) if open => return Ok(()),
+                (Some(next), Some(out_of_turn)) if out_of_turn <= next => {
+                    return Err(not_alternating(other));
+                }
+                (None, Some(_)) => return Err(not_alternating(other)),
+                (Some(_), _) => {
+                    expected.next();
+                    if open {
+                        remaining -= 1;
+                    }
+                    open = !open;
+                }
+            }
+        }
+        Ok(())
+    }
+
+    /// Returns the latest opening time at or before `now`, if it follows the
+    /// latest closing time.
+    fn last_open(&self, now: &Zoned) -> Option<Zoned> {
+        let open = last_at_or_before(&self.open, now);
+        let close = last_at_or_before(&self.close, now);
+        match (open, close) {
+            (Some(open), Some(close)) if open > close => Some(open),
+            (Some(open), None) => Some(open),
+            _ => None,
+        }
+    }
+}
+
+// The latest fire time of `recurrence` at or before `now`.
+fn last_at_or_before<R>(recurrence: &R, now: &Zoned) -> Option<Zoned>
+where
+    R: Recurrence,
+{
+    match now.checked_add(SignedDuration::from_nanos(1)) {
+        Ok(just_after) => recurrence.prev_before(&just_after),
+        // Nothing follows the last representable instant, so `now` can't be a
+        // fire time.
+        Err(_) => recurrence.prev_before(now),
+    }
+}
+
+fn not_alternating(times: &mut Peekable<impl Iterator<Item = Zoned>>) -> Error {
+    let at = times.next().expect("the time out of turn was peeked");
+    ErrorKind::NotAlternating { at }.into()
+}
+
+#[cfg(test)]
+mod test {
+    use std::str::FromStr;
+
+    use jiff::{civil::date, tz::TimeZone, ToSpan};
+
+    use super::*;
+
+    #[test]
+    fn test_occupancy_across_dst_transition() {
+        let time_zone = TimeZone::get("Europe/London").unwrap();
+        let at = |day, hour| {
+            date(2019, 3, day)
+                .at(hour, 30, 0, 0)
+                .to_zoned(time_zone.clone())
+                .unwrap()
+        };
+        let schedule = Schedule::from_str("0 30 0 * * *").unwrap();
+
+        // Two hours after 00:30 GMT, the clocks read 03:30 BST.
+        let occupancy = Occupancy::new(schedule.clone(), 2.hours());
+        let window = occupancy.current_window(&at(31, 3).checked_sub(1.second()).unwrap());
+        assert_eq!(Some(&at(31, 3)), window.as_ref().map(Window::end));
+        assert!(!occupancy.contains(&at(31, 3)));
+
+        // Windows lasting longer than the schedule's period overlap.
+        let occupancy = Occupancy::new(schedule, 1.day().hours(1));
+        let window = occupancy.current_window(&at(31, 1)).unwrap();
+        assert_eq!(&at(31, 0), window.start());
+        assert_eq!(
+            Some(date(2019, 4, 1).at(0, 30, 0, 0)),
+            occupancy
+                .next_window(&at(31, 1))
+                .map(|window| window.start().datetime())
+        );
+    }
+
+    #[test]
+    fn test_window_schedule() {
+        let at = |day, hour| {
+            date(2025, 1, day)
+                .at(hour, 0, 0, 0)
+                .to_zoned(TimeZone::UTC)
+                .unwrap()
+        };
+        let windows = WindowSchedule::new(
+            Schedule::from_str("0 0 9 * * Mon-Fri").unwrap(),
+            Schedule::from_str("0 0 17 * * Mon-Fri").unwrap(),
+        );
+        windows.validate(&at(1, 0), 1000).unwrap();
+        assert!(windows.contains(&at(6, 9)));
+        assert!(!windows.contains(&at(6, 17)));
+        assert!(!windows.contains(&at(4, 12)));
+        assert_eq!(
+            Some(Window {
+                start: at(6, 9),
+                end: at(6, 17),
+            }),
+            windows.next_window(&at(4, 12))
+        );
+        assert_eq!(
+            Some(Transition::Open(at(6, 9))),
+            windows.next_transition(&at(4, 12))
+        );
+        assert_eq!(
+            Some(Transition::Close(at(6, 17))),
+            windows.next_transition(&at(6, 9))
+        );
+
+        // Opening and closing at once.
+        let windows = WindowSchedule::new(
+            Schedule::from_str("0 0 9,17 * * *").unwrap(),
+            Schedule::from_str("0 0 17 * * *").unwrap(),
+        );
+        let error = windows.validate(&at(1, 0), 10).unwrap_err();
+        assert_eq!(&ErrorKind::NotAlternating { at: at(1, 17) }, error.kind());
+        assert_eq!("JC0010", error.code());
+
+        // A window which never closes.
+        let windows = WindowSchedule::new(
+            Schedule::from_str("0 0 9 1 1 * 2025").unwrap(),
+            Schedule::from_str("0 0 17 1 1 * 2024").unwrap(),
+        );
+        windows.validate(&at(1, 0), 10).unwrap();
+        assert!(windows.contains(&at(6, 9)));
+        assert_eq!(None, windows.current_window(&at(6, 9)));
+        assert_eq!(None, windows.next_transition(&at(6, 9)));
+    }
+
+    #[test]
+    fn test_windows_of_any_recurrence() {
+        // Only searches forwards, so windows are found with the default
+        // `Recurrence::prev_before`.
+        struct Forwards(Schedule);
+
+        impl Recurrence for Forwards {
+            fn next_after(&self, after: &Zoned) -> Option<Zoned> {
+                self.0.next_after(after)
+            }
+        }
+
+        let at = |day, hour| {
+            date(2025, 1, day)
+                .at(hour, 0, 0, 0)
+                .to_zoned(TimeZone::UTC)
+                .unwrap()
+        };
+        let occupancy = Occupancy::new(
+            Forwards(Schedule::from_str("0 0 2 * * Mon").unwrap()),
+            2.hours(),
+        );
+        assert!(occupancy.contains(&at(6, 2)));
+        assert!(occupancy.contains(&at(6, 3)));
+        assert!(!occupancy.contains(&at(6, 4)));
+        assert_eq!(
+            Some(at(13, 2)),
+            occupancy
+                .next_window(&at(6, 3))
+                .map(|window| window.start().clone())
+        );
+
+        let windows: WindowSchedule<Box<dyn Recurrence>> = WindowSchedule::new(
+            Box::new(Forwards(Schedule::from_str("0 0 9 * * Mon").unwrap())),
+            Box::new(Schedule::from_str("0 0 17 * * Fri").unwrap()),
+        );
+        windows.validate(&at(1, 0), 10).unwrap();
+        assert_eq!(
+            Some(Window {
+                start: at(6, 9),
+                end: at(10, 17),
+            }),
+            windows.current_window(&at(8, 12))
+        );
+        assert!(!windows.contains(&at(11, 12)));
+    }
+
+    #[test]
+    fn test_occupancy_without_more_windows() {
+        let schedule = Schedule::from_str("0 0 2 1 1 * 2020").unwrap();
+        let occupancy = Occupancy::new(schedule, 2.hours());
+        let now = date(2025, 1, 1)
+            .at(3, 0, 0, 0)
+            .to_zoned(TimeZone::UTC)
+            .unwrap();
+        assert_eq!(None, occupancy.current_window(&now));
+        assert_eq!(None, occupancy.next_window(&now));
+    }
+}
@@ -0,0 +1,65 @@
+use jiff::Zoned;
+
+use crate::{cache::RecentSchedules, error::Error};
+
+/// How many of the most recently used expressions stay parsed.
+const RECENT_CAPACITY: usize = 64;
+
+static RECENT: RecentSchedules = RecentSchedules::new(RECENT_CAPACITY);
+
+/// Parses `expression` and returns its next fire time after `after`, or
+/// `None` if it never fires again.
+///
+/// The most recently used expressions are kept parsed, so calling this
+/// repeatedly with the same few expressions doesn't parse them each time.
+/// Store a [`Schedule`](crate::Schedule) instead when the expression is known
+/// up front.
+///
+/// # Example
+///
+/// ```rust
+/// use jiff_cron::{
+///     jiff::{civil::date, tz::TimeZone},
+///     next_occurrence,
+/// };
+///
+/// let after = date(2025, 1, 3)
+///     .at(18, 0, 0, 0)
+///     .to_zoned(TimeZone::UTC)
+///     .unwrap();
+/// let next = next_occurrence("0 30 9 * * Mon-Fri", &after).unwrap();
+/// assert_eq!(
+///     Some(date(2025, 1, 6).at(9, 30, 0, 0)),
+///     next.map(|next| next.datetime())
+/// );
+/// assert!(next_occurrence("0 30 9 * * Caturday", &after).is_err());
+/// ```
+pub fn next_occurrence(expression: &str, after: &Zoned) -> Result<Option<Zoned>, Error> {
+    let schedule = RECENT.get(expression)?;
+    Ok(schedule.after(after).next())
+}
+
+/// Parses `expression` and returns whether `time` matches it, like
+/// [`Schedule::includes`](crate::Schedule::includes). Recently used
+/// expressions are kept parsed, as for [`next_occurrence`].
+///
+/// # Example
+///
+/// ```rust
+/// use jiff_cron::{
+///     jiff::{civil::date, tz::TimeZone},
+///     matches,
+/// };
+///
+/// let time = date(2025, 1, 6)
+///     .at(9, 30, 0, 0)
+///     .to_zoned(TimeZone::UTC)
+///     .unwrap();
+/// assert!(matches("0 30 9 * * Mon-Fri", &time).unwrap());
+/// assert!(!matches("0 30 9 * * Sat,Sun", &time).unwrap());
+/// assert!(matches("0 30 9 * * Caturday", &time).is_err());
+/// ```
+pub fn matches(expression: &str, time: &Zoned) -> Result<bool, Error> {
+    let schedule = RECENT.get(expression)?;
+    Ok(schedule.includes(time.clone()))
+}
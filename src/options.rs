@@ -0,0 +1,308 @@
+use std::borrow::Cow;
+
+use jiff::SignedDuration;
+
+use crate::{
+    capabilities::Dialect,
+    dialects,
+    error::{Error, ErrorKind},
+    hashed,
+    parsing::parse_without_seconds,
+    schedule::Schedule,
+    time_unit::Seconds,
+};
+
+/// Restrictions on the schedules an expression may describe, for platforms
+/// validating expressions submitted by their users.
+///
+/// # Example
+///
+/// ```rust
+/// use jiff_cron::{error::ErrorKind, jiff::SignedDuration, ParseOptions};
+///
+/// let options = ParseOptions::new().with_min_interval(SignedDuration::from_mins(1));
+/// assert!(options.parse("0 */5 * * * *").is_ok());
+///
+/// let error = options.parse("*/30 * * * * *").unwrap_err();
+/// assert_eq!(
+///     &ErrorKind::IntervalTooShort {
+///         interval: SignedDuration::from_secs(30),
+///         min: SignedDuration::from_mins(1),
+///     },
+///     error.kind()
+/// );
+/// ```
+#[derive(Clone, Debug, Default)]
+pub struct ParseOptions {
+    min_interval: Option<SignedDuration>,
+    omitted_seconds: Option<Seconds>,
+    dialect: Option<Dialect>,
+    hash_key: Option<Vec<u8>>,
+}
+
+impl ParseOptions {
+    /// Creates options accepting every valid expression.
+    pub fn new() -> ParseOptions {
+        ParseOptions::default()
+    }
+
+    /// Rejects schedules which fire less than `min_interval` apart, as
+    /// measured by [`Schedule::min_interval`], with
+    /// [`ErrorKind::IntervalTooShort`].
+    pub fn with_min_interval(mut self, min_interval: SignedDuration) -> ParseOptions {
+        self.min_interval = Some(min_interval);
+        self
+    }
+
+    /// Also accepts classic five-field expressions, which start from the
+    /// minutes, completing them with `seconds`: usually either `0`, to fire
+    /// once at the start of each minute, or `*`, to fire every second of it.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use jiff_cron::{ParseOptions, Seconds, TimeUnitSpec};
+    ///
+    /// let seconds: Seconds = "0".parse().unwrap();
+    /// let options = ParseOptions::new().with_omitted_seconds(seconds);
+    /// let schedule = options.parse("*/5 9-17 * * Mon-Fri").unwrap();
+    /// assert_eq!(1, schedule.seconds().count());
+    /// assert_eq!(12, schedule.minutes().count());
+    /// assert_eq!("*/5 9-17 * * Mon-Fri", schedule.source());
+    ///
+    /// // Expressions with seconds are parsed as usual.
+    /// let schedule = options.parse("*/10 * * * * *").unwrap();
+    /// assert_eq!(6, schedule.seconds().count());
+    /// ```
+    pub fn with_omitted_seconds(mut self, seconds: Seconds) -> ParseOptions {
+        self.omitted_seconds = Some(seconds);
+        self
+    }
+
+    /// Parses expressions as `dialect` does, e.g. for expressions carried over
+    /// from Java services using Quartz, rejecting those using features it
+    /// doesn't accept or would read differently with
+    /// [`ErrorKind::NotInDialect`]. Omitted seconds don't apply.
+    ///
+    /// For [`Dialect::Quartz`], exactly one of the days of the month and the
+    /// days of the week must be `?`, years run from 1970 to 2099, and neither
+    /// nicknames, step offsets nor occurrences counted from the end of the
+    /// month other than the last are accepted. Quartz numbers the days of the
+    /// week from Sunday 1, like this crate, and reads a bare `L` in the days of
+    /// the week as Saturday, the last of them.
+    ///
+    /// For [`Dialect::EventBridge`], expressions have six fields from the
+    /// minutes to the years, optionally written as `cron(fields)`, with the
+    /// same rules for `?` and the same features as Quartz. `rate(5 minutes)`,
+    /// `rate(1 hour)` and `rate(1 day)` are accepted too, firing on the
+    /// clock, e.g. on the hour rather than an hour after a rule was created,
+    /// so rates which don't divide an hour or a day evenly are rejected.
+    /// Expressions of other dialects are rejected with
+    /// [`ErrorKind::Expression`].
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use jiff_cron::{error::ErrorKind, Dialect, ParseOptions};
+    ///
+    /// let quartz = ParseOptions::new().with_dialect(Dialect::Quartz);
+    /// assert!(quartz.parse("0 15 10 ? * 6L").is_ok());
+    /// assert_eq!(
+    ///     quartz.parse("0 0 0 ? * SAT").unwrap(),
+    ///     quartz.parse("0 0 0 ? * L").unwrap()
+    /// );
+    ///
+    /// let error = quartz.parse("0 15 10 * * Fri").unwrap_err();
+    /// assert!(matches!(error.kind(), ErrorKind::NotInDialect { .. }));
+    /// assert!(ParseOptions::new().parse("0 15 10 * * Fri").is_ok());
+    ///
+    /// let event_bridge = ParseOptions::new().with_dialect(Dialect::EventBridge);
+    /// let schedule = event_bridge.parse("cron(15 10 ? * 6L 2025)").unwrap();
+    /// assert_eq!(schedule, quartz.parse("0 15 10 ? * 6L 2025").unwrap());
+    /// assert_eq!(
+    ///     "0 */5 * * * *",
+    ///     event_bridge.parse("rate(5 minutes)").unwrap().source()
+    /// );
+    /// ```
+    pub fn with_dialect(mut self, dialect: Dialect) -> ParseOptions {
+        self.dialect = Some(dialect);
+        self
+    }
+
+    /// Accepts Jenkins' `H` in any field but the years, replacing it with a
+    /// value derived from `key`, such as a job's name, so that jobs written
+    /// alike spread out over the hour or day rather than all firing at once.
+    ///
+    /// `H` picks any value of the unit, or of a range for e.g. `H(0-29)`,
+    /// and the days of the month only up to the 28th. With a step, e.g.
+    /// `H/15`, it picks the first value below the step. Values are derived
+    /// from the 64-bit FNV-1a hash of the key and the unit, so they're the
+    /// same for a key on every machine and with every release. The source of
+    /// the parsed schedule has each `H` replaced. Without a key, `H` is
+    /// rejected with [`ErrorKind::HashWithoutKey`].
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use jiff_cron::{ParseOptions, TimeUnitSpec};
+    ///
+    /// let options = ParseOptions::new().with_hash_key("nightly-backup");
+    /// let schedule = options.parse("0 H H(0-5) * * *").unwrap();
+    /// assert_eq!(1, schedule.minutes().count());
+    /// assert!(schedule.hours().iter().all(|hour| hour <= 5));
+    /// assert_eq!(schedule, options.parse("0 H H(0-5) * * *").unwrap());
+    ///
+    /// let schedule = options.parse("0 H/15 * * * *").unwrap();
+    /// assert_eq!(4, schedule.minutes().count());
+    /// let error = ParseOptions::new().parse("0 H/15 * * * *").unwrap_err();
+    /// assert_eq!("JC0017", error.code());
+    /// ```
+    pub fn with_hash_key(mut self, key: impl AsRef<[u8]>) -> ParseOptions {
+        self.hash_key = Some(key.as_ref().to_vec());
+        self
+    }
+
+    /// Returns the shortest time allowed between fire times, if restricted.
+    pub fn min_interval(&self) -> Option<SignedDuration> {
+        self.min_interval
+    }
+
+    /// Returns the seconds five-field expressions are completed with, if
+    /// they're accepted.
+    pub fn omitted_seconds(&self) -> Option<&Seconds> {
+        self.omitted_seconds.as_ref()
+    }
+
+    /// Returns the dialect expressions are parsed as, if any.
+    pub fn dialect(&self) -> Option<Dialect> {
+        self.dialect
+    }
+
+    /// Returns the key `H` is resolved with, if it's accepted.
+    pub fn hash_key(&self) -> Option<&[u8]> {
+        self.hash_key.as_deref()
+    }
+
+    /// Parses `expression`, checking the schedule against the options.
+    pub fn parse(&self, expression: &str) -> Result<Schedule, Error> {
+        // Dialects lay their fields out differently, so they resolve `H`
+        // themselves.
+        let expression = match (&self.hash_key, self.dialect) {
+            (Some(key), None) => hashed::resolve(expression, key),
+            _ => Cow::Borrowed(expression),
+        };
+        let expression = &*expression;
+        let schedule = match (self.dialect, &self.omitted_seconds) {
+            (Some(dialect), _) => dialects::parse(expression, dialect, self.hash_key())?,
+            (None, Some(seconds)) if expression.split_whitespace().count() == 5 => {
+                parse_without_seconds(expression, seconds)?
+            }
+            _ => expression.parse()?,
+        };
+        if let Some(min) = self.min_interval {
+            match schedule.min_interval() {
+                Some(interval) if interval < min => {
+                    return Err(Error::from(ErrorKind::IntervalTooShort { interval, min })
+                        .with_expression(expression));
+                }
+                _ => {}
+            }
+        }
+        Ok(schedule)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::time_unit::TimeUnit;
+
+    #[test]
+    fn test_min_interval() {
+        let options = ParseOptions::new().with_min_interval(SignedDuration::from_hours(1));
+        assert!(options.parse("0 0 * * * *").is_ok());
+        assert!(options.parse("0 0 0 1 1 * 2030").is_ok());
+        assert!(options.parse("0 0 25 * * *").is_err());
+
+        let error = options.parse("0 0,30 * * * *").unwrap_err();
+        assert_eq!("JC0011", error.code());
+        assert_eq!(Some("0 0,30 * * * *"), error.expression());
+        assert_eq!(
+            "Invalid expression: Fires 30m apart, but must fire at least 1h apart",
+            error.to_string()
+        );
+    }
+
+    #[test]
+    fn test_omitted_seconds() {
+        let every_second = ParseOptions::new().with_omitted_seconds("*".parse().unwrap());
+        let schedule = every_second.parse("0 9 * * *").unwrap();
+        assert_eq!(Some(SignedDuration::from_secs(1)), schedule.min_interval());
+        assert!(ParseOptions::new().parse("0 9 * * *").is_err());
+        assert!(every_second.parse("@daily").is_ok());
+
+        // Spans and field indices count from the minutes.
+        let error = every_second.parse("0 9 * * Mun").unwrap_err();
+        assert_eq!(Some(4), error.field_index());
+        assert_eq!(Some("Mun"), error.token());
+        assert_eq!(Some(8..11), error.span());
+
+        // Combined with a minimum interval, an omitted `*` is usually too often.
+        let options = every_second.with_min_interval(SignedDuration::from_secs(2));
+        assert_eq!("JC0011", options.parse("0 9 * * *").unwrap_err().code());
+    }
+
+    #[test]
+    fn test_hash_without_key() {
+        let error = ParseOptions::new().parse("H 0 0 * * *").unwrap_err();
+        assert_eq!(
+            &ErrorKind::HashWithoutKey {
+                unit: TimeUnit::Seconds,
+                field: "H".to_owned()
+            },
+            error.kind()
+        );
+        assert_eq!(Some("H"), error.token());
+        assert_eq!(
+            "Invalid expression: 'H' in Seconds requires a hash key, set with \
+             `ParseOptions::with_hash_key`.",
+            error.to_string()
+        );
+        let quartz = ParseOptions::new().with_dialect(Dialect::Quartz);
+        let error = quartz.parse("0 H(0-29)/10 * ? * *").unwrap_err();
+        assert_eq!("JC0017", error.code());
+        assert_eq!(Some(TimeUnit::Minutes), error.kind().unit());
+    }
+
+    #[test]
+    fn test_hash_key_with_dialect() {
+        for key in ["a", "e", "nightly-backup"] {
+            let options = ParseOptions::new().with_hash_key(key);
+            let event_bridge = options.clone().with_dialect(Dialect::EventBridge);
+            // Each `H` is resolved for its own field, from the minutes.
+            assert_eq!(
+                options.parse("0 H 9 ? * MON *").unwrap(),
+                event_bridge.parse("cron(H 9 ? * MON *)").unwrap(),
+                "{}",
+                key
+            );
+            assert_eq!(
+                options.parse("0 0 H ? * MON *").unwrap(),
+                event_bridge.parse("0 H ? * MON *").unwrap(),
+                "{}",
+                key
+            );
+            assert_eq!(
+                options.parse("0 0 9 ? * H *").unwrap(),
+                event_bridge.parse("0 9 ? * H *").unwrap(),
+                "{}",
+                key
+            );
+            let quartz = options.clone().with_dialect(Dialect::Quartz);
+            assert_eq!(
+                options.parse("H H 9 ? * MON").unwrap(),
+                quartz.parse("H H 9 ? * MON").unwrap()
+            );
+        }
+    }
+}
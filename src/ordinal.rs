@@ -1,8 +1,684 @@
-use std::collections::BTreeSet;
+//! Sets of ordinals, the values a unit of time can take.
 
+#[cfg(feature = "serde")]
+use std::fmt;
+use std::{
+    collections::{btree_set, BTreeSet},
+    iter::FromIterator,
+    ops::{Bound, RangeBounds, RangeInclusive},
+};
+
+#[cfg(feature = "serde")]
+use serde::{
+    de::{self, EnumAccess, SeqAccess, VariantAccess, Visitor},
+    Deserialize, Deserializer, Serialize, Serializer,
+};
+
+/// A single value of a unit of time, such as the minute `30` or the year
+/// `2024`.
 pub type Ordinal = u32;
-// TODO: Make OrdinalSet an enum.
-// It should either be a BTreeSet of ordinals or an `All` option to save space.
-// `All` can iterate from inclusive_min to inclusive_max and answer membership
-// queries
-pub type OrdinalSet = BTreeSet<Ordinal>;
+
+/// Ordinals below this value are stored in the bitmask representation.
+const MASK_CAPACITY: Ordinal = u128::BITS;
+
+/// An ordered set of [`Ordinal`]s.
+///
+/// # Example
+///
+/// ```rust
+/// use jiff_cron::OrdinalSet;
+///
+/// let mut set = OrdinalSet::from(1..=5);
+/// set.insert(10);
+/// assert!(set.contains(&3));
+/// assert_eq!(vec![1, 2, 3, 4, 5, 10], set.to_vec());
+/// assert_eq!(set, OrdinalSet::from([10, 5, 4, 3, 2, 1]));
+/// ```
+#[derive(Clone, Debug)]
+pub struct OrdinalSet {
+    repr: Repr,
+}
+
+#[derive(Clone, Debug)]
+enum Repr {
+    // Bit `n` is set if ordinal `n` is a member of the set. Every unit of time
+    // except years fits in here.
+    Mask(u128),
+    // Every ordinal from the first to the second, inclusively. This is how
+    // `*` is stored, so wildcards never have to be materialized.
+    Range(Ordinal, Ordinal),
+    // Sparse years exceed the bitmask's capacity and are kept in a tree.
+    Tree(BTreeSet<Ordinal>),
+}
+
+impl OrdinalSet {
+    /// Creates an empty set.
+    pub const fn new() -> OrdinalSet {
+        OrdinalSet {
+            repr: Repr::Mask(0),
+        }
+    }
+
+    /// Creates a set of every ordinal from `start` to `end`, inclusively,
+    /// without storing each of them.
+    pub const fn from_inclusive_range(start: Ordinal, end: Ordinal) -> OrdinalSet {
+        OrdinalSet {
+            repr: Repr::Range(start, end),
+        }
+    }
+
+    /// Adds `ordinal` to the set, returning whether it was newly inserted.
+    pub fn insert(&mut self, ordinal: Ordinal) -> bool {
+        match self.repr {
+            Repr::Mask(0) if ordinal >= MASK_CAPACITY => {
+                self.repr = Repr::Range(ordinal, ordinal);
+                true
+            }
+            Repr::Range(start, end) if (start..=end).contains(&ordinal) => false,
+            Repr::Range(start, end) if start <= end && Some(ordinal) == end.checked_add(1) => {
+                self.repr = Repr::Range(start, ordinal);
+                true
+            }
+            Repr::Range(start, end) if start <= end && Some(start) == ordinal.checked_add(1) => {
+                self.repr = Repr::Range(ordinal, end);
+                true
+            }
+            Repr::Range(start, end) => {
+                self.repr = if end < MASK_CAPACITY {
+                    Repr::Mask(mask_for_range(&(start..=end)))
+                } else {
+                    Repr::Tree((start..=end).collect())
+                };
+                self.insert(ordinal)
+            }
+            Repr::Mask(ref mut bits) if ordinal < MASK_CAPACITY => {
+                let bit = 1 << ordinal;
+                let inserted = *bits & bit == 0;
+                *bits |= bit;
+                inserted
+            }
+            Repr::Mask(bits) => {
+                let mut tree: BTreeSet<Ordinal> = MaskIter { bits }.collect();
+                let inserted = tree.insert(ordinal);
+                self.repr = Repr::Tree(tree);
+                inserted
+            }
+            Repr::Tree(ref mut tree) => tree.insert(ordinal),
+        }
+    }
+
+    /// Returns whether `ordinal` is a member of the set.
+    pub fn contains(&self, ordinal: &Ordinal) -> bool {
+        match self.repr {
+            Repr::Mask(bits) => *ordinal < MASK_CAPACITY && bits & (1 << *ordinal) != 0,
+            Repr::Range(start, end) => (start..=end).contains(ordinal),
+            Repr::Tree(ref tree) => tree.contains(ordinal),
+        }
+    }
+
+    /// Returns the number of ordinals in the set.
+    pub fn len(&self) -> usize {
+        match self.repr {
+            Repr::Mask(bits) => bits.count_ones() as usize,
+            Repr::Range(start, end) if start <= end => (end - start) as usize + 1,
+            Repr::Range(..) => 0,
+            Repr::Tree(ref tree) => tree.len(),
+        }
+    }
+
+    /// Returns whether the set contains no ordinals.
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// Returns an iterator over the ordinals in the set, in ascending order.
+    pub fn iter(&self) -> Iter<'_> {
+        self.range(..)
+    }
+
+    /// Returns an iterator over the ordinals in the set within `range`, in
+    /// ascending order.
+    pub fn range<R>(&self, range: R) -> Iter<'_>
+    where
+        R: RangeBounds<Ordinal>,
+    {
+        let repr = match self.repr {
+            Repr::Mask(bits) => IterRepr::Mask(MaskIter {
+                bits: bits & mask_for_range(&range),
+            }),
+            Repr::Range(start, end) => {
+                let (range_start, range_end) = inclusive_bounds(&range);
+                IterRepr::Range(start.max(range_start)..=end.min(range_end))
+            }
+            Repr::Tree(ref tree) => {
+                // `BTreeSet::range` panics on inverted ranges.
+                let (start, end) = inclusive_bounds(&range);
+                if start > end {
+                    IterRepr::Mask(MaskIter { bits: 0 })
+                } else {
+                    IterRepr::Tree(tree.range(range))
+                }
+            }
+        };
+        Iter { repr }
+    }
+
+    /// Returns the ordinals in the set as a `Vec`, in ascending order.
+    pub fn to_vec(&self) -> Vec<Ordinal> {
+        self.iter().collect()
+    }
+}
+
+impl Default for OrdinalSet {
+    fn default() -> OrdinalSet {
+        OrdinalSet::new()
+    }
+}
+
+impl PartialEq for OrdinalSet {
+    fn eq(&self, other: &OrdinalSet) -> bool {
+        match (&self.repr, &other.repr) {
+            (Repr::Mask(a), Repr::Mask(b)) => a == b,
+            (Repr::Range(..), Repr::Range(..)) if self.is_empty() => other.is_empty(),
+            (Repr::Range(a_start, a_end), Repr::Range(b_start, b_end)) => {
+                (a_start, a_end) == (b_start, b_end)
+            }
+            (Repr::Tree(a), Repr::Tree(b)) => a == b,
+            _ => self.iter().eq(other.iter()),
+        }
+    }
+}
+
+impl Eq for OrdinalSet {}
+
+impl FromIterator<Ordinal> for OrdinalSet {
+    fn from_iter<I>(iter: I) -> OrdinalSet
+    where
+        I: IntoIterator<Item = Ordinal>,
+    {
+        let mut set = OrdinalSet::new();
+        set.extend(iter);
+        set
+    }
+}
+
+impl Extend<Ordinal> for OrdinalSet {
+    fn extend<I>(&mut self, iter: I)
+    where
+        I: IntoIterator<Item = Ordinal>,
+    {
+        for ordinal in iter {
+            self.insert(ordinal);
+        }
+    }
+}
+
+impl From<RangeInclusive<Ordinal>> for OrdinalSet {
+    fn from(range: RangeInclusive<Ordinal>) -> OrdinalSet {
+        OrdinalSet::from_inclusive_range(*range.start(), *range.end())
+    }
+}
+
+impl From<&[Ordinal]> for OrdinalSet {
+    fn from(ordinals: &[Ordinal]) -> OrdinalSet {
+        ordinals.iter().copied().collect()
+    }
+}
+
+impl<const N: usize> From<[Ordinal; N]> for OrdinalSet {
+    fn from(ordinals: [Ordinal; N]) -> OrdinalSet {
+        ordinals.into_iter().collect()
+    }
+}
+
+impl From<Vec<Ordinal>> for OrdinalSet {
+    fn from(ordinals: Vec<Ordinal>) -> OrdinalSet {
+        ordinals.into_iter().collect()
+    }
+}
+
+impl From<OrdinalSet> for Vec<Ordinal> {
+    fn from(set: OrdinalSet) -> Vec<Ordinal> {
+        set.into_iter().collect()
+    }
+}
+
+impl IntoIterator for OrdinalSet {
+    type Item = Ordinal;
+    type IntoIter = IntoIter;
+
+    fn into_iter(self) -> IntoIter {
+        let repr = match self.repr {
+            Repr::Mask(bits) => IntoIterRepr::Mask(MaskIter { bits }),
+            Repr::Range(start, end) => IntoIterRepr::Range(start..=end),
+            Repr::Tree(tree) => IntoIterRepr::Tree(tree.into_iter()),
+        };
+        IntoIter { repr }
+    }
+}
+
+impl<'a> IntoIterator for &'a OrdinalSet {
+    type Item = Ordinal;
+    type IntoIter = Iter<'a>;
+
+    fn into_iter(self) -> Iter<'a> {
+        self.iter()
+    }
+}
+
+/// Human-readable formats get a sequence of ordinals, e.g. `[2, 3, 4, 5, 6]`.
+/// Compact formats get the set's internal representation, so wildcards and
+/// bitmasks take a few bytes.
+#[cfg(feature = "serde")]
+impl Serialize for OrdinalSet {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        if serializer.is_human_readable() {
+            return serializer.collect_seq(self);
+        }
+        match self.repr {
+            Repr::Mask(bits) => {
+                serializer.serialize_newtype_variant("OrdinalSet", 0, "Mask", &bits)
+            }
+            Repr::Range(start, end) => {
+                serializer.serialize_newtype_variant("OrdinalSet", 1, "Range", &(start, end))
+            }
+            Repr::Tree(ref tree) => {
+                serializer.serialize_newtype_variant("OrdinalSet", 2, "Tree", tree)
+            }
+        }
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<'de> Deserialize<'de> for OrdinalSet {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        if deserializer.is_human_readable() {
+            deserializer.deserialize_seq(OrdinalSetVisitor)
+        } else {
+            deserializer.deserialize_enum("OrdinalSet", REPR_VARIANTS, OrdinalSetVisitor)
+        }
+    }
+}
+
+#[cfg(feature = "serde")]
+const REPR_VARIANTS: &[&str] = &["Mask", "Range", "Tree"];
+
+#[cfg(feature = "serde")]
+struct OrdinalSetVisitor;
+
+#[cfg(feature = "serde")]
+impl<'de> Visitor<'de> for OrdinalSetVisitor {
+    type Value = OrdinalSet;
+
+    fn expecting(&self, formatter: &mut fmt::Formatter<'_>) -> fmt::Result {
+        formatter.write_str("a set of ordinals")
+    }
+
+    fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error>
+    where
+        A: SeqAccess<'de>,
+    {
+        let mut set = OrdinalSet::new();
+        while let Some(ordinal) = seq.next_element()? {
+            set.insert(ordinal);
+        }
+        Ok(set)
+    }
+
+    fn visit_enum<A>(self, data: A) -> Result<Self::Value, A::Error>
+    where
+        A: EnumAccess<'de>,
+    {
+        let (variant, access) = data.variant()?;
+        let repr = match variant {
+            ReprVariant::Mask => Repr::Mask(access.newtype_variant()?),
+            ReprVariant::Range => {
+                let (start, end) = access.newtype_variant()?;
+                Repr::Range(start, end)
+            }
+            // Collected rather than stored as is, so that small ordinals still
+            // end up in a bitmask.
+            ReprVariant::Tree => {
+                let ordinals: Vec<Ordinal> = access.newtype_variant()?;
+                return Ok(ordinals.into());
+            }
+        };
+        Ok(OrdinalSet { repr })
+    }
+}
+
+#[cfg(feature = "serde")]
+enum ReprVariant {
+    Mask,
+    Range,
+    Tree,
+}
+
+#[cfg(feature = "serde")]
+impl<'de> Deserialize<'de> for ReprVariant {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        deserializer.deserialize_identifier(ReprVariantVisitor)
+    }
+}
+
+#[cfg(feature = "serde")]
+struct ReprVariantVisitor;
+
+#[cfg(feature = "serde")]
+impl Visitor<'_> for ReprVariantVisitor {
+    type Value = ReprVariant;
+
+    fn expecting(&self, formatter: &mut fmt::Formatter<'_>) -> fmt::Result {
+        formatter.write_str("an ordinal set representation")
+    }
+
+    fn visit_u64<E>(self, v: u64) -> Result<Self::Value, E>
+    where
+        E: de::Error,
+    {
+        match v {
+            0 => Ok(ReprVariant::Mask),
+            1 => Ok(ReprVariant::Range),
+            2 => Ok(ReprVariant::Tree),
+            _ => Err(de::Error::invalid_value(
+                de::Unexpected::Unsigned(v),
+                &"a variant index below 3",
+            )),
+        }
+    }
+
+    fn visit_str<E>(self, v: &str) -> Result<Self::Value, E>
+    where
+        E: de::Error,
+    {
+        match v {
+            "Mask" => Ok(ReprVariant::Mask),
+            "Range" => Ok(ReprVariant::Range),
+            "Tree" => Ok(ReprVariant::Tree),
+            _ => Err(de::Error::unknown_variant(v, REPR_VARIANTS)),
+        }
+    }
+}
+
+/// Returns the first and last ordinal within `range`. The first is greater
+/// than the last if the range is empty.
+pub(crate) fn inclusive_bounds<R>(range: &R) -> (Ordinal, Ordinal)
+where
+    R: RangeBounds<Ordinal>,
+{
+    let (start, start_overflow) = match range.start_bound() {
+        Bound::Included(&start) => (start, false),
+        Bound::Excluded(&start) => start.overflowing_add(1),
+        Bound::Unbounded => (Ordinal::MIN, false),
+    };
+    let (end, end_overflow) = match range.end_bound() {
+        Bound::Included(&end) => (end, false),
+        Bound::Excluded(&end) => end.overflowing_sub(1),
+        Bound::Unbounded => (Ordinal::MAX, false),
+    };
+    if start_overflow || end_overflow {
+        (Ordinal::MAX, Ordinal::MIN)
+    } else {
+        (start, end)
+    }
+}
+
+/// Returns a mask with the bits for every ordinal within `range` set.
+fn mask_for_range<R>(range: &R) -> u128
+where
+    R: RangeBounds<Ordinal>,
+{
+    let (start, end) = inclusive_bounds(range);
+    let end = end.min(MASK_CAPACITY - 1);
+    if start > end {
+        return 0;
+    }
+    let width = end - start + 1;
+    let low_bits = if width == MASK_CAPACITY {
+        u128::MAX
+    } else {
+        (1 << width) - 1
+    };
+    low_bits << start
+}
+
+#[derive(Clone, Debug)]
+struct MaskIter {
+    bits: u128,
+}
+
+impl Iterator for MaskIter {
+    type Item = Ordinal;
+
+    fn next(&mut self) -> Option<Ordinal> {
+        if self.bits == 0 {
+            return None;
+        }
+        let ordinal = self.bits.trailing_zeros();
+        self.bits &= self.bits - 1;
+        Some(ordinal)
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let len = self.bits.count_ones() as usize;
+        (len, Some(len))
+    }
+}
+
+impl DoubleEndedIterator for MaskIter {
+    fn next_back(&mut self) -> Option<Ordinal> {
+        if self.bits == 0 {
+            return None;
+        }
+        let ordinal = MASK_CAPACITY - 1 - self.bits.leading_zeros();
+        self.bits &= !(1 << ordinal);
+        Some(ordinal)
+    }
+}
+
+/// An iterator over the ordinals of an [`OrdinalSet`], in ascending order.
+///
+/// Created by [`OrdinalSet::iter`] and [`OrdinalSet::range`].
+#[derive(Clone, Debug)]
+pub struct Iter<'a> {
+    repr: IterRepr<'a>,
+}
+
+#[derive(Clone, Debug)]
+enum IterRepr<'a> {
+    Mask(MaskIter),
+    Range(RangeInclusive<Ordinal>),
+    Tree(btree_set::Range<'a, Ordinal>),
+}
+
+impl Iterator for Iter<'_> {
+    type Item = Ordinal;
+
+    fn next(&mut self) -> Option<Ordinal> {
+        match &mut self.repr {
+            IterRepr::Mask(iter) => iter.next(),
+            IterRepr::Range(iter) => iter.next(),
+            IterRepr::Tree(iter) => iter.next().copied(),
+        }
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        match &self.repr {
+            IterRepr::Mask(iter) => iter.size_hint(),
+            IterRepr::Range(iter) => iter.size_hint(),
+            IterRepr::Tree(iter) => iter.size_hint(),
+        }
+    }
+}
+
+impl DoubleEndedIterator for Iter<'_> {
+    fn next_back(&mut self) -> Option<Ordinal> {
+        match &mut self.repr {
+            IterRepr::Mask(iter) => iter.next_back(),
+            IterRepr::Range(iter) => iter.next_back(),
+            IterRepr::Tree(iter) => iter.next_back().copied(),
+        }
+    }
+}
+
+/// An owning iterator over the ordinals of an [`OrdinalSet`], in ascending
+/// order.
+#[derive(Debug)]
+pub struct IntoIter {
+    repr: IntoIterRepr,
+}
+
+#[derive(Debug)]
+enum IntoIterRepr {
+    Mask(MaskIter),
+    Range(RangeInclusive<Ordinal>),
+    Tree(btree_set::IntoIter<Ordinal>),
+}
+
+impl Iterator for IntoIter {
+    type Item = Ordinal;
+
+    fn next(&mut self) -> Option<Ordinal> {
+        match &mut self.repr {
+            IntoIterRepr::Mask(iter) => iter.next(),
+            IntoIterRepr::Range(iter) => iter.next(),
+            IntoIterRepr::Tree(iter) => iter.next(),
+        }
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        match &self.repr {
+            IntoIterRepr::Mask(iter) => iter.size_hint(),
+            IntoIterRepr::Range(iter) => iter.size_hint(),
+            IntoIterRepr::Tree(iter) => iter.size_hint(),
+        }
+    }
+}
+
+impl DoubleEndedIterator for IntoIter {
+    fn next_back(&mut self) -> Option<Ordinal> {
+        match &mut self.repr {
+            IntoIterRepr::Mask(iter) => iter.next_back(),
+            IntoIterRepr::Range(iter) => iter.next_back(),
+            IntoIterRepr::Tree(iter) => iter.next_back(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use std::ops::Bound::{Excluded, Included, Unbounded};
+
+    use super::*;
+
+    #[test]
+    fn test_mask_membership() {
+        let set: OrdinalSet = [0, 5, 59, 127].into_iter().collect();
+        assert!(set.contains(&0));
+        assert!(set.contains(&59));
+        assert!(set.contains(&127));
+        assert!(!set.contains(&1));
+        assert!(!set.contains(&128));
+        assert!(!set.contains(&u32::MAX));
+        assert_eq!(4, set.len());
+    }
+
+    #[test]
+    fn test_mask_range() {
+        let set: OrdinalSet = (0..60).collect();
+        assert!(set.range((Included(10), Excluded(13))).eq([10, 11, 12]));
+        assert!(set.range((Excluded(56), Unbounded)).eq([57, 58, 59]));
+        assert!(set.range((Included(3), Included(1))).next().is_none());
+        assert!(set.range(..).rev().take(2).eq([59, 58]));
+        assert!(set.range((Excluded(u32::MAX), Unbounded)).next().is_none());
+    }
+
+    #[test]
+    fn test_spills_into_tree() {
+        let mut set: OrdinalSet = [3, 1].into_iter().collect();
+        assert!(set.insert(2024));
+        assert!(!set.insert(3));
+        assert!(set.iter().eq([1, 3, 2024]));
+        assert!(set.range(2..).rev().eq([2024, 3]));
+        assert_eq!(set, [2024, 1, 3].into_iter().collect());
+        assert!(set.range((Included(2030), Excluded(2020))).next().is_none());
+        assert!(set.range((Excluded(3), Excluded(3))).next().is_none());
+    }
+
+    #[test]
+    fn test_inclusive_range() {
+        let mut set = OrdinalSet::from_inclusive_range(1970, 2100);
+        assert_eq!(131, set.len());
+        assert!(set.contains(&1970));
+        assert!(set.contains(&2100));
+        assert!(!set.contains(&2101));
+        assert!(set.range((Excluded(2098), Unbounded)).eq([2099, 2100]));
+        assert!(set.range(..1971).rev().eq([1970]));
+        assert!(set.range(..1970).next().is_none());
+        assert!(set.range((Excluded(u32::MAX), Unbounded)).next().is_none());
+        assert_eq!(set, (1970..=2100).collect());
+
+        assert!(!set.insert(2000));
+        assert!(set.insert(2101));
+        assert_eq!(set, OrdinalSet::from_inclusive_range(1970, 2101));
+        assert!(set.insert(3000));
+        assert_eq!(133, set.len());
+
+        let seconds = OrdinalSet::from_inclusive_range(0, 59);
+        assert_eq!(seconds, (0..60).collect());
+        let mut stepped: OrdinalSet = seconds.into_iter().step_by(30).collect();
+        assert!(stepped.iter().eq([0, 30]));
+        assert!(stepped.insert(59));
+        assert_eq!(3, stepped.len());
+    }
+
+    #[test]
+    fn test_conversions() {
+        let set = OrdinalSet::from(&[5, 1, 3][..]);
+        assert_eq!(vec![1, 3, 5], set.to_vec());
+        assert_eq!(set, OrdinalSet::from(vec![3, 5, 1]));
+        assert_eq!(set, OrdinalSet::from([1, 3, 5]));
+        assert_eq!(vec![1, 3, 5], Vec::from(set));
+
+        let set = OrdinalSet::from(2000..=2003);
+        assert_eq!(vec![2000, 2001, 2002, 2003], Vec::from(set));
+        #[allow(clippy::reversed_empty_ranges)]
+        let empty = OrdinalSet::from(3..=1);
+        assert!(empty.is_empty());
+        assert_eq!(empty, OrdinalSet::new());
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn test_ser_de_ordinal_set() {
+        use serde_test::{assert_tokens, Configure, Token};
+
+        assert_tokens(
+            &OrdinalSet::from([2, 4]).readable(),
+            &[
+                Token::Seq { len: Some(2) },
+                Token::U32(2),
+                Token::U32(4),
+                Token::SeqEnd,
+            ],
+        );
+        for set in [
+            OrdinalSet::from([2, 4]),
+            OrdinalSet::from(1970..=2100),
+            OrdinalSet::from([1990, 2000]),
+            OrdinalSet::new(),
+        ] {
+            let bytes = postcard::to_stdvec(&set).unwrap();
+            assert_eq!(set, postcard::from_bytes::<OrdinalSet>(&bytes).unwrap());
+        }
+    }
+}
@@ -1,22 +1,25 @@
 use std::{
     borrow::Cow,
+    collections::HashMap,
     convert::TryFrom,
+    fmt::Write,
+    ops::Range,
+    slice,
     str::{self, FromStr},
+    sync::Arc,
 };
 
 use nom::{
     branch::alt,
-    bytes::complete::tag,
+    bytes::complete::{tag, tag_no_case},
     character::complete::{alpha1, digit1, multispace0},
-    combinator::{all_consuming, eof, map, map_res, opt},
-    multi::separated_list1,
-    sequence::{delimited, separated_pair, terminated, tuple},
+    combinator::{all_consuming, eof, map, map_res, opt, recognize},
+    sequence::{delimited, pair, preceded, separated_pair, terminated, tuple},
     IResult,
 };
 
 use crate::{
     error::{Error, ErrorKind},
-    ordinal::*,
     schedule::{Schedule, ScheduleFields},
     specifier::*,
     time_unit::*,
@@ -28,7 +31,7 @@ impl TryFrom<Cow<'_, str>> for Schedule {
     fn try_from(expression: Cow<'_, str>) -> Result<Self, Self::Error> {
         match schedule(&expression) {
             Ok((_, schedule_fields)) => Ok(Schedule::new(expression.into_owned(), schedule_fields)), /* Extract from nom tuple */
-            Err(_) => Err(ErrorKind::Expression("Invalid cron expression.".to_owned()).into()), /* TODO: Details */
+            Err(_) => Err(diagnose(&expression)),
         }
     }
 }
@@ -52,14 +55,160 @@ impl TryFrom<&str> for Schedule {
 impl FromStr for Schedule {
     type Err = Error;
 
+    /// Parses an expression of six or seven fields, from the seconds to the
+    /// optional years, or one of these nicknames, each firing at the start of
+    /// its period:
+    ///
+    /// | Nickname   | Equivalent            | Fires                           |
+    /// |------------|-----------------------|---------------------------------|
+    /// | `@yearly`  | `0 0 0 1 1 *`         | At midnight on January 1st      |
+    /// | `@monthly` | `0 0 0 1 * *`         | At midnight on the 1st          |
+    /// | `@weekly`  | `0 0 0 * * Sun`       | At midnight on Sundays          |
+    /// | `@weekday` | `0 0 0 * * Mon-Fri`   | At midnight, Monday to Friday   |
+    /// | `@weekend` | `0 0 0 * * Sat,Sun`   | At midnight on Saturday, Sunday |
+    /// | `@daily`   | `0 0 0 * * *`         | At midnight                     |
+    /// | `@hourly`  | `0 0 * * * *`         | At the start of every hour      |
     fn from_str(expression: &str) -> Result<Self, Self::Err> {
         Self::try_from(Cow::Borrowed(expression))
     }
 }
 
+impl Schedule {
+    /// Parses each of `expressions`, returning a result per expression in the
+    /// same order.
+    ///
+    /// Schedules parsed from the same expression share their source, and
+    /// schedules whose fields are identical, such as `0 0 * * * *` and
+    /// `0 0 * * * * *`, share their fields, so parsing many expressions with
+    /// few distinct schedules among them only stores each schedule once.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use jiff_cron::Schedule;
+    ///
+    /// let results = Schedule::parse_many(["@hourly", "0 0 * * * *", "@hourly", "@fortnightly"]);
+    /// assert_eq!(4, results.len());
+    /// assert_eq!(results[0].as_ref().unwrap(), results[1].as_ref().unwrap());
+    /// assert_eq!("@hourly", results[2].as_ref().unwrap().source());
+    /// assert!(results[3].is_err());
+    /// ```
+    pub fn parse_many<'a, I>(expressions: I) -> Vec<Result<Schedule, Error>>
+    where
+        I: IntoIterator<Item = &'a str>,
+    {
+        let expressions = expressions.into_iter();
+        let mut results = Vec::with_capacity(expressions.size_hint().0);
+        let mut by_source: HashMap<&'a str, Schedule> = HashMap::new();
+        let mut by_fields: HashMap<String, Arc<ScheduleFields>> = HashMap::new();
+        // Reused to render each schedule's fields for lookup in `by_fields`.
+        let mut key = String::new();
+        for expression in expressions {
+            if let Some(schedule) = by_source.get(expression) {
+                results.push(Ok(schedule.clone()));
+                continue;
+            }
+            let fields = match schedule(expression) {
+                Ok((_, fields)) => fields,
+                Err(_) => {
+                    results.push(Err(diagnose(expression)));
+                    continue;
+                }
+            };
+            key.clear();
+            write!(key, "{}", fields).expect("writing to a String cannot fail");
+            let fields = match by_fields.get(key.as_str()) {
+                Some(fields) => Arc::clone(fields),
+                None => {
+                    let fields = Arc::new(fields);
+                    by_fields.insert(key.clone(), Arc::clone(&fields));
+                    fields
+                }
+            };
+            let schedule = Schedule::from_shared(Arc::new(expression.to_owned()), fields);
+            by_source.insert(expression, schedule.clone());
+            results.push(Ok(schedule));
+        }
+        results
+    }
+}
+
+impl FromStr for Seconds {
+    type Err = Error;
+
+    fn from_str(expression: &str) -> Result<Self, Self::Err> {
+        parse_field(expression, field)
+    }
+}
+
+impl FromStr for Minutes {
+    type Err = Error;
+
+    fn from_str(expression: &str) -> Result<Self, Self::Err> {
+        parse_field(expression, field)
+    }
+}
+
+impl FromStr for Hours {
+    type Err = Error;
+
+    fn from_str(expression: &str) -> Result<Self, Self::Err> {
+        parse_field(expression, field)
+    }
+}
+
+impl FromStr for DaysOfMonth {
+    type Err = Error;
+
+    fn from_str(expression: &str) -> Result<Self, Self::Err> {
+        parse_field(expression, field_with_any)
+    }
+}
+
+impl FromStr for Months {
+    type Err = Error;
+
+    fn from_str(expression: &str) -> Result<Self, Self::Err> {
+        parse_field(expression, field)
+    }
+}
+
+impl FromStr for DaysOfWeek {
+    type Err = Error;
+
+    fn from_str(expression: &str) -> Result<Self, Self::Err> {
+        parse_field(expression, field_with_any)
+    }
+}
+
+impl FromStr for Years {
+    type Err = Error;
+
+    fn from_str(expression: &str) -> Result<Self, Self::Err> {
+        parse_field(expression, field)
+    }
+}
+
 #[derive(Debug, PartialEq)]
 pub struct Field {
-    pub specifiers: Vec<RootSpecifier>, // TODO: expose iterator?
+    specifiers: Specifiers,
+}
+
+// Most fields consist of a single specifier, which is kept inline rather than
+// in a `Vec`.
+#[derive(Debug, PartialEq)]
+enum Specifiers {
+    One(RootSpecifier),
+    Many(Vec<RootSpecifier>),
+}
+
+impl Field {
+    pub fn specifiers(&self) -> &[RootSpecifier] {
+        match self.specifiers {
+            Specifiers::One(ref specifier) => slice::from_ref(specifier),
+            Specifiers::Many(ref specifiers) => specifiers,
+        }
+    }
 }
 
 trait FromField
@@ -75,31 +224,151 @@ where
     T: TimeUnitField,
 {
     fn from_field(field: Field) -> Result<T, Error> {
-        if field.specifiers.len() == 1
-            && field.specifiers.first().unwrap() == &RootSpecifier::from(Specifier::All)
-        {
-            return Ok(T::all());
+        T::from_specifiers(field.specifiers())
+    }
+}
+
+fn invalid_expression() -> Error {
+    ErrorKind::Expression("Invalid cron expression.".to_owned()).into()
+}
+
+/// Explains why `expression` isn't a valid schedule. Its fields are split up
+/// first, so that a wrong number of fields is reported as such, and then
+/// validated one at a time up to the first invalid one.
+fn diagnose(expression: &str) -> Error {
+    diagnose_fields(expression, &TimeUnit::ALL, &[6, 7]).with_expression(expression)
+}
+
+/// Like [`diagnose`], for an expression whose fields are `units`, of which it
+/// must have one of `counts`.
+fn diagnose_fields(expression: &str, units: &[TimeUnit], counts: &[usize]) -> Error {
+    if expression.trim_start().starts_with('@') {
+        return invalid_expression();
+    }
+    let mut i = expression;
+    let mut fields = Vec::new();
+    // The unit, text and span of a field which couldn't be split up.
+    let mut unparsed = None;
+    while !i.trim_start().is_empty() {
+        let unit = units.get(fields.len()).copied();
+        let parser = match unit {
+            Some(TimeUnit::DaysOfMonth | TimeUnit::DaysOfWeek) => field_with_any,
+            _ => field,
+        };
+        let offset = expression.len() - i.len();
+        let start = expression.len() - i.trim_start().len();
+        // A field must be followed by whitespace, or it was only partially
+        // parsed, like `5` in `5!`.
+        match parser(i) {
+            Ok((rest, field))
+                if rest.is_empty() || i[..i.len() - rest.len()].ends_with(char::is_whitespace) =>
+            {
+                let end = offset + i[..i.len() - rest.len()].trim_end().len();
+                fields.push((field, start..end));
+                i = rest;
+                continue;
+            }
+            _ => {}
         }
-        let mut ordinals = OrdinalSet::new();
-        for specifier in field.specifiers {
-            let specifier_ordinals: OrdinalSet = T::ordinals_from_root_specifier(&specifier)?;
-            for ordinal in specifier_ordinals {
-                ordinals.insert(T::validate_ordinal(ordinal)?);
+        // Count each word from the unparsable one onwards as a field.
+        let mut words = i.split_whitespace();
+        let text = words.next().unwrap_or_default();
+        let found = fields.len() + 1 + words.count();
+        match unit {
+            Some(unit) if counts.contains(&found) => {
+                unparsed = Some((unit, text, start..start + text.len()));
+                break;
             }
+            _ => return ErrorKind::WrongFieldCount { found }.into(),
+        }
+    }
+    if unparsed.is_none() && !counts.contains(&fields.len()) {
+        let found = fields.len();
+        return ErrorKind::WrongFieldCount { found }.into();
+    }
+    let unparsed_index = fields.len();
+    for (index, ((field, span), &unit)) in fields.into_iter().zip(units).enumerate() {
+        let text = &expression[span.clone()];
+        let validated = match unit {
+            TimeUnit::Seconds => validate::<Seconds>(&field, text).map(drop),
+            TimeUnit::Minutes => validate::<Minutes>(&field, text).map(drop),
+            TimeUnit::Hours => validate::<Hours>(&field, text).map(drop),
+            TimeUnit::DaysOfMonth => validate::<DaysOfMonth>(&field, text).map(drop),
+            TimeUnit::Months => validate::<Months>(&field, text).map(drop),
+            TimeUnit::DaysOfWeek => validate::<DaysOfWeek>(&field, text).map(drop),
+            TimeUnit::Years => validate::<Years>(&field, text).map(drop),
+        };
+        if let Err((error, token)) = validated {
+            let token = span.start + token.start..span.start + token.end;
+            return error
+                .with_field_index(index)
+                .with_span(span)
+                .with_token_span(token);
+        }
+    }
+    match unparsed {
+        Some((unit, text, span)) => Error::from(ErrorKind::InvalidField {
+            unit,
+            field: text.to_owned(),
+        })
+        .with_field_index(unparsed_index)
+        .with_span(span.clone())
+        .with_token_span(span),
+        None => invalid_expression(),
+    }
+}
+
+/// Converts `field`, written as `text`, for the unit `T`. On failure, also
+/// returns the range within `text` of the first specifier the unit rejects.
+fn validate<T>(field: &Field, text: &str) -> Result<T, (Error, Range<usize>)>
+where
+    T: TimeUnitField,
+{
+    let error = match T::from_specifiers(field.specifiers()) {
+        Ok(unit) => return Ok(unit),
+        Err(error) => error,
+    };
+    let rejected = field
+        .specifiers()
+        .iter()
+        .position(|specifier| T::from_specifiers(slice::from_ref(specifier)).is_err());
+    // Specifiers never contain commas, so the list elements line up with them.
+    let mut offset = 0;
+    for (index, element) in text.split(',').enumerate() {
+        if Some(index) == rejected {
+            let start = offset + element.len() - element.trim_start().len();
+            return Err((error, start..start + element.trim().len()));
         }
-        Ok(T::from_ordinal_set(ordinals))
+        offset += element.len() + 1;
     }
+    Err((error, 0..text.len()))
+}
+
+fn parse_field<T>(expression: &str, field: fn(&str) -> IResult<&str, Field>) -> Result<T, Error>
+where
+    T: TimeUnitField,
+{
+    let span = 0..expression.len();
+    let error = match all_consuming(field)(expression) {
+        Ok((_, field)) => match validate(&field, expression) {
+            Ok(unit) => return Ok(unit),
+            Err((error, token)) => error.with_token_span(token),
+        },
+        Err(_) => Error::from(ErrorKind::InvalidField {
+            unit: T::unit(),
+            field: expression.to_owned(),
+        })
+        .with_token_span(span.clone()),
+    };
+    Err(error.with_span(span).with_expression(expression))
 }
 
 fn ordinal(i: &str) -> IResult<&str, u32> {
     map_res(delimited(multispace0, digit1, multispace0), u32::from_str)(i)
 }
 
-fn name(i: &str) -> IResult<&str, String> {
-    map(
-        delimited(multispace0, alpha1, multispace0),
-        ToOwned::to_owned,
-    )(i)
+fn name(i: &str) -> IResult<&str, &str> {
+    delimited(multispace0, alpha1, multispace0)(i)
 }
 
 fn point(i: &str) -> IResult<&str, Specifier> {
@@ -109,21 +378,15 @@ fn point(i: &str) -> IResult<&str, Specifier> {
 
 fn named_point(i: &str) -> IResult<&str, RootSpecifier> {
     let (i, n) = name(i)?;
-    Ok((i, RootSpecifier::NamedPoint(n)))
+    Ok((i, RootSpecifier::NamedPoint(n.to_owned())))
 }
 
-fn period(i: &str) -> IResult<&str, RootSpecifier> {
-    map(
-        separated_pair(specifier, tag("/"), ordinal),
-        |(start, step)| RootSpecifier::Period(start, step),
-    )(i)
+fn step(i: &str) -> IResult<&str, u32> {
+    preceded(tag("/"), ordinal)(i)
 }
 
-fn period_with_any(i: &str) -> IResult<&str, RootSpecifier> {
-    map(
-        separated_pair(specifier_with_any, tag("/"), ordinal),
-        |(start, step)| RootSpecifier::Period(start, step),
-    )(i)
+fn offset(i: &str) -> IResult<&str, u32> {
+    preceded(tag("+"), ordinal)(i)
 }
 
 fn range(i: &str) -> IResult<&str, Specifier> {
@@ -134,9 +397,10 @@ fn range(i: &str) -> IResult<&str, Specifier> {
 }
 
 fn named_range(i: &str) -> IResult<&str, Specifier> {
-    map(separated_pair(name, tag("-"), name), |(start, end)| {
-        Specifier::NamedRange(start, end)
-    })(i)
+    // Names are only copied once both have been matched, so backtracking past
+    // a half-matched range doesn't allocate.
+    let (i, (start, end)) = separated_pair(name, tag("-"), name)(i)?;
+    Ok((i, Specifier::NamedRange(start.to_owned(), end.to_owned())))
 }
 
 fn all(i: &str) -> IResult<&str, Specifier> {
@@ -157,38 +421,112 @@ fn specifier_with_any(i: &str) -> IResult<&str, Specifier> {
     alt((any, specifier))(i)
 }
 
+// A specifier is parsed once and then optionally followed by a step, rather
+// than being parsed again if it turns out not to be a period.
+fn stepped(
+    specifier: fn(&str) -> IResult<&str, Specifier>,
+) -> impl FnMut(&str) -> IResult<&str, RootSpecifier> {
+    move |i| {
+        let (i, specifier) = specifier(i)?;
+        let (i, step) = opt(pair(step, opt(offset)))(i)?;
+        let root_specifier = match step {
+            Some((step, Some(offset))) => RootSpecifier::OffsetPeriod(specifier, step, offset),
+            Some((step, None)) => RootSpecifier::Period(specifier, step),
+            None => RootSpecifier::Specifier(specifier),
+        };
+        Ok((i, root_specifier))
+    }
+}
+
+// Jenkins' `H`, optionally limited to a range and stepped, e.g. `H(0-29)/10`.
+// No name starts with `H`, so it's tried first.
+fn hashed(i: &str) -> IResult<&str, RootSpecifier> {
+    let (i, _) = preceded(multispace0, tag("H"))(i)?;
+    let (i, range) = opt(delimited(tag("("), range, tag(")")))(i)?;
+    let (i, step) = opt(step)(i)?;
+    let (i, _) = multispace0(i)?;
+    Ok((
+        i,
+        RootSpecifier::Hashed(range.unwrap_or(Specifier::All), step),
+    ))
+}
+
 fn root_specifier(i: &str) -> IResult<&str, RootSpecifier> {
-    alt((period, map(specifier, RootSpecifier::from), named_point))(i)
+    alt((hashed, stepped(specifier), named_point))(i)
 }
 
-fn root_specifier_with_any(i: &str) -> IResult<&str, RootSpecifier> {
-    alt((
-        period_with_any,
-        map(specifier_with_any, RootSpecifier::from),
-        named_point,
-    ))(i)
+fn occurrence(i: &str) -> IResult<&str, i32> {
+    map_res(recognize(pair(opt(tag("-")), digit1)), i32::from_str)(i)
 }
 
-fn root_specifier_list(i: &str) -> IResult<&str, Vec<RootSpecifier>> {
-    let list = separated_list1(tag(","), root_specifier);
-    let single_item = map(root_specifier, |spec| vec![spec]);
-    delimited(multispace0, alt((list, single_item)), multispace0)(i)
+// A day-of-week point may be followed by its occurrence within the month,
+// e.g. `Fri#-2`, or by `L` for its last occurrence, e.g. `FriL`, and a
+// day-of-month point by `W`, e.g. `15W`, which are only checked for once the
+// point has been parsed. `L`, which may be followed by an offset, e.g. `L-3`,
+// and `LW` are parsed as names first, like the names of the days of the week,
+// which `L` may end.
+fn root_specifier_with_any(i: &str) -> IResult<&str, RootSpecifier> {
+    let (i, root_specifier) = alt((hashed, stepped(specifier_with_any), named_point))(i)?;
+    match root_specifier {
+        RootSpecifier::NamedPoint(ref name) if name.eq_ignore_ascii_case("L") => {
+            let (i, offset) = opt(preceded(tag("-"), ordinal))(i)?;
+            return Ok((i, RootSpecifier::LastDay(offset.unwrap_or(0))));
+        }
+        RootSpecifier::NamedPoint(ref name) if name.eq_ignore_ascii_case("LW") => {
+            return Ok((i, RootSpecifier::LastWeekday))
+        }
+        RootSpecifier::NamedPoint(ref name) if name.ends_with(['L', 'l']) => {
+            let weekday = RootSpecifier::named_point(&name[..name.len() - 1]);
+            return Ok((i, RootSpecifier::nth_weekday(weekday, -1)));
+        }
+        RootSpecifier::Specifier(Specifier::Point(day)) => {
+            if let (i, Some(_)) = opt(tag_no_case("W"))(i)? {
+                return Ok((i, RootSpecifier::NearestWeekday(day)));
+            }
+            if let (i, Some(_)) = opt(tag_no_case("L"))(i)? {
+                return Ok((i, RootSpecifier::nth_weekday(root_specifier, -1)));
+            }
+        }
+        RootSpecifier::NamedPoint(_) => {}
+        _ => return Ok((i, root_specifier)),
+    }
+    match opt(preceded(tag("#"), occurrence))(i)? {
+        (i, Some(nth)) => Ok((i, RootSpecifier::nth_weekday(root_specifier, nth))),
+        (i, None) => Ok((i, root_specifier)),
+    }
 }
 
-fn root_specifier_list_with_any(i: &str) -> IResult<&str, Vec<RootSpecifier>> {
-    let list = separated_list1(tag(","), root_specifier_with_any);
-    let single_item = map(root_specifier_with_any, |spec| vec![spec]);
-    delimited(multispace0, alt((list, single_item)), multispace0)(i)
+fn root_specifier_list(
+    root_specifier: fn(&str) -> IResult<&str, RootSpecifier>,
+) -> impl FnMut(&str) -> IResult<&str, Field> {
+    move |i| {
+        let (mut i, first) = preceded(multispace0, root_specifier)(i)?;
+        let mut specifiers = Specifiers::One(first);
+        while let Ok((rest, next)) = preceded(tag(","), root_specifier)(i) {
+            specifiers = match specifiers {
+                Specifiers::One(first) => {
+                    let mut specifiers = Vec::with_capacity(4);
+                    specifiers.extend([first, next]);
+                    Specifiers::Many(specifiers)
+                }
+                Specifiers::Many(mut specifiers) => {
+                    specifiers.push(next);
+                    Specifiers::Many(specifiers)
+                }
+            };
+            i = rest;
+        }
+        let (i, _) = multispace0(i)?;
+        Ok((i, Field { specifiers }))
+    }
 }
 
 fn field(i: &str) -> IResult<&str, Field> {
-    let (i, specifiers) = root_specifier_list(i)?;
-    Ok((i, Field { specifiers }))
+    root_specifier_list(root_specifier)(i)
 }
 
 fn field_with_any(i: &str) -> IResult<&str, Field> {
-    let (i, specifiers) = root_specifier_list_with_any(i)?;
-    Ok((i, Field { specifiers }))
+    root_specifier_list(root_specifier_with_any)(i)
 }
 
 fn shorthand_yearly(i: &str) -> IResult<&str, ScheduleFields> {
@@ -233,6 +571,34 @@ fn shorthand_weekly(i: &str) -> IResult<&str, ScheduleFields> {
     Ok((i, fields))
 }
 
+fn shorthand_weekday(i: &str) -> IResult<&str, ScheduleFields> {
+    let (i, _) = tag("@weekday")(i)?;
+    let fields = ScheduleFields::new(
+        Seconds::from_ordinal(0),
+        Minutes::from_ordinal(0),
+        Hours::from_ordinal(0),
+        DaysOfMonth::all(),
+        Months::all(),
+        DaysOfWeek::from_ordinal_set((2..=6).into()),
+        Years::all(),
+    );
+    Ok((i, fields))
+}
+
+fn shorthand_weekend(i: &str) -> IResult<&str, ScheduleFields> {
+    let (i, _) = tag("@weekend")(i)?;
+    let fields = ScheduleFields::new(
+        Seconds::from_ordinal(0),
+        Minutes::from_ordinal(0),
+        Hours::from_ordinal(0),
+        DaysOfMonth::all(),
+        Months::all(),
+        DaysOfWeek::from_ordinal_set([1, 7].into()),
+        Years::all(),
+    );
+    Ok((i, fields))
+}
+
 fn shorthand_daily(i: &str) -> IResult<&str, ScheduleFields> {
     let (i, _) = tag("@daily")(i)?;
     let fields = ScheduleFields::new(
@@ -266,6 +632,8 @@ fn shorthand(i: &str) -> IResult<&str, ScheduleFields> {
         shorthand_yearly,
         shorthand_monthly,
         shorthand_weekly,
+        shorthand_weekday,
+        shorthand_weekend,
         shorthand_daily,
         shorthand_hourly,
     ));
@@ -311,9 +679,325 @@ fn schedule(i: &str) -> IResult<&str, ScheduleFields> {
     all_consuming(alt((shorthand, longhand)))(i)
 }
 
+/// Parses the five fields from minutes to days of the week, as in classic
+/// cron expressions, completing the schedule with `seconds`.
+fn longhand_without_seconds<'a>(
+    seconds: &'a Seconds,
+) -> impl FnMut(&str) -> IResult<&str, ScheduleFields> + 'a {
+    move |i| {
+        let minutes = map_res(field, Minutes::from_field);
+        let hours = map_res(field, Hours::from_field);
+        let days_of_month = map_res(field_with_any, DaysOfMonth::from_field);
+        let months = map_res(field, Months::from_field);
+        let days_of_week = map_res(field_with_any, DaysOfWeek::from_field);
+        let fields = tuple((minutes, hours, days_of_month, months, days_of_week));
+        map(
+            all_consuming(fields),
+            |(minutes, hours, days_of_month, months, days_of_week)| {
+                ScheduleFields::new(
+                    seconds.clone(),
+                    minutes,
+                    hours,
+                    days_of_month,
+                    months,
+                    days_of_week,
+                    Years::all(),
+                )
+            },
+        )(i)
+    }
+}
+
+/// Parses each of `words` as a field, without checking the ordinals against
+/// any unit, returning `None` if any of them isn't a list of specifiers.
+pub(crate) fn parse_words(words: &[&str]) -> Option<Vec<Field>> {
+    words
+        .iter()
+        .map(|word| {
+            all_consuming(field_with_any)(word)
+                .ok()
+                .map(|(_, field)| field)
+        })
+        .collect()
+}
+
+/// Parses a classic five-field expression, without seconds, completing it with
+/// `seconds`.
+pub(crate) fn parse_without_seconds(
+    expression: &str,
+    seconds: &Seconds,
+) -> Result<Schedule, Error> {
+    match longhand_without_seconds(seconds)(expression) {
+        Ok((_, fields)) => Ok(Schedule::new(expression.to_owned(), fields)),
+        Err(_) => {
+            Err(diagnose_fields(expression, &TimeUnit::ALL[1..6], &[5]).with_expression(expression))
+        }
+    }
+}
+
 #[cfg(test)]
 mod test {
+    use nom::combinator::verify;
+
     use super::*;
+    use crate::ParseOptions;
+
+    fn period(i: &str) -> IResult<&str, RootSpecifier> {
+        verify(all_consuming(root_specifier), |specifier| {
+            matches!(
+                specifier,
+                RootSpecifier::Period(..) | RootSpecifier::OffsetPeriod(..)
+            )
+        })(i)
+    }
+
+    fn period_with_any(i: &str) -> IResult<&str, RootSpecifier> {
+        verify(all_consuming(root_specifier_with_any), |specifier| {
+            matches!(specifier, RootSpecifier::Period(..))
+        })(i)
+    }
+
+    #[test]
+    fn test_error_help() {
+        let help = |expression| Schedule::from_str(expression).unwrap_err().help();
+        assert_eq!(
+            Some("Did you mean 'Tue'?".to_owned()),
+            help("0 0 0 * * Tuse")
+        );
+        assert_eq!(
+            Some("Did you mean 'Sep'?".to_owned()),
+            help("0 0 0 * Septmber *")
+        );
+        assert_eq!(None, help("0 0 0 * * Holiday"));
+        assert_eq!(
+            Some("Did you mean 'Fri-Sat,Sun-Mon'?".to_owned()),
+            help("0 0 0 * * Fri-Mon")
+        );
+        assert_eq!(
+            Some("Did you mean '50-59,0-10'?".to_owned()),
+            help("0 50-10 * * * *")
+        );
+        // Halves of a single value aren't written as ranges.
+        assert_eq!(
+            Some("Did you mean 'Sat,Sun'?".to_owned()),
+            help("0 0 0 * * Sat-Sun")
+        );
+        assert_eq!(
+            Some("Did you mean 'Sat,Sun-Mon'?".to_owned()),
+            help("0 0 0 * * Sat-Mon")
+        );
+        assert_eq!(
+            Some("Did you mean '59,0-5'?".to_owned()),
+            help("0 59-5 * * * *")
+        );
+        // Ranges of `H` don't wrap.
+        let options = ParseOptions::new().with_hash_key("key");
+        let error = options.parse("0 H(10-5) * * * *").unwrap_err();
+        assert_eq!(
+            ErrorKind::InvalidRange {
+                unit: TimeUnit::Minutes,
+                start: "10".to_owned(),
+                end: "5".to_owned()
+            },
+            *error.kind()
+        );
+        assert_eq!(Some("H(10-5)"), error.token());
+        assert_eq!(None, error.help());
+        assert_eq!(None, help("0 0 0 * * * 2030-2020"));
+        assert_eq!(None, help("0 0 0 * * * * *"));
+        assert_eq!(None, help("0 0 25 * * *"));
+    }
+
+    #[test]
+    fn test_error_kinds() {
+        let kind = |expression| Schedule::from_str(expression).unwrap_err().kind().clone();
+        assert_eq!(
+            ErrorKind::InvalidOrdinal {
+                unit: TimeUnit::Minutes,
+                value: 60,
+                min: 0,
+                max: 59
+            },
+            kind("0 60 * * * *")
+        );
+        assert_eq!(
+            ErrorKind::InvalidRange {
+                unit: TimeUnit::Hours,
+                start: "17".to_owned(),
+                end: "9".to_owned()
+            },
+            kind("0 0 17-9 * * *")
+        );
+        assert_eq!(
+            ErrorKind::InvalidRange {
+                unit: TimeUnit::DaysOfWeek,
+                start: "Fri".to_owned(),
+                end: "Mon".to_owned()
+            },
+            kind("0 0 0 * * Fri-Mon")
+        );
+        assert_eq!(
+            ErrorKind::ZeroStep {
+                unit: TimeUnit::Seconds
+            },
+            kind("*/0 * * * * *")
+        );
+        assert_eq!(
+            ErrorKind::InvalidStep {
+                unit: TimeUnit::Months,
+                step: 13,
+                max: 12
+            },
+            kind("0 0 0 1 */13 *")
+        );
+        assert_eq!(
+            ErrorKind::InvalidOffset {
+                unit: TimeUnit::Minutes,
+                offset: 15,
+                step: 15
+            },
+            kind("0 */15+15 * * * *")
+        );
+        assert_eq!(
+            ErrorKind::UnknownName {
+                unit: TimeUnit::Months,
+                name: "Mun".to_owned()
+            },
+            kind("0 0 0 1 Mun *")
+        );
+        assert_eq!(
+            ErrorKind::UnknownName {
+                unit: TimeUnit::Hours,
+                name: "Mon".to_owned()
+            },
+            kind("0 0 Mon * * *")
+        );
+        assert_eq!(
+            ErrorKind::InvalidField {
+                unit: TimeUnit::DaysOfMonth,
+                field: "1-".to_owned()
+            },
+            kind("0 0 0 1- * *")
+        );
+        assert_eq!(
+            ErrorKind::InvalidOccurrence {
+                unit: TimeUnit::DaysOfWeek,
+                nth: 6
+            },
+            kind("0 0 0 * * Fri#6")
+        );
+        assert_eq!(
+            ErrorKind::InvalidOccurrence {
+                unit: TimeUnit::DaysOfWeek,
+                nth: 0
+            },
+            kind("0 0 0 * * 6#0")
+        );
+        assert_eq!(
+            ErrorKind::InvalidField {
+                unit: TimeUnit::DaysOfMonth,
+                field: "Mon#2".to_owned()
+            },
+            kind("0 0 0 Mon#2 * *")
+        );
+        assert_eq!(
+            ErrorKind::InvalidField {
+                unit: TimeUnit::DaysOfWeek,
+                field: "Mon-Fri#2".to_owned()
+            },
+            kind("0 0 0 * * Mon-Fri#2")
+        );
+        // Only the Quartz and EventBridge dialects read a bare `L` as Saturday.
+        assert_eq!(
+            ErrorKind::InvalidField {
+                unit: TimeUnit::DaysOfWeek,
+                field: "L".to_owned()
+            },
+            kind("0 0 0 * * L")
+        );
+        assert_eq!(
+            ErrorKind::InvalidOrdinal {
+                unit: TimeUnit::DaysOfMonth,
+                value: 32,
+                min: 1,
+                max: 31
+            },
+            kind("0 0 0 32W * *")
+        );
+        assert_eq!(
+            ErrorKind::InvalidField {
+                unit: TimeUnit::DaysOfWeek,
+                field: "2W".to_owned()
+            },
+            kind("0 0 0 * * 2W")
+        );
+        assert_eq!(
+            ErrorKind::UnknownName {
+                unit: TimeUnit::DaysOfWeek,
+                name: "Fry".to_owned()
+            },
+            kind("0 0 0 * * FryL")
+        );
+        assert_eq!(
+            ErrorKind::InvalidField {
+                unit: TimeUnit::DaysOfMonth,
+                field: "L-31".to_owned()
+            },
+            kind("0 0 0 L-31 * *")
+        );
+        assert_eq!(ErrorKind::WrongFieldCount { found: 5 }, kind("0 0 * * *"));
+        assert_eq!(
+            ErrorKind::WrongFieldCount { found: 8 },
+            kind("0 0 0 * * * * *")
+        );
+        assert_eq!(
+            ErrorKind::WrongFieldCount { found: 8 },
+            kind("0 0 0 * * * * 5!")
+        );
+        assert!(matches!(kind("@fortnightly"), ErrorKind::Expression(_)));
+        assert_eq!("JC0001", kind("@fortnightly").code());
+        assert_eq!("JC0005", kind("*/0 * * * * *").code());
+        assert_eq!("JC0008", kind("0 0 * * *").code());
+        assert_eq!(None, kind("0 0 * * *").unit());
+    }
+
+    #[test]
+    fn test_error_spans() {
+        let span = |expression| Schedule::from_str(expression).unwrap_err().span();
+        assert_eq!(Some(2..4), span("0 60 * * * *"));
+        assert_eq!(Some(5..13), span("0  0 17-9 , 8 * * *"));
+        assert_eq!(Some(11..13), span(" 0 0 0 * * 1- "));
+        assert_eq!(None, span("0 0 * * *"));
+        assert_eq!(Some(0..4), Hours::from_str("9-25").unwrap_err().span());
+    }
+
+    #[test]
+    fn test_error_context() {
+        let expression = "0  0 8 , 17-9 * * *";
+        let error = Schedule::from_str(expression).unwrap_err();
+        assert_eq!(Some(expression), error.expression());
+        assert_eq!(Some(2), error.field_index());
+        assert_eq!(Some(9..13), error.token_span());
+        assert_eq!(Some("17-9"), error.token());
+
+        let error = Schedule::from_str("0 0 9 * Jan,Fob *").unwrap_err();
+        assert_eq!(Some(4), error.field_index());
+        assert_eq!(Some("Fob"), error.token());
+
+        let error = Schedule::from_str("0 0 0 * * 1- ").unwrap_err();
+        assert_eq!(Some(5), error.field_index());
+        assert_eq!(Some("1-"), error.token());
+
+        let error = Schedule::from_str("0 0 * * *").unwrap_err();
+        assert_eq!(Some("0 0 * * *"), error.expression());
+        assert_eq!(None, error.field_index());
+        assert_eq!(None, error.token());
+
+        let error = Minutes::from_str("0,15,*/0").unwrap_err();
+        assert_eq!(Some("0,15,*/0"), error.expression());
+        assert_eq!(None, error.field_index());
+        assert_eq!(Some("*/0"), error.token());
+    }
 
     #[test]
     fn test_nom_valid_number() {
@@ -351,6 +1035,36 @@ mod test {
         assert!(period(expression).is_err());
     }
 
+    #[test]
+    fn test_nom_valid_period_with_offset() {
+        assert_eq!(
+            Ok(("", RootSpecifier::OffsetPeriod(Specifier::All, 15, 5))),
+            period("*/15+5")
+        );
+        period("10-40/10+3").unwrap();
+        period("Mon-Fri/2+1").unwrap();
+    }
+
+    #[test]
+    fn test_nom_invalid_period_with_offset() {
+        assert!(period("*+5").is_err());
+        assert!(period("*/15+").is_err());
+        assert!(period("*/15+5+1").is_err());
+    }
+
+    #[test]
+    fn test_nom_valid_period_list() {
+        let (_, field) = all_consuming(field)("1-5/2,10,20-30/5").unwrap();
+        assert_eq!(
+            &[
+                RootSpecifier::Period(Specifier::Range(1, 5), 2),
+                RootSpecifier::from(10),
+                RootSpecifier::Period(Specifier::Range(20, 30), 5),
+            ],
+            field.specifiers()
+        );
+    }
+
     #[test]
     fn test_nom_valid_number_list() {
         let expression = "1,2";
@@ -668,6 +1382,30 @@ mod test {
         Schedule::from_str(expression).unwrap();
     }
 
+    #[test]
+    fn test_field_from_str() {
+        assert_eq!(
+            DaysOfWeek::from_str("MON-FRI").unwrap(),
+            DaysOfWeek::from_ordinal_set((2..=6).collect())
+        );
+        assert_eq!(
+            Minutes::from_str("*/15").unwrap(),
+            Minutes::from_ordinal_set([0, 15, 30, 45].into_iter().collect())
+        );
+        assert_eq!(Seconds::from_str(" * ").unwrap(), Seconds::all());
+        assert_eq!(DaysOfMonth::from_str("?").unwrap(), DaysOfMonth::all());
+        assert_eq!(
+            Years::from_str("2020,2030").unwrap(),
+            Years::from_ordinal_set([2020, 2030].into_iter().collect())
+        );
+
+        assert!(Hours::from_str("?").is_err());
+        assert!(Hours::from_str("24").is_err());
+        assert!(Months::from_str("Foo").is_err());
+        assert!(Minutes::from_str("1 2").is_err());
+        assert!(Seconds::from_str("").is_err());
+    }
+
     /// Issue #59
     #[test]
     fn test_reject_invalid_interval() {
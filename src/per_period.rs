@@ -0,0 +1,325 @@
+use jiff::{civil::Date, SignedDuration, ToSpan, Zoned};
+
+use crate::error::{Error, ErrorKind};
+
+/// Implemented by the schedule iterators, which move to any position in
+/// constant time, so adapters can skip fire times without visiting them.
+pub(crate) trait Seek: DoubleEndedIterator<Item = Zoned> {
+    /// Moves the iterator to `position`, which its next fire time follows and
+    /// its previous one precedes. `None` exhausts the iterator.
+    fn seek(&mut self, position: Option<Zoned>);
+}
+
+/// Returns the start of the day after the one `zoned` falls on.
+fn start_of_next_day(zoned: &Zoned) -> Option<Zoned> {
+    zoned
+        .date()
+        .tomorrow()
+        .ok()?
+        .to_zoned(zoned.time_zone().clone())
+        .ok()
+}
+
+/// Returns the start of the month after the one `zoned` falls on.
+fn start_of_next_month(zoned: &Zoned) -> Option<Zoned> {
+    zoned
+        .date()
+        .first_of_month()
+        .checked_add(1.month())
+        .ok()?
+        .to_zoned(zoned.time_zone().clone())
+        .ok()
+}
+
+/// Returns the last instant before `zoned`.
+fn just_before(zoned: &Zoned) -> Option<Zoned> {
+    zoned.checked_sub(SignedDuration::from_nanos(1)).ok()
+}
+
+/// An iterator over the first fire times of each day of a schedule iterator,
+/// returned by [`ScheduleIterator::limit_per_day`] and
+/// [`OwnedScheduleIterator::limit_per_day`].
+///
+/// [`ScheduleIterator::limit_per_day`]: crate::ScheduleIterator::limit_per_day
+/// [`OwnedScheduleIterator::limit_per_day`]: crate::OwnedScheduleIterator::limit_per_day
+#[derive(Clone, Debug)]
+pub struct LimitPerDay<I> {
+    iter: I,
+    limit: usize,
+    day: Option<Date>,
+    count: usize,
+}
+
+impl<I> LimitPerDay<I> {
+    pub(crate) fn new(iter: I, limit: usize) -> LimitPerDay<I> {
+        LimitPerDay {
+            iter,
+            limit,
+            day: None,
+            count: 0,
+        }
+    }
+}
+
+impl<I> Iterator for LimitPerDay<I>
+where
+    I: Seek,
+{
+    type Item = Zoned;
+
+    fn next(&mut self) -> Option<Zoned> {
+        if self.limit == 0 {
+            return None;
+        }
+        loop {
+            let fire = self.iter.next()?;
+            if self.day != Some(fire.date()) {
+                self.day = Some(fire.date());
+                self.count = 0;
+            }
+            if self.count < self.limit {
+                self.count += 1;
+                return Some(fire);
+            }
+            // Skip the rest of the day.
+            let end_of_day = start_of_next_day(&fire).and_then(|start| just_before(&start));
+            self.iter.seek(end_of_day);
+        }
+    }
+}
+
+#[derive(Clone, Copy, Debug)]
+enum Period {
+    Day,
+    Month,
+}
+
+#[derive(Clone, Copy, Debug)]
+enum Edge {
+    First,
+    Last,
+}
+
+/// An iterator over the first or last fire time of each day or month of a
+/// schedule iterator, returned by methods such as
+/// [`ScheduleIterator::last_of_each_day`].
+///
+/// [`ScheduleIterator::last_of_each_day`]: crate::ScheduleIterator::last_of_each_day
+#[derive(Clone, Debug)]
+pub struct EachPeriod<I> {
+    iter: I,
+    period: Period,
+    edge: Edge,
+}
+
+impl<I> EachPeriod<I> {
+    pub(crate) fn first_of_each_day(iter: I) -> EachPeriod<I> {
+        EachPeriod {
+            iter,
+            period: Period::Day,
+            edge: Edge::First,
+        }
+    }
+
+    pub(crate) fn last_of_each_day(iter: I) -> EachPeriod<I> {
+        EachPeriod {
+            iter,
+            period: Period::Day,
+            edge: Edge::Last,
+        }
+    }
+
+    pub(crate) fn first_of_each_month(iter: I) -> EachPeriod<I> {
+        EachPeriod {
+            iter,
+            period: Period::Month,
+            edge: Edge::First,
+        }
+    }
+
+    pub(crate) fn last_of_each_month(iter: I) -> EachPeriod<I> {
+        EachPeriod {
+            iter,
+            period: Period::Month,
+            edge: Edge::Last,
+        }
+    }
+}
+
+impl<I> Iterator for EachPeriod<I>
+where
+    I: Seek,
+{
+    type Item = Zoned;
+
+    fn next(&mut self) -> Option<Zoned> {
+        let fire = self.iter.next()?;
+        let next_period = match self.period {
+            Period::Day => start_of_next_day(&fire),
+            Period::Month => start_of_next_month(&fire),
+        };
+        match self.edge {
+            Edge::First => {
+                self.iter
+                    .seek(next_period.and_then(|start| just_before(&start)));
+                Some(fire)
+            }
+            // The last fire time of the period is the one preceding the next
+            // period, which leaves the iterator positioned at it. A period
+            // running past the largest supported time never ends, so it has
+            // no last fire time.
+            Edge::Last => {
+                self.iter.seek(next_period);
+                self.iter.next_back()
+            }
+        }
+    }
+}
+
+/// An iterator over the fire times of a schedule iterator which ends with an
+/// [`ErrorKind::EndOfSchedule`] error once the schedule runs out of fire
+/// times, returned by [`ScheduleIterator::with_end_marker`] and
+/// [`OwnedScheduleIterator::with_end_marker`].
+///
+/// [`ScheduleIterator::with_end_marker`]: crate::ScheduleIterator::with_end_marker
+/// [`OwnedScheduleIterator::with_end_marker`]: crate::OwnedScheduleIterator::with_end_marker
+#[derive(Clone, Debug)]
+pub struct WithEndMarker<I> {
+    iter: I,
+    last: Option<Zoned>,
+    ended: bool,
+}
+
+impl<I> WithEndMarker<I> {
+    pub(crate) fn new(iter: I) -> WithEndMarker<I> {
+        WithEndMarker {
+            iter,
+            last: None,
+            ended: false,
+        }
+    }
+}
+
+impl<I> Iterator for WithEndMarker<I>
+where
+    I: Iterator<Item = Zoned>,
+{
+    type Item = Result<Zoned, Error>;
+
+    fn next(&mut self) -> Option<Result<Zoned, Error>> {
+        if self.ended {
+            return None;
+        }
+        match self.iter.next() {
+            Some(fire) => {
+                self.last = Some(fire.clone());
+                Some(Ok(fire))
+            }
+            None => {
+                self.ended = true;
+                let last = self.last.take();
+                Some(Err(ErrorKind::EndOfSchedule { last }.into()))
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use std::str::FromStr;
+
+    use jiff::{civil::date, tz::TimeZone};
+
+    use crate::{error::ErrorKind, Schedule};
+
+    #[test]
+    fn test_limit_per_day() {
+        let time_zone = TimeZone::get("America/New_York").unwrap();
+        let schedule = Schedule::from_str("* * 23 * * *").unwrap();
+        let after = date(2025, 1, 1).at(0, 0, 0, 0).to_zoned(time_zone).unwrap();
+        let fires: Vec<_> = schedule
+            .after_owned(after.clone())
+            .limit_per_day(1)
+            .take(3)
+            .map(|fire| fire.datetime())
+            .collect();
+        assert_eq!(
+            vec![
+                date(2025, 1, 1).at(23, 0, 0, 0),
+                date(2025, 1, 2).at(23, 0, 0, 0),
+                date(2025, 1, 3).at(23, 0, 0, 0),
+            ],
+            fires
+        );
+        assert_eq!(None, schedule.after(&after).limit_per_day(0).next());
+
+        let schedule = Schedule::from_str("0 0 12 * * * 2025").unwrap();
+        assert_eq!(365, schedule.after(&after).limit_per_day(3).count());
+    }
+
+    #[test]
+    fn test_each_period() {
+        let time_zone = TimeZone::get("Europe/London").unwrap();
+        let schedule = Schedule::from_str("* * * * * * 2019").unwrap();
+        let after = date(2019, 3, 30)
+            .at(12, 0, 0, 0)
+            .to_zoned(time_zone)
+            .unwrap();
+        let datetimes = |iter: &mut dyn Iterator<Item = jiff::Zoned>| {
+            iter.take(2).map(|fire| fire.datetime()).collect::<Vec<_>>()
+        };
+
+        assert_eq!(
+            vec![
+                date(2019, 3, 30).at(12, 0, 1, 0),
+                date(2019, 3, 31).at(0, 0, 0, 0)
+            ],
+            datetimes(&mut schedule.after(&after).first_of_each_day())
+        );
+        assert_eq!(
+            vec![
+                date(2019, 3, 30).at(23, 59, 59, 0),
+                date(2019, 3, 31).at(23, 59, 59, 0)
+            ],
+            datetimes(&mut schedule.after_owned(after.clone()).last_of_each_day())
+        );
+        assert_eq!(
+            vec![
+                date(2019, 3, 30).at(12, 0, 1, 0),
+                date(2019, 4, 1).at(0, 0, 0, 0)
+            ],
+            datetimes(&mut schedule.after(&after).first_of_each_month())
+        );
+        assert_eq!(
+            vec![
+                date(2019, 3, 31).at(23, 59, 59, 0),
+                date(2019, 4, 30).at(23, 59, 59, 0)
+            ],
+            datetimes(&mut schedule.after(&after).last_of_each_month())
+        );
+        assert_eq!(12 - 2, schedule.after(&after).last_of_each_month().count());
+    }
+
+    #[test]
+    fn test_with_end_marker() {
+        let schedule = Schedule::from_str("0 0 0 1 1 * 2099-2100").unwrap();
+        let after = date(2098, 6, 1).in_tz("UTC").unwrap();
+        let mut fires = schedule.after(&after).with_end_marker();
+        let first = fires.next().unwrap().unwrap();
+        let last = fires.next().unwrap().unwrap();
+        assert_eq!((2099, 2100), (first.year(), last.year()));
+        let error = fires.next().unwrap().unwrap_err();
+        assert_eq!(&ErrorKind::EndOfSchedule { last: Some(last) }, error.kind());
+        assert!(fires.next().is_none());
+
+        let mut fires = schedule.after_owned(first).with_end_marker();
+        assert!(fires.next().unwrap().is_ok());
+        assert!(fires.next().unwrap().is_err());
+
+        // An iterator which is already exhausted ends straight away.
+        let after = date(2100, 6, 1).in_tz("UTC").unwrap();
+        let mut fires = schedule.after(&after).with_end_marker();
+        let error = fires.next().unwrap().unwrap_err();
+        assert_eq!(&ErrorKind::EndOfSchedule { last: None }, error.kind());
+    }
+}
@@ -0,0 +1,69 @@
+use jiff::{SignedDuration, Zoned};
+
+/// How far a time is between the fire times of a schedule surrounding it, as
+/// returned by [`Schedule::phase`](crate::Schedule::phase).
+///
+/// # Example
+///
+/// ```rust
+/// use std::str::FromStr;
+///
+/// use jiff_cron::{
+///     jiff::{civil::date, tz::TimeZone, SignedDuration},
+///     Schedule,
+/// };
+///
+/// let schedule = Schedule::from_str("0 0 */6 * * *").unwrap();
+/// let now = date(2025, 1, 1)
+///     .at(13, 30, 0, 0)
+///     .to_zoned(TimeZone::UTC)
+///     .unwrap();
+/// let phase = schedule.phase(&now).unwrap();
+/// assert_eq!(12, phase.previous().hour());
+/// assert_eq!(18, phase.next().hour());
+/// assert_eq!(0.25, phase.fraction());
+/// assert_eq!(SignedDuration::from_mins(90), phase.elapsed());
+/// assert_eq!(SignedDuration::from_mins(270), phase.remaining());
+/// ```
+#[derive(Clone, Debug, PartialEq)]
+pub struct Phase {
+    previous: Zoned,
+    next: Zoned,
+    now: Zoned,
+}
+
+impl Phase {
+    pub(crate) fn new(previous: Zoned, next: Zoned, now: Zoned) -> Phase {
+        Phase {
+            previous,
+            next,
+            now,
+        }
+    }
+
+    /// Returns the latest fire time at or before the time.
+    pub fn previous(&self) -> &Zoned {
+        &self.previous
+    }
+
+    /// Returns the earliest fire time after the time.
+    pub fn next(&self) -> &Zoned {
+        &self.next
+    }
+
+    /// Returns the share of the time between the two fire times which has
+    /// passed, from `0.0` at the previous one up to, but excluding, `1.0`.
+    pub fn fraction(&self) -> f64 {
+        self.elapsed().as_secs_f64() / self.next.duration_since(&self.previous).as_secs_f64()
+    }
+
+    /// Returns the time passed since the previous fire time.
+    pub fn elapsed(&self) -> SignedDuration {
+        self.now.duration_since(&self.previous)
+    }
+
+    /// Returns the time left until the next fire time.
+    pub fn remaining(&self) -> SignedDuration {
+        self.next.duration_since(&self.now)
+    }
+}
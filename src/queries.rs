@@ -1,197 +1,313 @@
-use jiff::{SignedDuration, Zoned};
+use jiff::{
+    civil::{date, time, Date, DateTime, Time},
+    tz::TimeZone,
+    SignedDuration, Zoned,
+};
 
 use crate::{
-    ordinal::Ordinal,
-    time_unit::{DaysOfMonth, Hours, Minutes, Months, Seconds, TimeUnitField},
+    ordinal::{Ordinal, OrdinalSet},
+    time_unit::{DaysOfMonth, DaysOfWeek, TimeUnitField},
 };
 
-const ONE_SECOND: SignedDuration = SignedDuration::from_secs(1);
+pub const ONE_SECOND: SignedDuration = SignedDuration::from_secs(1);
 
-// TODO: Possibility of one query struct?
+const YEAR: usize = 0;
+const MONTH: usize = 1;
+const DAY_OF_MONTH: usize = 2;
+const HOUR: usize = 3;
+const MINUTE: usize = 4;
+const SECOND: usize = 5;
+const UNITS: usize = 6;
 
-pub struct NextAfterQuery {
-    initial_datetime: Zoned,
-    first_month: bool,
-    first_day_of_month: bool,
-    first_hour: bool,
-    first_minute: bool,
-    first_second: bool,
-}
+// The days of the month are clamped to the length of each month when seeking.
+const MIN_VALUES: [Ordinal; UNITS] = [Ordinal::MIN, 1, 1, 0, 0, 0];
+const MAX_VALUES: [Ordinal; UNITS] = [Ordinal::MAX, 12, 31, 23, 59, 59];
 
-impl NextAfterQuery {
-    pub fn from(after: &Zoned) -> NextAfterQuery {
-        NextAfterQuery {
-            initial_datetime: after.clone().saturating_add(ONE_SECOND),
-            first_month: true,
-            first_day_of_month: true,
-            first_hour: true,
-            first_minute: true,
-            first_second: true,
-        }
-    }
+// Years before this precede every ordinal, and can't be written as ordinals.
+const FIRST_ORDINAL_DATE_TIME: DateTime = date(0, 1, 1).at(0, 0, 0, 0);
 
-    pub fn year_lower_bound(&self) -> Ordinal {
-        // Unlike the other units, years will never wrap around.
-        self.initial_datetime.year() as u32
-    }
+/// Finds the date-times matching a schedule by treating its fields like the
+/// wheels of an odometer.
+///
+/// Starting from the most significant unit, each field seeks to its next
+/// included ordinal at or after the current value. When a field runs out of
+/// ordinals, the next more significant field is advanced by one and the
+/// search resumes from there, so sparse schedules such as `0 0 0 29 2 *` are
+/// resolved with a handful of lookups rather than by stepping through
+/// candidates.
+pub struct Seeker<'a> {
+    years: &'a OrdinalSet,
+    months: &'a OrdinalSet,
+    days_of_month: &'a DaysOfMonth,
+    days_of_week: &'a DaysOfWeek,
+    hours: &'a OrdinalSet,
+    minutes: &'a OrdinalSet,
+    seconds: &'a OrdinalSet,
+}
 
-    pub fn month_lower_bound(&mut self) -> Ordinal {
-        if self.first_month {
-            self.first_month = false;
-            return self.initial_datetime.month() as u32;
+impl<'a> Seeker<'a> {
+    pub fn new(
+        years: &'a OrdinalSet,
+        months: &'a OrdinalSet,
+        days_of_month: &'a DaysOfMonth,
+        days_of_week: &'a DaysOfWeek,
+        hours: &'a OrdinalSet,
+        minutes: &'a OrdinalSet,
+        seconds: &'a OrdinalSet,
+    ) -> Seeker<'a> {
+        Seeker {
+            years,
+            months,
+            days_of_month,
+            days_of_week,
+            hours,
+            minutes,
+            seconds,
         }
-        Months::inclusive_min()
-    }
-
-    pub fn reset_month(&mut self) {
-        self.first_month = false;
-        self.reset_day_of_month();
     }
 
-    pub fn day_of_month_lower_bound(&mut self) -> Ordinal {
-        if self.first_day_of_month {
-            self.first_day_of_month = false;
-            return self.initial_datetime.day() as u32;
+    /// Returns the earliest matching date-time at or after `start`.
+    pub fn next_from(&self, start: DateTime) -> Option<DateTime> {
+        let mut values = civil_values(start.max(FIRST_ORDINAL_DATE_TIME));
+        let mut unit = YEAR;
+        while unit < UNITS {
+            let max = self.max_value(unit, &values);
+            let found = if unit == DAY_OF_MONTH && self.has_month_days() {
+                (values[unit]..=max).find(|&day| self.includes_day(&values, day))
+            } else if unit == DAY_OF_MONTH {
+                self.days_of_month
+                    .ordinals()
+                    .range(values[unit]..=max)
+                    .find(|&day| self.includes_weekday(&values, day))
+            } else {
+                self.set(unit).range(values[unit]..=max).next()
+            };
+            match found {
+                Some(ordinal) => {
+                    if ordinal != values[unit] {
+                        values[unit] = ordinal;
+                        values[unit + 1..].copy_from_slice(&MIN_VALUES[unit + 1..]);
+                    }
+                    unit += 1;
+                }
+                // Carry into the next more significant unit.
+                None if unit == YEAR => return None,
+                None => {
+                    unit -= 1;
+                    values[unit] = values[unit].checked_add(1)?;
+                    values[unit + 1..].copy_from_slice(&MIN_VALUES[unit + 1..]);
+                }
+            }
         }
-        DaysOfMonth::inclusive_min()
+        Some(civil_date_time(&values))
     }
 
-    pub fn reset_day_of_month(&mut self) {
-        self.first_day_of_month = false;
-        self.reset_hour();
-    }
-
-    pub fn hour_lower_bound(&mut self) -> Ordinal {
-        if self.first_hour {
-            self.first_hour = false;
-            return self.initial_datetime.hour() as u32;
+    /// Returns the latest matching date-time at or before `start`.
+    pub fn prev_from(&self, start: DateTime) -> Option<DateTime> {
+        if start < FIRST_ORDINAL_DATE_TIME {
+            return None;
         }
-        Hours::inclusive_min()
-    }
-
-    pub fn reset_hour(&mut self) {
-        self.first_hour = false;
-        self.reset_minute();
-    }
-
-    pub fn minute_lower_bound(&mut self) -> Ordinal {
-        if self.first_minute {
-            self.first_minute = false;
-            return self.initial_datetime.minute() as u32;
+        let mut values = civil_values(start);
+        let mut unit = YEAR;
+        while unit < UNITS {
+            let min = MIN_VALUES[unit];
+            let end = values[unit].min(self.max_value(unit, &values));
+            let found = if unit == DAY_OF_MONTH && self.has_month_days() {
+                (min..=end)
+                    .rev()
+                    .find(|&day| self.includes_day(&values, day))
+            } else if unit == DAY_OF_MONTH {
+                self.days_of_month
+                    .ordinals()
+                    .range(min..=end)
+                    .rev()
+                    .find(|&day| self.includes_weekday(&values, day))
+            } else {
+                self.set(unit).range(min..=end).next_back()
+            };
+            match found {
+                Some(ordinal) => {
+                    if ordinal != values[unit] {
+                        values[unit] = ordinal;
+                        values[unit + 1..].copy_from_slice(&MAX_VALUES[unit + 1..]);
+                    }
+                    unit += 1;
+                }
+                // Borrow from the next more significant unit, which may itself
+                // already be at its minimum.
+                None => loop {
+                    if unit == YEAR {
+                        return None;
+                    }
+                    unit -= 1;
+                    if let Some(ordinal) = values[unit].checked_sub(1) {
+                        values[unit] = ordinal;
+                        values[unit + 1..].copy_from_slice(&MAX_VALUES[unit + 1..]);
+                        break;
+                    }
+                },
+            }
         }
-        Minutes::inclusive_min()
-    }
-
-    pub fn reset_minute(&mut self) {
-        self.first_minute = false;
-        self.reset_second();
+        Some(civil_date_time(&values))
     }
 
-    pub fn second_lower_bound(&mut self) -> Ordinal {
-        if self.first_second {
-            self.first_second = false;
-            return self.initial_datetime.second() as u32;
+    fn set(&self, unit: usize) -> &'a OrdinalSet {
+        match unit {
+            YEAR => self.years,
+            MONTH => self.months,
+            DAY_OF_MONTH => self.days_of_month.ordinals(),
+            HOUR => self.hours,
+            MINUTE => self.minutes,
+            _ => self.seconds,
         }
-        Seconds::inclusive_min()
     }
 
-    pub fn reset_second(&mut self) {
-        self.first_second = false;
-    }
-} // End of impl
-
-pub struct PrevFromQuery {
-    initial_datetime: Zoned,
-    first_month: bool,
-    first_day_of_month: bool,
-    first_hour: bool,
-    first_minute: bool,
-    first_second: bool,
-}
-
-impl PrevFromQuery {
-    pub fn from(before: &Zoned) -> PrevFromQuery {
-        let initial_datetime = if before.subsec_nanosecond() > 0 {
-            before.clone()
+    fn max_value(&self, unit: usize, values: &[Ordinal; UNITS]) -> Ordinal {
+        if unit == DAY_OF_MONTH {
+            date(values[YEAR] as i16, values[MONTH] as i8, 1).days_in_month() as Ordinal
         } else {
-            before.saturating_sub(ONE_SECOND)
-        };
-        PrevFromQuery {
-            initial_datetime,
-            first_month: true,
-            first_day_of_month: true,
-            first_hour: true,
-            first_minute: true,
-            first_second: true,
+            MAX_VALUES[unit]
         }
     }
 
-    pub fn year_upper_bound(&self) -> Ordinal {
-        // Unlike the other units, years will never wrap around.
-        self.initial_datetime.year() as u32
+    // Days depending on the length of the month can't be sought among the
+    // ordinals, so every day of the month is checked instead.
+    fn has_month_days(&self) -> bool {
+        !self.days_of_month.month_days().is_empty()
     }
 
-    pub fn month_upper_bound(&mut self) -> Ordinal {
-        if self.first_month {
-            self.first_month = false;
-            return self.initial_datetime.month() as u32;
-        }
-        Months::inclusive_max()
+    fn includes_day(&self, values: &[Ordinal; UNITS], day_of_month: Ordinal) -> bool {
+        let date = date(values[YEAR] as i16, values[MONTH] as i8, day_of_month as i8);
+        self.days_of_month.includes_date(date) && self.days_of_week.includes_date(date)
     }
 
-    pub fn reset_month(&mut self) {
-        self.first_month = false;
-        self.reset_day_of_month();
+    fn includes_weekday(&self, values: &[Ordinal; UNITS], day_of_month: Ordinal) -> bool {
+        let date = date(values[YEAR] as i16, values[MONTH] as i8, day_of_month as i8);
+        self.days_of_week.includes_date(date)
     }
+}
+
+/// The times of day a schedule fires at, in ascending order, as the mixed
+/// radix numbers formed by its hours, minutes and seconds.
+pub struct TimesOfDay<'a> {
+    hours: &'a OrdinalSet,
+    minutes: &'a OrdinalSet,
+    seconds: &'a OrdinalSet,
+}
 
-    pub fn day_of_month_upper_bound(&mut self) -> Ordinal {
-        if self.first_day_of_month {
-            self.first_day_of_month = false;
-            return self.initial_datetime.day() as u32;
+impl<'a> TimesOfDay<'a> {
+    pub fn new(
+        hours: &'a OrdinalSet,
+        minutes: &'a OrdinalSet,
+        seconds: &'a OrdinalSet,
+    ) -> TimesOfDay<'a> {
+        TimesOfDay {
+            hours,
+            minutes,
+            seconds,
         }
-        DaysOfMonth::inclusive_max()
     }
 
-    pub fn reset_day_of_month(&mut self) {
-        self.first_day_of_month = false;
-        self.reset_hour();
+    pub fn len(&self) -> usize {
+        self.hours.len() * self.minutes.len() * self.seconds.len()
     }
 
-    pub fn hour_upper_bound(&mut self) -> Ordinal {
-        if self.first_hour {
-            self.first_hour = false;
-            return self.initial_datetime.hour() as u32;
-        }
-        Hours::inclusive_max()
+    /// Returns the index of `time`, which must be one of the times of day.
+    pub fn index_of(&self, time: Time) -> usize {
+        let rank = |set: &OrdinalSet, ordinal: i8| set.range(..ordinal as Ordinal).count();
+        (rank(self.hours, time.hour()) * self.minutes.len() + rank(self.minutes, time.minute()))
+            * self.seconds.len()
+            + rank(self.seconds, time.second())
     }
 
-    pub fn reset_hour(&mut self) {
-        self.first_hour = false;
-        self.reset_minute();
+    pub fn nth(&self, index: usize) -> Option<Time> {
+        let nth = |set: &OrdinalSet, index: usize| set.iter().nth(index);
+        let second = nth(self.seconds, index % self.seconds.len())?;
+        let index = index / self.seconds.len();
+        let minute = nth(self.minutes, index % self.minutes.len())?;
+        let hour = nth(self.hours, index / self.minutes.len())?;
+        Some(time(hour as i8, minute as i8, second as i8, 0))
     }
+}
 
-    pub fn minute_upper_bound(&mut self) -> Ordinal {
-        if self.first_minute {
-            self.first_minute = false;
-            return self.initial_datetime.minute() as u32;
-        }
-        Minutes::inclusive_max()
+/// Returns whether every civil time on `date` maps to exactly one instant in
+/// `time_zone`, conservatively treating transitions on the surrounding days
+/// as affecting it too.
+pub fn is_regular_day(date: Date, time_zone: &TimeZone) -> bool {
+    let window = (
+        date.yesterday(),
+        date.tomorrow().and_then(|date| date.tomorrow()),
+    );
+    let (Ok(start), Ok(end)) = window else {
+        return false;
+    };
+    let (Ok(start), Ok(end)) = (
+        start.to_zoned(time_zone.clone()),
+        end.to_zoned(time_zone.clone()),
+    ) else {
+        return false;
+    };
+    match time_zone.following(start.timestamp()).next() {
+        Some(transition) => transition.timestamp() > end.timestamp(),
+        None => true,
     }
+}
 
-    pub fn reset_minute(&mut self) {
-        self.first_minute = false;
-        self.reset_second();
+/// Returns the earliest and latest civil date-times which `zoned` could be
+/// written as. These only differ shortly after a transition, when the civil
+/// time in the previous offset hasn't been reached again yet: straight after
+/// a fold, `01:10-05` has also been `02:10-04`.
+///
+/// Searching from both bounds finds fire times which were shifted by the
+/// transition, as long as candidates on the wrong side of `zoned` are
+/// discarded.
+pub fn civil_bounds(zoned: &Zoned) -> (DateTime, DateTime) {
+    let own = zoned.datetime();
+    let time_zone = zoned.time_zone();
+    let timestamp = zoned.timestamp();
+    let transition = timestamp
+        .checked_add(SignedDuration::from_nanos(1))
+        .ok()
+        .and_then(|timestamp| time_zone.preceding(timestamp).next());
+    let Some(transition) = transition else {
+        return (own, own);
+    };
+    let Ok(just_before) = transition
+        .timestamp()
+        .checked_sub(SignedDuration::from_nanos(1))
+    else {
+        return (own, own);
+    };
+    let previous_offset = time_zone.to_offset(just_before);
+    let shift = transition.offset().duration_since(previous_offset).abs();
+    if timestamp.duration_since(transition.timestamp()) >= shift {
+        return (own, own);
     }
+    let other = previous_offset.to_datetime(timestamp);
+    (own.min(other), own.max(other))
+}
 
-    pub fn second_upper_bound(&mut self) -> Ordinal {
-        if self.first_second {
-            self.first_second = false;
-            return self.initial_datetime.second() as u32;
-        }
-        Seconds::inclusive_max()
-    }
+fn civil_values(date_time: DateTime) -> [Ordinal; UNITS] {
+    [
+        date_time.year() as Ordinal,
+        date_time.month() as Ordinal,
+        date_time.day() as Ordinal,
+        date_time.hour() as Ordinal,
+        date_time.minute() as Ordinal,
+        date_time.second() as Ordinal,
+    ]
+}
 
-    pub fn reset_second(&mut self) {
-        self.first_second = false;
-    }
+fn civil_date_time(values: &[Ordinal; UNITS]) -> DateTime {
+    date(
+        values[YEAR] as i16,
+        values[MONTH] as i8,
+        values[DAY_OF_MONTH] as i8,
+    )
+    .at(
+        values[HOUR] as i8,
+        values[MINUTE] as i8,
+        values[SECOND] as i8,
+        0,
+    )
 }
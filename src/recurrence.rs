@@ -0,0 +1,327 @@
+use std::{rc::Rc, sync::Arc};
+
+use jiff::{SignedDuration, Timestamp, Zoned};
+
+use crate::{jitter::JitteredSchedule, schedule::Schedule, union::ScheduleUnion};
+
+/// Anything firing at a sequence of instants, such as a [`Schedule`], for
+/// code which works with any of them, including recurrences defined outside
+/// of this crate, e.g. following a market's trading calendar.
+///
+/// Only [`Recurrence::next_after`] is required. The other methods have
+/// default implementations in terms of it, which implementors may replace
+/// with faster ones.
+///
+/// Combinators such as [`ScheduleUnion`](crate::ScheduleUnion),
+/// [`Occupancy`](crate::Occupancy) and
+/// [`WindowSchedule`](crate::WindowSchedule) accept any recurrence, or
+/// `Box<dyn Recurrence>` to combine recurrences of different types.
+///
+/// # Example
+///
+/// ```rust
+/// use std::str::FromStr;
+///
+/// use jiff_cron::{
+///     jiff::{civil::date, tz::TimeZone, Zoned},
+///     Recurrence, Schedule,
+/// };
+///
+/// /// Fires whenever the inner schedule does, except on the 1st of the month.
+/// struct SkipFirst(Schedule);
+///
+/// impl Recurrence for SkipFirst {
+///     fn next_after(&self, after: &Zoned) -> Option<Zoned> {
+///         self.0.after(after).find(|fire| fire.day() != 1)
+///     }
+/// }
+///
+/// let recurrence = SkipFirst(Schedule::from_str("0 0 9 * * *").unwrap());
+/// let after = date(2025, 1, 31)
+///     .at(12, 0, 0, 0)
+///     .to_zoned(TimeZone::UTC)
+///     .unwrap();
+/// let next = recurrence.next_after(&after).unwrap();
+/// assert_eq!(date(2025, 2, 2), next.date());
+/// assert!(recurrence.includes(&next));
+/// assert_eq!(2, recurrence.iter_after(&after).take(2).count());
+/// assert_eq!(
+///     date(2025, 1, 31),
+///     recurrence.prev_before(&after).unwrap().date()
+/// );
+/// ```
+pub trait Recurrence {
+    /// Returns the first fire time strictly after `after`, in its time zone.
+    fn next_after(&self, after: &Zoned) -> Option<Zoned>;
+
+    /// Returns the last fire time strictly before `before`, in its time zone.
+    ///
+    /// By default, this searches forwards from ever earlier times, doubling
+    /// how far back it starts from a second until it finds a fire time, which
+    /// takes about as many calls to [`Recurrence::next_after`] as there are
+    /// fire times within twice the gap before `before`.
+    fn prev_before(&self, before: &Zoned) -> Option<Zoned> {
+        let mut lookback = SignedDuration::from_secs(1);
+        loop {
+            let (mut previous, earliest) = match before.checked_sub(lookback) {
+                Ok(from) => (from, false),
+                Err(_) => (Timestamp::MIN.to_zoned(before.time_zone().clone()), true),
+            };
+            let mut last = None;
+            while let Some(next) = self.next_after(&previous) {
+                if next >= *before {
+                    break;
+                }
+                last = Some(next.clone());
+                previous = next;
+            }
+            if last.is_some() || earliest {
+                return last;
+            }
+            lookback = lookback.checked_mul(2)?;
+        }
+    }
+
+    /// Returns whether the recurrence fires at `date_time`.
+    fn includes(&self, date_time: &Zoned) -> bool {
+        date_time
+            .checked_sub(SignedDuration::from_nanos(1))
+            .is_ok_and(|before| self.next_after(&before).as_ref() == Some(date_time))
+    }
+
+    /// Returns the typical shortest time between consecutive fire times, if
+    /// one is known, e.g. to size buffers or to warn about schedules firing
+    /// too often.
+    ///
+    /// This isn't a lower bound: for [`Schedule`], it's the shortest civil
+    /// time, and DST transitions may bring fire times closer together. Polling
+    /// at this interval may therefore miss fire times, unless each poll looks
+    /// for all of those since the previous one.
+    fn min_interval_hint(&self) -> Option<SignedDuration> {
+        None
+    }
+
+    /// Returns an iterator over the fire times after `after`, in its time
+    /// zone. The iterator works with the adapters of
+    /// [`FireTimes`](crate::FireTimes).
+    fn iter_after(&self, after: &Zoned) -> RecurrenceIterator<'_, Self>
+    where
+        Self: Sized,
+    {
+        RecurrenceIterator {
+            recurrence: self,
+            previous: Some(after.clone()),
+        }
+    }
+}
+
+impl Recurrence for Schedule {
+    fn next_after(&self, after: &Zoned) -> Option<Zoned> {
+        self.after(after).next()
+    }
+
+    fn prev_before(&self, before: &Zoned) -> Option<Zoned> {
+        self.after(before).next_back()
+    }
+
+    fn includes(&self, date_time: &Zoned) -> bool {
+        Schedule::includes(self, date_time.clone())
+    }
+
+    fn min_interval_hint(&self) -> Option<SignedDuration> {
+        self.min_interval()
+    }
+}
+
+impl<R> Recurrence for ScheduleUnion<R>
+where
+    R: Recurrence,
+{
+    fn next_after(&self, after: &Zoned) -> Option<Zoned> {
+        self.after(after).next()
+    }
+
+    fn prev_before(&self, before: &Zoned) -> Option<Zoned> {
+        self.schedules()
+            .iter()
+            .filter_map(|schedule| schedule.prev_before(before))
+            .max()
+    }
+
+    fn includes(&self, date_time: &Zoned) -> bool {
+        ScheduleUnion::includes(self, date_time)
+    }
+
+    fn min_interval_hint(&self) -> Option<SignedDuration> {
+        // Fire times of different schedules may be arbitrarily close.
+        match self.schedules() {
+            [schedule] => schedule.min_interval_hint(),
+            _ => None,
+        }
+    }
+}
+
+impl Recurrence for JitteredSchedule {
+    fn next_after(&self, after: &Zoned) -> Option<Zoned> {
+        self.after(after).next()
+    }
+
+    fn includes(&self, date_time: &Zoned) -> bool {
+        JitteredSchedule::includes(self, date_time)
+    }
+
+    fn min_interval_hint(&self) -> Option<SignedDuration> {
+        self.schedule().min_interval()
+    }
+}
+
+#[cfg(feature = "calendars")]
+impl Recurrence for crate::calendar_system::CalendarSchedule {
+    fn next_after(&self, after: &Zoned) -> Option<Zoned> {
+        self.after(after).next()
+    }
+
+    fn includes(&self, date_time: &Zoned) -> bool {
+        crate::calendar_system::CalendarSchedule::includes(self, date_time)
+    }
+}
+
+#[cfg(feature = "solar")]
+impl Recurrence for crate::solar::SolarSchedule {
+    fn next_after(&self, after: &Zoned) -> Option<Zoned> {
+        self.after(after).next()
+    }
+}
+
+impl<R> Recurrence for &R
+where
+    R: Recurrence + ?Sized,
+{
+    fn next_after(&self, after: &Zoned) -> Option<Zoned> {
+        (**self).next_after(after)
+    }
+
+    fn prev_before(&self, before: &Zoned) -> Option<Zoned> {
+        (**self).prev_before(before)
+    }
+
+    fn includes(&self, date_time: &Zoned) -> bool {
+        (**self).includes(date_time)
+    }
+
+    fn min_interval_hint(&self) -> Option<SignedDuration> {
+        (**self).min_interval_hint()
+    }
+}
+
+macro_rules! impl_recurrence_for_pointer {
+    ($($pointer:ident),*) => {
+        $(
+            impl<R> Recurrence for $pointer<R>
+            where
+                R: Recurrence + ?Sized,
+            {
+                fn next_after(&self, after: &Zoned) -> Option<Zoned> {
+                    (**self).next_after(after)
+                }
+
+                fn prev_before(&self, before: &Zoned) -> Option<Zoned> {
+                    (**self).prev_before(before)
+                }
+
+                fn includes(&self, date_time: &Zoned) -> bool {
+                    (**self).includes(date_time)
+                }
+
+                fn min_interval_hint(&self) -> Option<SignedDuration> {
+                    (**self).min_interval_hint()
+                }
+            }
+        )*
+    };
+}
+
+impl_recurrence_for_pointer!(Box, Rc, Arc);
+
+/// An iterator over the fire times of any [`Recurrence`], returned by
+/// [`Recurrence::iter_after`].
+#[derive(Clone, Debug)]
+pub struct RecurrenceIterator<'a, R> {
+    recurrence: &'a R,
+    previous: Option<Zoned>,
+}
+
+impl<R> Iterator for RecurrenceIterator<'_, R>
+where
+    R: Recurrence,
+{
+    type Item = Zoned;
+
+    fn next(&mut self) -> Option<Zoned> {
+        let next = self.recurrence.next_after(self.previous.as_ref()?);
+        self.previous.clone_from(&next);
+        next
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use std::str::FromStr;
+
+    use jiff::{
+        civil::{date, time},
+        ToSpan,
+    };
+
+    use super::*;
+    use crate::FireTimes;
+
+    #[test]
+    fn test_recurrence_iterator_matches_schedule() {
+        let schedule = Schedule::from_str("0 */20 9-10 * * Mon-Fri").unwrap();
+        let after = date(2025, 1, 3).at(10, 0, 0, 0).in_tz("UTC").unwrap();
+        let expected: Vec<_> = schedule.after(&after).take(6).collect();
+        let fires: Vec<_> = schedule.iter_after(&after).take(6).collect();
+        assert_eq!(expected, fires);
+
+        let boxed: Vec<Box<dyn Recurrence>> = vec![
+            Box::new(schedule.clone()),
+            Box::new(ScheduleUnion::from_exprs(&["@daily", "@hourly"]).unwrap()),
+        ];
+        let next: Vec<_> = boxed
+            .iter()
+            .map(|recurrence| recurrence.next_after(&after).unwrap().time())
+            .collect();
+        assert_eq!(vec![time(10, 20, 0, 0), time(11, 0, 0, 0)], next);
+        assert!(boxed[0].includes(&expected[0]));
+        assert_eq!(
+            Some(SignedDuration::from_mins(20)),
+            boxed[0].min_interval_hint()
+        );
+        assert_eq!(None, boxed[1].min_interval_hint());
+
+        // Adapters apply to the fire times of any recurrence.
+        let offset: Vec<_> = schedule
+            .iter_after(&after)
+            .offset_by(5.minutes())
+            .take(1)
+            .collect();
+        assert_eq!(25, offset[0].minute());
+    }
+
+    #[test]
+    fn test_default_includes() {
+        struct Every(Schedule);
+
+        impl Recurrence for Every {
+            fn next_after(&self, after: &Zoned) -> Option<Zoned> {
+                self.0.next_after(after)
+            }
+        }
+
+        let every = Every(Schedule::from_str("0 0 * * * *").unwrap());
+        let on_the_hour = date(2025, 1, 1).at(3, 0, 0, 0).in_tz("UTC").unwrap();
+        assert!(every.includes(&on_the_hour));
+        assert!(!every.includes(&date(2025, 1, 1).at(3, 0, 1, 0).in_tz("UTC").unwrap()));
+    }
+}
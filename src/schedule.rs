@@ -1,259 +1,572 @@
 use std::{
+    cmp::Ordering,
+    collections::HashMap,
     fmt::{Display, Formatter, Result as FmtResult},
-    ops::Bound::{Included, Unbounded},
+    ops::RangeBounds,
+    sync::Arc,
 };
 
-use jiff::{civil::date, tz::TimeZone, Zoned};
+use jiff::{
+    civil::{date, Date, DateTime, Time, Weekday},
+    tz::TimeZone,
+    SignedDuration, Span, Timestamp, Zoned,
+};
 #[cfg(feature = "serde")]
 use serde::{
     de::{self, Visitor},
     Deserialize, Serialize, Serializer,
 };
 
-use crate::{ordinal::*, queries::*, time_unit::*};
+use crate::{
+    budget::{Exhausted, SearchBudget},
+    cache::NextFireCache,
+    calendar::FireCalendar,
+    capabilities::Capabilities,
+    cursor::IteratorCursor,
+    dst::{DstOccurrence, DstResolution},
+    error::{Error, ErrorKind},
+    fnv::fnv1a,
+    jitter::JitteredSchedule,
+    ordinal::*,
+    per_period::{EachPeriod, LimitPerDay, Seek, WithEndMarker},
+    phase::Phase,
+    queries::*,
+    simulation::Simulation,
+    time_unit::*,
+    transpose,
+};
 
 impl From<Schedule> for String {
     fn from(schedule: Schedule) -> String {
-        schedule.source
+        // Only copies the source if it's shared with a clone of the schedule.
+        Arc::try_unwrap(schedule.source).unwrap_or_else(|source| (*source).clone())
     }
 }
 
 #[derive(Clone, Debug, Eq)]
 pub struct Schedule {
-    source: String,
-    fields: ScheduleFields,
+    // Shared between clones, which schedulers tend to make one of per task.
+    source: Arc<String>,
+    fields: Arc<ScheduleFields>,
+    next_fire_cache: Option<Arc<NextFireCache>>,
 }
 
 impl Schedule {
     pub(crate) fn new(source: String, fields: ScheduleFields) -> Schedule {
-        Schedule { source, fields }
+        Schedule::from_shared(Arc::new(source), Arc::new(fields))
+    }
+
+    pub(crate) fn from_shared(source: Arc<String>, fields: Arc<ScheduleFields>) -> Schedule {
+        Schedule {
+            source,
+            fields,
+            next_fire_cache: None,
+        }
+    }
+
+    /// Creates a [`Schedule`] from its individual fields. The source
+    /// expression is generated from the fields' compact representations.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use std::str::FromStr;
+    ///
+    /// use jiff_cron::{
+    ///     DaysOfMonth, DaysOfWeek, Hours, Minutes, Months, RootSpecifier, Schedule, Seconds,
+    ///     Specifier, Years,
+    /// };
+    ///
+    /// let schedule = Schedule::from_fields(
+    ///     Seconds::from_specifiers(&[RootSpecifier::from(0)]).unwrap(),
+    ///     Minutes::from_specifiers(&[RootSpecifier::period(Specifier::All, 15)]).unwrap(),
+    ///     Hours::from_specifiers(&[RootSpecifier::from(Specifier::range(9, 17))]).unwrap(),
+    ///     DaysOfMonth::from_str("*").unwrap(),
+    ///     Months::from_str("*").unwrap(),
+    ///     DaysOfWeek::from_specifiers(&[Specifier::named_range("Mon", "Fri").into()]).unwrap(),
+    ///     Years::from_str("*").unwrap(),
+    /// );
+    /// assert_eq!("0 */15 9-17 * * 2-6 *", schedule.source());
+    /// assert_eq!(
+    ///     Schedule::from_str("0 */15 9-17 * * Mon-Fri").unwrap(),
+    ///     schedule
+    /// );
+    /// ```
+    pub fn from_fields(
+        seconds: Seconds,
+        minutes: Minutes,
+        hours: Hours,
+        days_of_month: DaysOfMonth,
+        months: Months,
+        days_of_week: DaysOfWeek,
+        years: Years,
+    ) -> Schedule {
+        let fields = ScheduleFields::new(
+            seconds,
+            minutes,
+            hours,
+            days_of_month,
+            months,
+            days_of_week,
+            years,
+        );
+        Schedule::new(fields.to_string(), fields)
+    }
+
+    /// Creates a [`Schedule`] from a systemd calendar event, as given to
+    /// `OnCalendar=` in timer units, such as `Mon..Fri *-*-1..7 09:00:00` or
+    /// `weekly`. The source expression is the equivalent cron expression.
+    ///
+    /// Days counted from the end of the month, such as `*-02~03`, weekday
+    /// ranges running past Sunday, such as `Fri..Mon`, and the shorthands from
+    /// `minutely` to `annually` are supported. Some syntax isn't:
+    ///
+    /// - time zones, including `UTC`, as the time zone of a schedule is given
+    ///   when iterating it;
+    /// - fractional seconds, such as `*:*:0.5`;
+    /// - ranges or repetitions counted from the end of the month, such as
+    ///   `*-*~1..7`, as `~` takes a single day.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use std::str::FromStr;
+    ///
+    /// use jiff_cron::Schedule;
+    ///
+    /// let schedule = Schedule::from_on_calendar("Mon..Fri *-*-1..7 09:00:00").unwrap();
+    /// assert_eq!("00 00 09 1-7 * Mon-Fri", schedule.source());
+    /// assert_eq!(Schedule::from_str("0 0 9 1-7 * Mon-Fri").unwrap(), schedule);
+    /// assert!(Schedule::from_on_calendar("*-*-* 12:00 Europe/Berlin").is_err());
+    ///
+    /// // systemd weeks start on Monday.
+    /// let schedule = Schedule::from_on_calendar("Mon..Sun 12:00").unwrap();
+    /// assert_eq!("0 00 12 * * Mon-Sat,Sun", schedule.source());
+    /// ```
+    pub fn from_on_calendar(expression: &str) -> Result<Schedule, Error> {
+        crate::systemd::parse(expression)
     }
 
     fn next_after(&self, after: &Zoned) -> Option<Zoned> {
-        let mut query = NextAfterQuery::from(after);
-        for year in self
-            .fields
-            .years
-            .ordinals()
-            .range((Included(query.year_lower_bound()), Unbounded))
-            .cloned()
-        {
-            // It's a future year, the current year's range is irrelevant.
-            if year > after.year() as u32 {
-                query.reset_month();
-                query.reset_day_of_month();
-            }
-            let month_start = query.month_lower_bound();
-            if !self.fields.months.ordinals().contains(&month_start) {
-                query.reset_month();
-            }
-            let month_range = (Included(month_start), Included(Months::inclusive_max()));
-            for month in self.fields.months.ordinals().range(month_range).cloned() {
-                let day_of_month_start = query.day_of_month_lower_bound();
-                if !self
-                    .fields
-                    .days_of_month
-                    .ordinals()
-                    .contains(&day_of_month_start)
-                {
-                    query.reset_day_of_month();
-                }
-                let day_of_month_end = days_in_month(month, year);
-                let day_of_month_range = (
-                    Included(day_of_month_start.min(day_of_month_end)),
-                    Included(day_of_month_end),
-                );
+        let Some(cache) = &self.next_fire_cache else {
+            return self.find_next_after(after);
+        };
+        if let Some(next) = cache.get(after) {
+            return Some(next);
+        }
+        let next = self.find_next_after(after)?;
+        cache.insert(after, &next);
+        Some(next)
+    }
 
-                'day_loop: for day_of_month in self
-                    .fields
-                    .days_of_month
-                    .ordinals()
-                    .range(day_of_month_range)
-                    .cloned()
-                {
-                    let hour_start = query.hour_lower_bound();
-                    if !self.fields.hours.ordinals().contains(&hour_start) {
-                        query.reset_hour();
-                    }
-                    let hour_range = (Included(hour_start), Included(Hours::inclusive_max()));
+    fn find_next_after(&self, after: &Zoned) -> Option<Zoned> {
+        let (start, _) = civil_bounds(after);
+        self.find_next_from(start, after)
+    }
 
-                    for hour in self.fields.hours.ordinals().range(hour_range).cloned() {
-                        let minute_start = query.minute_lower_bound();
-                        if !self.fields.minutes.ordinals().contains(&minute_start) {
-                            query.reset_minute();
-                        }
-                        let minute_range =
-                            (Included(minute_start), Included(Minutes::inclusive_max()));
-
-                        for minute in self.fields.minutes.ordinals().range(minute_range).cloned() {
-                            let second_start = query.second_lower_bound();
-                            if !self.fields.seconds.ordinals().contains(&second_start) {
-                                query.reset_second();
-                            }
-                            let second_range =
-                                (Included(second_start), Included(Seconds::inclusive_max()));
-
-                            for second in
-                                self.fields.seconds.ordinals().range(second_range).cloned()
-                            {
-                                let time_zone = after.time_zone().clone();
-                                let candidate = date(year as i16, month as i8, day_of_month as i8)
-                                    .at(hour as i8, minute as i8, second as i8, 0)
-                                    .to_zoned(time_zone);
-                                let candidate = if let Ok(candidate) = candidate {
-                                    candidate
-                                } else {
-                                    continue;
-                                };
-                                if !self
-                                    .fields
-                                    .days_of_week
-                                    .ordinals()
-                                    .contains(&(candidate.weekday().to_sunday_one_offset() as u32))
-                                {
-                                    continue 'day_loop;
-                                }
-                                return Some(candidate);
-                            }
-                            query.reset_minute();
-                        } // End of minutes range
-                        query.reset_hour();
-                    } // End of hours range
-                    query.reset_day_of_month();
-                } // End of Day of Month range
-                query.reset_month();
-            } // End of Month range
-        }
-
-        // We ran out of dates to try.
-        None
+    /// Returns the first fire time after `after`, searching civil date-times
+    /// from `start`.
+    fn find_next_from(&self, start: DateTime, after: &Zoned) -> Option<Zoned> {
+        self.search_next_from(start, after, SearchBudget::UNLIMITED)
+            .ok()
+            .flatten()
     }
 
     fn prev_from(&self, before: &Zoned) -> Option<Zoned> {
-        let mut query = PrevFromQuery::from(before);
-        for year in self
-            .fields
-            .years
-            .ordinals()
-            .range((Unbounded, Included(query.year_upper_bound())))
-            .rev()
-            .cloned()
-        {
-            let month_start = query.month_upper_bound();
-
-            if !self.fields.months.ordinals().contains(&month_start) {
-                query.reset_month();
+        self.search_prev_from(before, SearchBudget::UNLIMITED)
+            .ok()
+            .flatten()
+    }
+
+    /// Searches for the first fire time after `after` from `start`, giving up
+    /// once `budget` is spent.
+    ///
+    /// Each candidate is found by a [`Seeker`] in a bounded number of steps,
+    /// and only candidates which a DST transition maps to `after` or earlier,
+    /// or to no instant at all, are rejected.
+    fn search_next_from(
+        &self,
+        start: DateTime,
+        after: &Zoned,
+        budget: SearchBudget,
+    ) -> Result<Option<Zoned>, Exhausted> {
+        search_next_with(&self.fields.seeker(), start, after, budget)
+    }
+
+    fn search_prev_from(
+        &self,
+        before: &Zoned,
+        budget: SearchBudget,
+    ) -> Result<Option<Zoned>, Exhausted> {
+        let mut spending = budget.start();
+        let time_zone = before.time_zone();
+        let seeker = self.fields.seeker();
+        let (_, mut start) = civil_bounds(before);
+        loop {
+            let Some(candidate) = seeker.prev_from(start) else {
+                return Ok(None);
+            };
+            spending.spend()?;
+            match candidate.to_zoned(time_zone.clone()) {
+                Ok(candidate) if candidate < *before => return Ok(Some(candidate)),
+                _ => {}
+            }
+            match candidate.checked_sub(ONE_SECOND) {
+                Ok(prev) => start = prev,
+                Err(_) => return Ok(None),
             }
-            let month_range = (Included(Months::inclusive_min()), Included(month_start));
-
-            for month in self
-                .fields
-                .months
-                .ordinals()
-                .range(month_range)
-                .rev()
-                .cloned()
-            {
-                let day_of_month_end = query.day_of_month_upper_bound();
-                if !self
-                    .fields
-                    .days_of_month
-                    .ordinals()
-                    .contains(&day_of_month_end)
-                {
-                    query.reset_day_of_month();
+        }
+    }
+
+    /// Returns the next fire time after `after`, like
+    /// `schedule.after(after).next()`, but gives up with
+    /// [`ErrorKind::SearchExhausted`] once `budget` is spent, either on
+    /// date-times matching every field or on time.
+    ///
+    /// Candidates are only passed over if they fall within a period a DST
+    /// transition skipped or repeated but denote `after` or an earlier
+    /// instant, or if they can't be represented in `after`'s time zone. Each
+    /// candidate is found with a bounded amount of work, however sparse the
+    /// schedule, which makes this suitable where a search must never run for
+    /// long.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use std::str::FromStr;
+    ///
+    /// use jiff_cron::{
+    ///     error::ErrorKind,
+    ///     jiff::{civil::date, tz::TimeZone},
+    ///     Schedule, SearchBudget,
+    /// };
+    ///
+    /// let schedule = Schedule::from_str("0 0 0 29 2 *").unwrap();
+    /// let after = date(2025, 1, 1)
+    ///     .at(0, 0, 0, 0)
+    ///     .to_zoned(TimeZone::UTC)
+    ///     .unwrap();
+    /// let next = schedule
+    ///     .try_next_after(&after, SearchBudget::candidates(1))
+    ///     .unwrap();
+    /// assert_eq!(Some(date(2028, 2, 29)), next.map(|next| next.date()));
+    /// let budget = SearchBudget::candidates(0);
+    /// let error = schedule.try_next_after(&after, budget).unwrap_err();
+    /// assert_eq!(&ErrorKind::SearchExhausted(budget), error.kind());
+    /// ```
+    pub fn try_next_after(
+        &self,
+        after: &Zoned,
+        budget: SearchBudget,
+    ) -> Result<Option<Zoned>, Error> {
+        let (start, _) = civil_bounds(after);
+        self.search_next_from(start, after, budget)
+            .map_err(|_| ErrorKind::SearchExhausted(budget).into())
+    }
+
+    /// Returns the last fire time before `before`, like
+    /// `schedule.after(before).next_back()`, but gives up with
+    /// [`ErrorKind::SearchExhausted`] once `budget` is spent. See
+    /// [`Schedule::try_next_after`].
+    pub fn try_prev_before(
+        &self,
+        before: &Zoned,
+        budget: SearchBudget,
+    ) -> Result<Option<Zoned>, Error> {
+        self.search_prev_from(before, budget)
+            .map_err(|_| ErrorKind::SearchExhausted(budget).into())
+    }
+
+    /// Returns the latest fire time at or before `now` together with the
+    /// earliest one after it, e.g. for comparing the most recent expected run
+    /// of a job with its actual runs while showing when it runs next.
+    ///
+    /// This is a convenience for the two searches, one backwards and one
+    /// forwards from `now`, each taking a bounded number of steps. Neither
+    /// iterates over the fire times in between, but neither reuses the work
+    /// of the other.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use std::str::FromStr;
+    ///
+    /// use jiff_cron::{
+    ///     jiff::{civil::date, tz::TimeZone},
+    ///     Schedule,
+    /// };
+    ///
+    /// let schedule = Schedule::from_str("0 0 */6 * * *").unwrap();
+    /// let now = date(2025, 1, 1)
+    ///     .at(13, 0, 0, 0)
+    ///     .to_zoned(TimeZone::UTC)
+    ///     .unwrap();
+    /// let (prev, next) = schedule.surrounding(&now);
+    /// assert_eq!(Some(12), prev.map(|prev| prev.hour()));
+    /// assert_eq!(Some(18), next.map(|next| next.hour()));
+    ///
+    /// let now = date(2025, 1, 1)
+    ///     .at(12, 0, 0, 0)
+    ///     .to_zoned(TimeZone::UTC)
+    ///     .unwrap();
+    /// assert_eq!(Some(now.clone()), schedule.surrounding(&now).0);
+    /// ```
+    pub fn surrounding(&self, now: &Zoned) -> (Option<Zoned>, Option<Zoned>) {
+        let prev = match now.checked_add(SignedDuration::from_nanos(1)) {
+            Ok(just_after) => self.prev_from(&just_after),
+            // Nothing follows the last representable instant, so `now` can't
+            // be a fire time.
+            Err(_) => self.prev_from(now),
+        };
+        (prev, self.next_after(now))
+    }
+
+    /// Returns how far `now` is between the fire times surrounding it, as
+    /// found by [`Schedule::surrounding`], or `None` if the schedule doesn't
+    /// fire both before and after it. See [`Phase`].
+    pub fn phase(&self, now: &Zoned) -> Option<Phase> {
+        match self.surrounding(now) {
+            (Some(previous), Some(next)) => Some(Phase::new(previous, next, now.clone())),
+            _ => None,
+        }
+    }
+
+    /// Returns the fire time closest to `time`, if it's no further than
+    /// `tolerance` from it in either direction, e.g. to match a job which ran
+    /// slightly late to the fire time it ran for. Of two fire times equally
+    /// close, the earlier one is returned.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use std::str::FromStr;
+    ///
+    /// use jiff_cron::{
+    ///     jiff::{civil::date, tz::TimeZone, ToSpan},
+    ///     Schedule,
+    /// };
+    ///
+    /// let schedule = Schedule::from_str("0 0 * * * *").unwrap();
+    /// let ran = date(2025, 1, 1)
+    ///     .at(9, 0, 42, 0)
+    ///     .to_zoned(TimeZone::UTC)
+    ///     .unwrap();
+    /// let fire = schedule.matches_within(&ran, 1.minute()).unwrap();
+    /// assert_eq!(date(2025, 1, 1).at(9, 0, 0, 0), fire.datetime());
+    /// assert_eq!(None, schedule.matches_within(&ran, 30.seconds()));
+    /// ```
+    pub fn matches_within(&self, time: &Zoned, tolerance: Span) -> Option<Zoned> {
+        let tolerance = tolerance.abs();
+        let (prev, next) = self.surrounding(time);
+        let prev = prev.filter(|prev| {
+            prev.checked_add(tolerance)
+                .map_or(true, |latest| *time <= latest)
+        });
+        let next = next.filter(|next| {
+            time.checked_add(tolerance)
+                .is_ok_and(|latest| *next <= latest)
+        });
+        match (prev, next) {
+            (Some(prev), Some(next)) => {
+                if time.duration_since(&prev) <= next.duration_since(time) {
+                    Some(prev)
+                } else {
+                    Some(next)
                 }
+            }
+            (prev, next) => prev.or(next),
+        }
+    }
+
+    /// Returns when a job pinging at each fire time is overdue, having last
+    /// pinged at `last_ping`: `grace` after the first fire time following it.
+    /// Returns `None` if the schedule doesn't fire after `last_ping`, or the
+    /// deadline can't be represented.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use std::str::FromStr;
+    ///
+    /// use jiff_cron::{
+    ///     jiff::{civil::date, tz::TimeZone, ToSpan},
+    ///     Schedule,
+    /// };
+    ///
+    /// let schedule = Schedule::from_str("0 0 * * * *").unwrap();
+    /// let last_ping = date(2025, 1, 1)
+    ///     .at(9, 0, 3, 0)
+    ///     .to_zoned(TimeZone::UTC)
+    ///     .unwrap();
+    /// let deadline = schedule.deadline_after(&last_ping, 5.minutes()).unwrap();
+    /// assert_eq!(date(2025, 1, 1).at(10, 5, 0, 0), deadline.datetime());
+    /// ```
+    pub fn deadline_after(&self, last_ping: &Zoned, grace: Span) -> Option<Zoned> {
+        self.next_after(last_ping)?.checked_add(grace).ok()
+    }
 
-                let day_of_month_end = days_in_month(month, year).min(day_of_month_end);
+    /// Returns whether a job pinging at each fire time, which last pinged at
+    /// `last_ping`, is overdue at `now`, i.e. whether `now` is past the
+    /// [deadline](Schedule::deadline_after).
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use std::str::FromStr;
+    ///
+    /// use jiff_cron::{
+    ///     jiff::{civil::date, tz::TimeZone, ToSpan},
+    ///     Schedule,
+    /// };
+    ///
+    /// let schedule = Schedule::from_str("0 0 * * * *").unwrap();
+    /// let last_ping = date(2025, 1, 1)
+    ///     .at(9, 0, 3, 0)
+    ///     .to_zoned(TimeZone::UTC)
+    ///     .unwrap();
+    /// let at = |minute| {
+    ///     date(2025, 1, 1)
+    ///         .at(10, minute, 0, 0)
+    ///         .to_zoned(TimeZone::UTC)
+    ///         .unwrap()
+    /// };
+    /// assert!(!schedule.is_overdue(&last_ping, &at(5), 5.minutes()));
+    /// assert!(schedule.is_overdue(&last_ping, &at(6), 5.minutes()));
+    /// ```
+    pub fn is_overdue(&self, last_ping: &Zoned, now: &Zoned, grace: Span) -> bool {
+        self.deadline_after(last_ping, grace)
+            .is_some_and(|deadline| *now > deadline)
+    }
 
-                let day_of_month_range = (
-                    Included(DaysOfMonth::inclusive_min()),
-                    Included(day_of_month_end),
-                );
+    /// Returns the `n`th fire time after `after`, counting from zero, without
+    /// visiting each of the fire times before it.
+    ///
+    /// Whole days are skipped by counting the times of day the schedule fires
+    /// at. Days close to a DST transition are stepped through one fire time at
+    /// a time, as the transition may skip or repeat some of them.
+    fn nth_after(&self, after: &Zoned, mut n: usize) -> Option<Zoned> {
+        let time_zone = after.time_zone();
+        let times = TimesOfDay::new(
+            self.fields.hours.ordinals(),
+            self.fields.minutes.ordinals(),
+            self.fields.seconds.ordinals(),
+        );
+        let mut next = self.next_after(after)?;
+        while n > 0 {
+            let day = next.date();
+            let index = times.index_of(next.time());
+            // Fire times shifted by a transition aren't among the times of
+            // day, so step through them as on days close to a transition.
+            if !is_regular_day(day, time_zone) || index >= times.len() {
+                next = self.next_after(&next)?;
+                n -= 1;
+                continue;
+            }
+            let remaining = times.len() - index - 1;
+            if n <= remaining {
+                let time = times.nth(index + n)?;
+                return day.to_datetime(time).to_zoned(time_zone.clone()).ok();
+            }
+            n -= remaining + 1;
+            let start = day.tomorrow().ok()?.to_datetime(Time::midnight());
+            next = self.find_next_from(start, &next)?;
+        }
+        Some(next)
+    }
 
-                'day_loop: for day_of_month in self
-                    .fields
-                    .days_of_month
-                    .ordinals()
-                    .range(day_of_month_range)
-                    .rev()
-                    .cloned()
-                {
-                    let hour_start = query.hour_upper_bound();
-                    if !self.fields.hours.ordinals().contains(&hour_start) {
-                        query.reset_hour();
-                    }
-                    let hour_range = (Included(Hours::inclusive_min()), Included(hour_start));
-
-                    for hour in self
-                        .fields
-                        .hours
-                        .ordinals()
-                        .range(hour_range)
-                        .rev()
-                        .cloned()
-                    {
-                        let minute_start = query.minute_upper_bound();
-                        if !self.fields.minutes.ordinals().contains(&minute_start) {
-                            query.reset_minute();
-                        }
-                        let minute_range =
-                            (Included(Minutes::inclusive_min()), Included(minute_start));
-
-                        for minute in self
-                            .fields
-                            .minutes
-                            .ordinals()
-                            .range(minute_range)
-                            .rev()
-                            .cloned()
-                        {
-                            let second_start = query.second_upper_bound();
-                            if !self.fields.seconds.ordinals().contains(&second_start) {
-                                query.reset_second();
-                            }
-                            let second_range =
-                                (Included(Seconds::inclusive_min()), Included(second_start));
-
-                            for second in self
-                                .fields
-                                .seconds
-                                .ordinals()
-                                .range(second_range)
-                                .rev()
-                                .cloned()
-                            {
-                                let time_zone = before.time_zone().clone();
-                                let candidate = date(year as i16, month as i8, day_of_month as i8)
-                                    .at(hour as i8, minute as i8, second as i8, 0)
-                                    .to_zoned(time_zone);
-                                let candidate = if let Ok(candidate) = candidate {
-                                    candidate
-                                } else {
-                                    continue;
-                                };
-                                if !self
-                                    .fields
-                                    .days_of_week
-                                    .ordinals()
-                                    .contains(&(candidate.weekday().to_sunday_one_offset() as u32))
-                                {
-                                    continue 'day_loop;
-                                }
-                                return Some(candidate);
-                            }
-                            query.reset_minute();
-                        } // End of minutes range
-                        query.reset_hour();
-                    } // End of hours range
-                    query.reset_day_of_month();
-                } // End of Day of Month range
-                query.reset_month();
-            } // End of Month range
-        }
-
-        // We ran out of dates to try.
-        None
+    /// Returns the next fire time after `after` of each of `schedules`, in the
+    /// same order.
+    ///
+    /// `after` is converted to its civil date-time in its time zone once
+    /// overall, and every search starts from that, while schedules sharing
+    /// their fields, such as clones or those returned by
+    /// [`Schedule::parse_many`] for equivalent expressions, are only searched
+    /// once.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use jiff_cron::{
+    ///     jiff::{civil::date, tz::TimeZone},
+    ///     Schedule,
+    /// };
+    ///
+    /// let schedules: Vec<Schedule> = Schedule::parse_many(["@hourly", "@daily", "0 0 0 1 1 * 2020"])
+    ///     .into_iter()
+    ///     .collect::<Result<_, _>>()
+    ///     .unwrap();
+    /// let after = date(2025, 1, 1)
+    ///     .at(12, 30, 0, 0)
+    ///     .to_zoned(TimeZone::UTC)
+    ///     .unwrap();
+    ///
+    /// let next = Schedule::next_fires(&schedules, &after);
+    /// assert_eq!(
+    ///     Some(date(2025, 1, 1).at(13, 0, 0, 0)),
+    ///     next[0].as_ref().map(|next| next.datetime())
+    /// );
+    /// assert_eq!(
+    ///     Some(date(2025, 1, 2).at(0, 0, 0, 0)),
+    ///     next[1].as_ref().map(|next| next.datetime())
+    /// );
+    /// assert_eq!(None, next[2]);
+    /// ```
+    pub fn next_fires(schedules: &[Schedule], after: &Zoned) -> Vec<Option<Zoned>> {
+        let (start, _) = civil_bounds(after);
+        let mut by_fields: HashMap<*const ScheduleFields, Option<Zoned>> = HashMap::new();
+        schedules
+            .iter()
+            .map(|schedule| {
+                by_fields
+                    .entry(Arc::as_ptr(&schedule.fields))
+                    .or_insert_with(|| schedule.find_next_from(start, after))
+                    .clone()
+            })
+            .collect()
+    }
+
+    /// Returns the next `n` fire times after `from`, like
+    /// `schedule.after(from).take(n).collect()`, but in one pass which sets up
+    /// the search once rather than for every fire time, e.g. for showing the
+    /// next runs of many schedules at once.
+    ///
+    /// Fewer than `n` fire times are returned if the schedule runs out of
+    /// them.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use std::str::FromStr;
+    ///
+    /// use jiff_cron::{
+    ///     jiff::{civil::date, tz::TimeZone},
+    ///     Schedule,
+    /// };
+    ///
+    /// let schedule = Schedule::from_str("0 30 9 * * Mon-Fri").unwrap();
+    /// let from = date(2025, 1, 3)
+    ///     .at(12, 0, 0, 0)
+    ///     .to_zoned(TimeZone::UTC)
+    ///     .unwrap();
+    /// let next = schedule.next_n(3, &from);
+    /// assert_eq!(schedule.after(&from).take(3).collect::<Vec<_>>(), next);
+    /// assert_eq!(date(2025, 1, 6).at(9, 30, 0, 0), next[0].datetime());
+    ///
+    /// let schedule = Schedule::from_str("0 0 0 1 1 * 2030").unwrap();
+    /// assert_eq!(1, schedule.next_n(10, &from).len());
+    /// ```
+    pub fn next_n(&self, n: usize, from: &Zoned) -> Vec<Zoned> {
+        let seeker = self.fields.seeker();
+        let mut fires: Vec<Zoned> = Vec::new();
+        while fires.len() < n {
+            let previous = fires.last().unwrap_or(from);
+            let (start, _) = civil_bounds(previous);
+            match search_next_with(&seeker, start, previous, SearchBudget::UNLIMITED) {
+                Ok(Some(next)) => fires.push(next),
+                _ => break,
+            }
+        }
+        fires
     }
 
     /// Provides an iterator which will return each [`jiff::Zoned`] that matches
@@ -269,6 +582,48 @@ impl Schedule {
         self.after_owned(after)
     }
 
+    /// Like [`Schedule::upcoming`], in the system time zone.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use std::str::FromStr;
+    ///
+    /// use jiff_cron::{jiff::tz::TimeZone, Schedule};
+    ///
+    /// let schedule = Schedule::from_str("0 0 9 * * Mon-Fri").unwrap();
+    /// let next = schedule.upcoming_system().next().unwrap();
+    /// assert_eq!(&TimeZone::system(), next.time_zone());
+    /// assert_eq!(9, next.hour());
+    /// ```
+    pub fn upcoming_system(&self) -> ScheduleIterator<'_> {
+        self.after(&Zoned::now())
+    }
+
+    /// Like [`Schedule::upcoming_owned`], in the system time zone.
+    pub fn upcoming_system_owned(&self) -> OwnedScheduleIterator {
+        self.after_owned(Zoned::now())
+    }
+
+    /// Returns the next fire time after the current time, in the system time
+    /// zone.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use std::str::FromStr;
+    ///
+    /// use jiff_cron::{jiff::Zoned, Schedule};
+    ///
+    /// let schedule = Schedule::from_str("0 0 * * * *").unwrap();
+    /// let next = schedule.after_now().unwrap();
+    /// assert!(next > Zoned::now());
+    /// assert_eq!(0, next.minute());
+    /// ```
+    pub fn after_now(&self) -> Option<Zoned> {
+        self.upcoming_system().next()
+    }
+
     /// Like the `upcoming` method, but allows you to specify a start time other
     /// than the present.
     pub fn after(&self, after: &Zoned) -> ScheduleIterator<'_> {
@@ -280,86 +635,1063 @@ impl Schedule {
         OwnedScheduleIterator::new(self.clone(), after)
     }
 
+    /// Returns the next `n` fire times after `from` in `time_zone` as RFC 3339
+    /// strings, for pinning an expected sequence of fire times in a snapshot
+    /// test.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use std::str::FromStr;
+    ///
+    /// use jiff_cron::{
+    ///     jiff::{tz::TimeZone, Timestamp},
+    ///     Schedule,
+    /// };
+    ///
+    /// let schedule = Schedule::from_str("0 30 9 * * Mon-Fri").unwrap();
+    /// let from: Timestamp = "2025-01-03T15:00:00Z".parse().unwrap();
+    /// let time_zone = TimeZone::get("America/New_York").unwrap();
+    /// assert_eq!(
+    ///     vec!["2025-01-06T09:30:00-05:00", "2025-01-07T09:30:00-05:00"],
+    ///     schedule.snapshot_next(2, from, &time_zone)
+    /// );
+    /// ```
+    pub fn snapshot_next(&self, n: usize, from: Timestamp, time_zone: &TimeZone) -> Vec<String> {
+        self.after(&from.to_zoned(time_zone.clone()))
+            .take(n)
+            .map(|zoned| {
+                zoned
+                    .timestamp()
+                    .display_with_offset(zoned.offset())
+                    .to_string()
+            })
+            .collect()
+    }
+
+    /// Like [`Schedule::snapshot_next`], but formats each fire time with a
+    /// [`jiff::fmt::strtime`] format string.
+    ///
+    /// # Panics
+    ///
+    /// Panics if a fire time can't be formatted with `format`, e.g. because it
+    /// contains an unsupported directive.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use std::str::FromStr;
+    ///
+    /// use jiff_cron::{
+    ///     jiff::{tz::TimeZone, Timestamp},
+    ///     Schedule,
+    /// };
+    ///
+    /// let schedule = Schedule::from_str("0 30 9 * * Mon-Fri").unwrap();
+    /// let from: Timestamp = "2025-01-03T12:00:00Z".parse().unwrap();
+    /// assert_eq!(
+    ///     vec!["Mon 09:30", "Tue 09:30"],
+    ///     schedule.snapshot_next_with(2, from, &TimeZone::UTC, "%a %H:%M")
+    /// );
+    /// ```
+    pub fn snapshot_next_with(
+        &self,
+        n: usize,
+        from: Timestamp,
+        time_zone: &TimeZone,
+        format: &str,
+    ) -> Vec<String> {
+        self.after(&from.to_zoned(time_zone.clone()))
+            .take(n)
+            .map(|zoned| zoned.strftime(format).to_string())
+            .collect()
+    }
+
+    /// Resumes iteration from a [`IteratorCursor`] taken from an iterator over
+    /// this schedule. Returns `None` if the cursor was taken from an iterator
+    /// over a different schedule, judged by its fingerprint.
+    pub fn resume(&self, cursor: &IteratorCursor) -> Option<ScheduleIterator<'_>> {
+        if cursor.fingerprint() != self.fingerprint() {
+            return None;
+        }
+        Some(ScheduleIterator {
+            schedule: self,
+            previous_datetime: cursor.position().cloned(),
+        })
+    }
+
+    /// Like [`Schedule::resume`], but returns an iterator owning the
+    /// schedule.
+    pub fn resume_owned(self, cursor: &IteratorCursor) -> Option<OwnedScheduleIterator> {
+        if cursor.fingerprint() != self.fingerprint() {
+            return None;
+        }
+        Some(OwnedScheduleIterator {
+            schedule: self,
+            previous_datetime: cursor.position().cloned(),
+        })
+    }
+
     pub fn includes(&self, date_time: Zoned) -> bool {
-        self.fields.years.includes(date_time.year() as Ordinal)
-            && self.fields.months.includes(date_time.month() as Ordinal)
-            && self
-                .fields
-                .days_of_week
-                .includes(date_time.weekday().to_sunday_one_offset() as u32)
-            && self
-                .fields
-                .days_of_month
-                .includes(date_time.day() as Ordinal)
+        self.fires_on(date_time.date())
             && self.fields.hours.includes(date_time.hour() as Ordinal)
             && self.fields.minutes.includes(date_time.minute() as Ordinal)
             && self.fields.seconds.includes(date_time.second() as Ordinal)
     }
 
+    /// Returns true if the schedule fires at least once on the provided date.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use std::str::FromStr;
+    ///
+    /// use jiff_cron::{jiff::civil::date, Schedule};
+    ///
+    /// let schedule = Schedule::from_str("0 0 9 * * Mon-Fri").unwrap();
+    /// assert!(schedule.fires_on(date(2025, 1, 6)));
+    /// assert!(!schedule.fires_on(date(2025, 1, 5)));
+    /// ```
+    pub fn fires_on(&self, date: Date) -> bool {
+        self.includes_year(date.year())
+            && self.fields.months.includes(date.month() as Ordinal)
+            && self.fields.days_of_month.includes_date(date)
+            && self.fields.days_of_week.includes_date(date)
+    }
+
+    /// Returns whether the schedule may fire in `year`, including the years
+    /// past 2100 of a schedule with unbounded years.
+    pub(crate) fn includes_year(&self, year: i16) -> bool {
+        self.fields.year_ordinals().contains(&(year as Ordinal))
+    }
+
+    /// Returns the first year from `year` on in which the schedule may fire.
+    #[cfg(feature = "calendars")]
+    pub(crate) fn next_year_from(&self, year: i16) -> Option<i16> {
+        let year = Ordinal::try_from(year).unwrap_or(0);
+        let next = self.fields.year_ordinals().range(year..).next()?;
+        i16::try_from(next).ok()
+    }
+
+    /// Returns a [`FireCalendar`] answering date-level queries about the
+    /// schedule from a precomputed bitmap per year.
+    pub fn calendar(&self) -> FireCalendar {
+        FireCalendar::new(self.clone())
+    }
+
+    /// Returns every time the schedule fires on the provided date in the
+    /// provided time zone, in chronological order.
+    ///
+    /// Times of day which don't exist on that date due to a DST transition are
+    /// shifted forward, as they are when iterating.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use std::str::FromStr;
+    ///
+    /// use jiff_cron::{
+    ///     jiff::{civil::date, tz::TimeZone},
+    ///     Schedule,
+    /// };
+    ///
+    /// let schedule = Schedule::from_str("0 0 9,17 * * Mon-Fri").unwrap();
+    /// let times = schedule.times_on(date(2025, 1, 6), &TimeZone::UTC);
+    /// assert_eq!(2, times.len());
+    /// assert_eq!(17, times[1].hour());
+    /// assert!(schedule
+    ///     .times_on(date(2025, 1, 5), &TimeZone::UTC)
+    ///     .is_empty());
+    /// ```
+    pub fn times_on(&self, date: Date, time_zone: &TimeZone) -> Vec<Zoned> {
+        if !self.fires_on(date) {
+            return Vec::new();
+        }
+        let mut times: Vec<Zoned> = self.candidates_on(date, time_zone).collect();
+        times.sort();
+        times.dedup();
+        times
+    }
+
+    /// Returns the times of day the schedule fires at, in chronological order,
+    /// e.g. for showing that it runs at 09:00 and 18:00.
+    ///
+    /// The time fields of a cron expression never depend on its date fields,
+    /// so these are the same on every day it fires, apart from times shifted
+    /// by DST transitions, as with [`Schedule::times_on`]. Returns `None` if
+    /// the schedule never fires on any date, such as `0 0 0 30 Feb *`.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use std::str::FromStr;
+    ///
+    /// use jiff_cron::{jiff::civil::time, Schedule};
+    ///
+    /// let schedule = Schedule::from_str("0 0 9,18 * * Mon-Fri").unwrap();
+    /// assert_eq!(
+    ///     Some(vec![time(9, 0, 0, 0), time(18, 0, 0, 0)]),
+    ///     schedule.daily_times()
+    /// );
+    ///
+    /// let schedule = Schedule::from_str("0 0 0 30 Feb *").unwrap();
+    /// assert_eq!(None, schedule.daily_times());
+    /// ```
+    pub fn daily_times(&self) -> Option<Vec<Time>> {
+        let start = date(1970, 1, 1).to_datetime(Time::midnight());
+        self.fields.seeker().next_from(start)?;
+        let times = TimesOfDay::new(
+            self.fields.hours.ordinals(),
+            self.fields.minutes.ordinals(),
+            self.fields.seconds.ordinals(),
+        );
+        Some(
+            (0..times.len())
+                .filter_map(|index| times.nth(index))
+                .collect(),
+        )
+    }
+
+    /// Returns an equivalent schedule expressed in `to` rather than `from`,
+    /// firing at the same instants, e.g. for moving a job from a server on
+    /// UTC to one on local time.
+    ///
+    /// This is only possible if neither time zone's offset changes during the
+    /// schedule's years, and the shifted times of day can be written as
+    /// fields. Days of the week are shifted along with times of day shifted
+    /// to another day, but days of the month, months and years can't be.
+    ///
+    /// # Errors
+    ///
+    /// Returns an [`ErrorKind::NotTransposable`] error with the
+    /// [`TransposeObstacle`](crate::TransposeObstacle) otherwise.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use std::str::FromStr;
+    ///
+    /// use jiff_cron::{jiff::tz::TimeZone, Schedule};
+    ///
+    /// let schedule = Schedule::from_str("0 0 16 * * Mon-Fri").unwrap();
+    /// let tokyo = TimeZone::get("Asia/Tokyo").unwrap();
+    /// let transposed = schedule.transpose(&TimeZone::UTC, &tokyo).unwrap();
+    /// // 01:00 on Tuesdays to Saturdays.
+    /// assert_eq!("0 0 1 * * 3-7 *", transposed.to_string());
+    ///
+    /// // Berlin observes DST.
+    /// let berlin = TimeZone::get("Europe/Berlin").unwrap();
+    /// assert!(schedule.transpose(&TimeZone::UTC, &berlin).is_err());
+    /// ```
+    pub fn transpose(&self, from: &TimeZone, to: &TimeZone) -> Result<Schedule, Error> {
+        transpose::transpose(self, from, to)
+    }
+
+    /// Summarises the fire times within `horizon` after `from`: how many
+    /// there are in total and in each month, the shortest and longest gaps
+    /// between them, and which are affected by DST transitions. This visits
+    /// every fire time, so it takes as long as iterating over them.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use std::str::FromStr;
+    ///
+    /// use jiff_cron::{
+    ///     jiff::{civil::date, tz::TimeZone, SignedDuration, ToSpan},
+    ///     Schedule,
+    /// };
+    ///
+    /// let schedule = Schedule::from_str("0 0 9 1,15 * *").unwrap();
+    /// let from = date(2025, 1, 1).to_zoned(TimeZone::UTC).unwrap();
+    /// let simulation = schedule.simulate(&from, 3.months());
+    /// assert_eq!(6, simulation.total());
+    /// assert_eq!(Some(&2), simulation.per_month().get(&(2025, 2)));
+    /// assert_eq!(
+    ///     Some(SignedDuration::from_hours(14 * 24)),
+    ///     simulation.shortest_gap()
+    /// );
+    /// assert_eq!(
+    ///     Some(SignedDuration::from_hours(17 * 24)),
+    ///     simulation.longest_gap()
+    /// );
+    /// ```
+    pub fn simulate(&self, from: &Zoned, horizon: Span) -> Simulation {
+        Simulation::run(self, from, horizon)
+    }
+
+    /// Returns every fire time in `year` which is affected by a DST transition
+    /// in `time_zone`, in order, along with how it was resolved, for reviewing
+    /// a schedule before deploying it.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use std::str::FromStr;
+    ///
+    /// use jiff_cron::{
+    ///     jiff::{civil::date, tz::TimeZone},
+    ///     DstResolution, Schedule,
+    /// };
+    ///
+    /// let schedule = Schedule::from_str("0 30 1,2 * * *").unwrap();
+    /// let time_zone = TimeZone::get("America/New_York").unwrap();
+    /// let audit = schedule.dst_audit(&time_zone, 2025);
+    /// assert_eq!(2, audit.len());
+    ///
+    /// // 02:30 didn't happen on March 9th, so the schedule fired at 03:30.
+    /// assert_eq!(date(2025, 3, 9).at(2, 30, 0, 0), audit[0].scheduled());
+    /// assert_eq!(date(2025, 3, 9).at(3, 30, 0, 0), audit[0].fire().datetime());
+    /// assert_eq!(DstResolution::Shifted, audit[0].resolution());
+    ///
+    /// // 01:30 happened twice on November 2nd.
+    /// assert_eq!(date(2025, 11, 2).at(1, 30, 0, 0), audit[1].scheduled());
+    /// assert_eq!(-4 * 3600, audit[1].fire().offset().seconds());
+    /// assert_eq!(DstResolution::Earlier, audit[1].resolution());
+    /// ```
+    pub fn dst_audit(&self, time_zone: &TimeZone, year: i16) -> Vec<DstOccurrence> {
+        let bounds = (
+            date(year, 1, 1).to_zoned(time_zone.clone()),
+            date(year, 12, 31)
+                .tomorrow()
+                .and_then(|end| end.to_zoned(time_zone.clone())),
+        );
+        let (Ok(start), Ok(end)) = bounds else {
+            return Vec::new();
+        };
+        let seeker = self.fields.seeker();
+        let includes = |date_time: DateTime| seeker.next_from(date_time) == Some(date_time);
+        let mut occurrences: Vec<DstOccurrence> = Vec::new();
+        let transitions = time_zone
+            .following(start.timestamp())
+            .take_while(|transition| transition.timestamp() < end.timestamp());
+        for transition in transitions {
+            let Ok(just_before) = transition
+                .timestamp()
+                .checked_sub(SignedDuration::from_nanos(1))
+            else {
+                continue;
+            };
+            let shift = transition
+                .offset()
+                .duration_since(time_zone.to_offset(just_before));
+            // The local time the clocks showed when they were changed.
+            let changed_at = time_zone
+                .to_offset(just_before)
+                .to_datetime(transition.timestamp());
+            // Local times from the start of the window up to its end were
+            // either skipped or happened twice.
+            let window = if shift.is_positive() {
+                (changed_at, changed_at.checked_add(shift))
+            } else if shift.is_negative() {
+                (
+                    changed_at.checked_add(shift).unwrap_or(changed_at),
+                    Ok(changed_at),
+                )
+            } else {
+                continue;
+            };
+            let (mut from, Ok(until)) = window else {
+                continue;
+            };
+            while let Some(scheduled) = seeker.next_from(from).filter(|&next| next < until) {
+                let Ok(fire) = scheduled.to_zoned(time_zone.clone()) else {
+                    break;
+                };
+                let resolution = if shift.is_negative() {
+                    DstResolution::Earlier
+                } else if includes(fire.datetime()) {
+                    DstResolution::Merged
+                } else {
+                    DstResolution::Shifted
+                };
+                occurrences.push(DstOccurrence {
+                    scheduled,
+                    fire,
+                    resolution,
+                });
+                let Ok(next) = scheduled.checked_add(ONE_SECOND) else {
+                    break;
+                };
+                from = next;
+            }
+        }
+        occurrences
+    }
+
+    /// Returns each date of the provided month on which the schedule fires at
+    /// least once in the provided time zone. Returns no dates if the year or
+    /// month is invalid.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use std::str::FromStr;
+    ///
+    /// use jiff_cron::{
+    ///     jiff::{civil::date, tz::TimeZone},
+    ///     Schedule,
+    /// };
+    ///
+    /// let schedule = Schedule::from_str("0 0 9 1-7 * Mon").unwrap();
+    /// assert_eq!(
+    ///     vec![date(2025, 9, 1)],
+    ///     schedule.dates_in_month(2025, 9, &TimeZone::UTC)
+    /// );
+    /// ```
+    pub fn dates_in_month(&self, year: i16, month: i8, time_zone: &TimeZone) -> Vec<Date> {
+        self.fields
+            .days_of_month
+            .resolved_for(year, month)
+            .into_iter()
+            .map(|day| date(year, month, day))
+            .filter(|&date| self.fires_on(date))
+            .filter(|&date| self.candidates_on(date, time_zone).next().is_some())
+            .collect()
+    }
+
+    /// Returns the shortest civil time between two consecutive fire times, or
+    /// `None` if the schedule fires at most once.
+    ///
+    /// DST transitions, which may bring fire times closer together, are
+    /// ignored. Fire dates are considered over the first 400 years the
+    /// schedule fires in, after which the calendar repeats itself.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use std::str::FromStr;
+    ///
+    /// use jiff_cron::{jiff::SignedDuration, Schedule};
+    ///
+    /// let schedule = Schedule::from_str("0 */20 9-17 * * *").unwrap();
+    /// assert_eq!(Some(SignedDuration::from_mins(20)), schedule.min_interval());
+    ///
+    /// // From 23:00 to 01:00 the following day.
+    /// let schedule = Schedule::from_str("0 0 1,23 * * *").unwrap();
+    /// assert_eq!(Some(SignedDuration::from_hours(2)), schedule.min_interval());
+    /// ```
+    pub fn min_interval(&self) -> Option<SignedDuration> {
+        const MINUTE: i64 = 60;
+        const HOUR: i64 = 60 * MINUTE;
+        const DAY: i64 = 24 * HOUR;
+
+        let fields = &self.fields;
+        let seconds = fields.seconds.ordinals();
+        let minutes = fields.minutes.ordinals();
+        let hours = fields.hours.ordinals();
+        let second_of_day = |hour: Ordinal, minute: Ordinal, second: Ordinal| {
+            i64::from(hour) * HOUR + i64::from(minute) * MINUTE + i64::from(second)
+        };
+        let (first_second, last_second) = (seconds.iter().next()?, seconds.iter().next_back()?);
+        let (first_minute, last_minute) = (minutes.iter().next()?, minutes.iter().next_back()?);
+        let (first_hour, last_hour) = (hours.iter().next()?, hours.iter().next_back()?);
+        let first = second_of_day(first_hour, first_minute, first_second);
+        let last = second_of_day(last_hour, last_minute, last_second);
+
+        // Consecutive times of day differ in their most significant field,
+        // and run from the last of each less significant one to the first.
+        let within_day = [
+            min_gap(seconds),
+            min_gap(minutes).map(|gap| gap * MINUTE - i64::from(last_second - first_second)),
+            min_gap(hours).map(|gap| {
+                gap * HOUR
+                    - (second_of_day(0, last_minute, last_second)
+                        - second_of_day(0, first_minute, first_second))
+            }),
+        ]
+        .into_iter()
+        .flatten()
+        .min();
+
+        let mut previous: Option<Date> = None;
+        let mut min_days: Option<i64> = None;
+        'years: for year in fields.year_ordinals().iter().take(400) {
+            let Ok(year) = i16::try_from(year) else {
+                break;
+            };
+            for month in fields.months.iter() {
+                for day in fields.days_of_month.resolved_for(year, month as i8) {
+                    let date = date(year, month as i8, day);
+                    if !fields.days_of_week.includes_date(date) {
+                        continue;
+                    }
+                    if let Some(previous) = previous {
+                        let days = previous.duration_until(date).as_secs() / DAY;
+                        min_days = Some(min_days.map_or(days, |min| min.min(days)));
+                        if days == 1 {
+                            break 'years;
+                        }
+                    }
+                    previous = Some(date);
+                }
+            }
+        }
+        let across_days = min_days.map(|days| days * DAY - (last - first));
+        within_day
+            .into_iter()
+            .chain(across_days)
+            .min()
+            .map(SignedDuration::from_secs)
+    }
+
+    /// Converts each scheduled time of day on `date` to `time_zone`, skipping
+    /// those which a DST transition moves onto another date.
+    fn candidates_on<'a>(
+        &'a self,
+        date: Date,
+        time_zone: &'a TimeZone,
+    ) -> impl Iterator<Item = Zoned> + 'a {
+        self.fields.hours.iter().flat_map(move |hour| {
+            self.fields.minutes.iter().flat_map(move |minute| {
+                self.fields.seconds.iter().filter_map(move |second| {
+                    date.at(hour as i8, minute as i8, second as i8, 0)
+                        .to_zoned(time_zone.clone())
+                        .ok()
+                        .filter(|candidate| candidate.date() == date)
+                })
+            })
+        })
+    }
+
+    /// Returns true if the schedule can fire on the provided day of the week.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use std::str::FromStr;
+    ///
+    /// use jiff_cron::{jiff::civil::Weekday, Schedule};
+    ///
+    /// let schedule = Schedule::from_str("0 0 9 * * Mon-Fri").unwrap();
+    /// assert!(schedule.includes_weekday(Weekday::Friday));
+    /// assert!(!schedule.includes_weekday(Weekday::Sunday));
+    /// ```
+    pub fn includes_weekday(&self, weekday: Weekday) -> bool {
+        self.fields.days_of_week.includes_weekday(weekday)
+    }
+
+    /// Returns true if the schedule can fire in the provided month, numbered
+    /// from `1` for January as in jiff.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use std::str::FromStr;
+    ///
+    /// use jiff_cron::Schedule;
+    ///
+    /// let schedule = Schedule::from_str("0 0 9 * Jun-Aug *").unwrap();
+    /// assert!(schedule.includes_month(7));
+    /// assert!(!schedule.includes_month(12));
+    /// ```
+    pub fn includes_month(&self, month: i8) -> bool {
+        self.fields.months.includes_civil_month(month)
+    }
+
+    /// Returns true if the schedule can fire during the provided hour of the
+    /// day.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use std::str::FromStr;
+    ///
+    /// use jiff_cron::Schedule;
+    ///
+    /// let schedule = Schedule::from_str("0 0 9-17 * * *").unwrap();
+    /// assert!(schedule.includes_hour(12));
+    /// assert!(!schedule.includes_hour(18));
+    /// ```
+    pub fn includes_hour(&self, hour: u8) -> bool {
+        self.fields.hours.includes(Ordinal::from(hour))
+    }
+
     /// Returns a [`TimeUnitSpec`] describing the years included in this
     /// [`Schedule`].
-    pub fn years(&self) -> &impl TimeUnitSpec {
+    pub fn years(&self) -> &Years {
         &self.fields.years
     }
 
     /// Returns a [`TimeUnitSpec`] describing the months of the year included in
     /// this [`Schedule`].
-    pub fn months(&self) -> &impl TimeUnitSpec {
+    pub fn months(&self) -> &Months {
         &self.fields.months
     }
 
     /// Returns a [`TimeUnitSpec`] describing the days of the month included in
     /// this [`Schedule`].
-    pub fn days_of_month(&self) -> &impl TimeUnitSpec {
+    pub fn days_of_month(&self) -> &DaysOfMonth {
         &self.fields.days_of_month
     }
 
     /// Returns a [`TimeUnitSpec`] describing the days of the week included in
     /// this [`Schedule`].
-    pub fn days_of_week(&self) -> &impl TimeUnitSpec {
+    pub fn days_of_week(&self) -> &DaysOfWeek {
         &self.fields.days_of_week
     }
 
     /// Returns a [`TimeUnitSpec`] describing the hours of the day included in
     /// this [`Schedule`].
-    pub fn hours(&self) -> &impl TimeUnitSpec {
+    pub fn hours(&self) -> &Hours {
         &self.fields.hours
     }
 
     /// Returns a [`TimeUnitSpec`] describing the minutes of the hour included
     /// in this [`Schedule`].
-    pub fn minutes(&self) -> &impl TimeUnitSpec {
+    pub fn minutes(&self) -> &Minutes {
         &self.fields.minutes
     }
 
     /// Returns a [`TimeUnitSpec`] describing the seconds of the minute included
     /// in this [`Schedule`].
-    pub fn seconds(&self) -> &impl TimeUnitSpec {
+    pub fn seconds(&self) -> &Seconds {
         &self.fields.seconds
     }
 
+    /// Returns the features of the cron syntax this schedule's expression
+    /// uses, for telling whether it can be carried over to another cron
+    /// implementation. See [`Capabilities`].
+    pub fn capabilities(&self) -> Capabilities {
+        Capabilities::of(self)
+    }
+
+    /// Returns a [`TimeUnitSpec`] for each field of this [`Schedule`], from
+    /// seconds to years.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use std::str::FromStr;
+    ///
+    /// use jiff_cron::{Schedule, TimeUnitSpec};
+    ///
+    /// let schedule = Schedule::from_str("0 30 9 * * Mon-Fri").unwrap();
+    /// for field in schedule.fields() {
+    ///     println!(
+    ///         "{} ({}-{}): {} included",
+    ///         field.unit_name(),
+    ///         field.min(),
+    ///         field.max(),
+    ///         field.count()
+    ///     );
+    /// }
+    /// ```
+    pub fn fields(&self) -> [&dyn TimeUnitSpec; 7] {
+        [
+            &self.fields.seconds,
+            &self.fields.minutes,
+            &self.fields.hours,
+            &self.fields.days_of_month,
+            &self.fields.months,
+            &self.fields.days_of_week,
+            &self.fields.years,
+        ]
+    }
+
+    /// Returns true if iteration stops after the last year included in the
+    /// year field, i.e. unless the year field is `*` and
+    /// [`Schedule::with_unbounded_years`] was used.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use std::str::FromStr;
+    ///
+    /// use jiff_cron::Schedule;
+    ///
+    /// let schedule = Schedule::from_str("@daily").unwrap();
+    /// assert!(schedule.is_year_bounded());
+    /// assert_eq!(Some(2100), schedule.year_bound());
+    ///
+    /// let schedule = schedule.with_unbounded_years();
+    /// assert!(!schedule.is_year_bounded());
+    /// assert_eq!(None, schedule.year_bound());
+    /// ```
+    pub fn is_year_bounded(&self) -> bool {
+        !self.fields.unbounded_years
+    }
+
+    /// Returns the last year in which iteration can find a fire time, or
+    /// `None` if iteration continues until jiff's maximum supported year.
+    pub fn year_bound(&self) -> Option<Ordinal> {
+        if self.fields.unbounded_years {
+            None
+        } else {
+            self.fields.years.iter().next_back()
+        }
+    }
+
+    /// Returns the first time the schedule can ever fire in `time_zone`, from
+    /// the first year of its years field, e.g. for showing when a schedule
+    /// restricted to some years becomes active.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use std::str::FromStr;
+    ///
+    /// use jiff_cron::{
+    ///     jiff::{civil::date, tz::TimeZone},
+    ///     Schedule,
+    /// };
+    ///
+    /// let schedule = Schedule::from_str("0 0 9 * * Mon 2030-2032").unwrap();
+    /// let earliest = schedule.earliest(&TimeZone::UTC).unwrap();
+    /// assert_eq!(date(2030, 1, 7).at(9, 0, 0, 0), earliest.datetime());
+    /// let latest = schedule.latest(&TimeZone::UTC).unwrap();
+    /// assert_eq!(date(2032, 12, 27).at(9, 0, 0, 0), latest.datetime());
+    ///
+    /// let unbounded = Schedule::from_str("@daily").unwrap().with_unbounded_years();
+    /// assert!(unbounded.earliest(&TimeZone::UTC).is_some());
+    /// assert_eq!(None, unbounded.latest(&TimeZone::UTC));
+    /// ```
+    pub fn earliest(&self, time_zone: &TimeZone) -> Option<Zoned> {
+        let first_year = i16::try_from(self.fields.year_ordinals().iter().next()?).ok()?;
+        let start = date(first_year, 1, 1).to_zoned(time_zone.clone()).ok()?;
+        if self.includes(start.clone()) {
+            return Some(start);
+        }
+        self.after(&start).next()
+    }
+
+    /// Returns the last time the schedule can ever fire in `time_zone`, up to
+    /// the end of its [`Schedule::year_bound`], or `None` if it's unbounded
+    /// or never fires. See [`Schedule::earliest`].
+    pub fn latest(&self, time_zone: &TimeZone) -> Option<Zoned> {
+        let last_year = i16::try_from(self.year_bound()?).ok()?;
+        let end = date(last_year.checked_add(1)?, 1, 1)
+            .to_zoned(time_zone.clone())
+            .ok()?;
+        self.after(&end).next_back()
+    }
+
+    /// Lets iteration continue past the year field's upper bound of 2100, up
+    /// to jiff's maximum supported year, if the year field is `*`. Schedules
+    /// restricted to specific years are returned unchanged.
+    ///
+    /// The source expression is unchanged, so reparsing it yields a bounded
+    /// schedule again.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use std::str::FromStr;
+    ///
+    /// use jiff_cron::{
+    ///     jiff::{civil::date, tz::TimeZone},
+    ///     Schedule,
+    /// };
+    ///
+    /// let after = date(2100, 12, 31)
+    ///     .at(12, 0, 0, 0)
+    ///     .to_zoned(TimeZone::UTC)
+    ///     .unwrap();
+    ///
+    /// let schedule = Schedule::from_str("@daily").unwrap();
+    /// assert_eq!(None, schedule.after(&after).next());
+    ///
+    /// let schedule = schedule.with_unbounded_years();
+    /// let next = schedule.after(&after).next().unwrap();
+    /// assert_eq!(date(2101, 1, 1), next.date());
+    /// ```
+    pub fn with_unbounded_years(mut self) -> Schedule {
+        let fields = Arc::make_mut(&mut self.fields);
+        fields.unbounded_years = fields.years.is_all();
+        self.reset_next_fire_cache();
+        self
+    }
+
+    /// Restricts the schedule to the years within `range`, keeping only those
+    /// which were already included. The source expression is regenerated from
+    /// the schedule's fields, so it parses back to the same schedule.
+    ///
+    /// Fails with [`ErrorKind::Expression`] if no years remain, and with
+    /// [`ErrorKind::InvalidOrdinal`] if years past the year field's upper
+    /// bound of 2100 remain, as from [`Schedule::with_unbounded_years`],
+    /// since neither could be written as an expression.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use std::str::FromStr;
+    ///
+    /// use jiff_cron::{Schedule, TimeUnitSpec};
+    ///
+    /// let template = Schedule::from_str("@daily").unwrap();
+    /// let schedule = template.clone().with_year_range(2025..=2027).unwrap();
+    /// assert_eq!(3, schedule.years().count());
+    /// assert_eq!("0 0 0 * * * 2025-2027", schedule.source());
+    /// assert!(template.with_year_range(..1970).is_err());
+    /// ```
+    pub fn with_year_range<R>(mut self, range: R) -> Result<Schedule, Error>
+    where
+        R: RangeBounds<Ordinal>,
+    {
+        let fields = Arc::make_mut(&mut self.fields);
+        let years: OrdinalSet = fields.year_ordinals().range(range).collect();
+        match years.iter().next_back() {
+            None => {
+                return Err(ErrorKind::Expression(
+                    "The schedule includes none of the years in the range".to_owned(),
+                )
+                .into())
+            }
+            Some(last) if last > Years::inclusive_max() => {
+                return Err(ErrorKind::InvalidOrdinal {
+                    unit: TimeUnit::Years,
+                    value: i64::from(last),
+                    min: Years::inclusive_min(),
+                    max: Years::inclusive_max(),
+                }
+                .into())
+            }
+            Some(_) => {}
+        }
+        fields.years = Years::from_ordinal_set(years);
+        fields.unbounded_years = false;
+        self.source = Arc::new(fields.to_string());
+        self.reset_next_fire_cache();
+        Ok(self)
+    }
+
+    /// Replaces the schedule's seconds with `seconds`. The source expression
+    /// is regenerated from the schedule's fields.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use std::str::FromStr;
+    ///
+    /// use jiff_cron::Schedule;
+    ///
+    /// let schedule = Schedule::from_str("0 */5 * * * *").unwrap();
+    /// let schedule = schedule.with_seconds("*/30".parse().unwrap());
+    /// assert_eq!("0,30 */5 * * * * *", schedule.source());
+    /// ```
+    pub fn with_seconds(mut self, seconds: Seconds) -> Schedule {
+        let fields = Arc::make_mut(&mut self.fields);
+        fields.seconds = seconds;
+        self.source = Arc::new(fields.to_string());
+        self.reset_next_fire_cache();
+        self
+    }
+
+    /// Remembers the most recently computed fire time, so that looking up the
+    /// next fire time after a slowly advancing `now` doesn't search for it
+    /// again until `now` passes it. This suits polling loops which repeatedly
+    /// call `schedule.after(&now).next()`.
+    ///
+    /// The cache sits behind a lock so the schedule can still be shared
+    /// between threads, and clones of the schedule share it.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use std::str::FromStr;
+    ///
+    /// use jiff_cron::{
+    ///     jiff::{civil::date, tz::TimeZone, ToSpan},
+    ///     Schedule,
+    /// };
+    ///
+    /// let schedule = Schedule::from_str("0 0 * * * *")
+    ///     .unwrap()
+    ///     .with_next_fire_cache();
+    /// let mut now = date(2025, 1, 1)
+    ///     .at(12, 0, 0, 0)
+    ///     .to_zoned(TimeZone::UTC)
+    ///     .unwrap();
+    /// for _ in 0..10 {
+    ///     // Only the first iteration searches the schedule.
+    ///     let next = schedule.after(&now).next().unwrap();
+    ///     assert_eq!(date(2025, 1, 1).at(13, 0, 0, 0), next.datetime());
+    ///     now = now.checked_add(1.second()).unwrap();
+    /// }
+    /// ```
+    pub fn with_next_fire_cache(mut self) -> Schedule {
+        self.next_fire_cache = Some(Arc::default());
+        self
+    }
+
+    // Detaches the schedule from the cache shared with its clones, whose
+    // fields no longer match.
+    fn reset_next_fire_cache(&mut self) {
+        if self.next_fire_cache.is_some() {
+            self.next_fire_cache = Some(Arc::default());
+        }
+    }
+
+    /// Returns a 64-bit fingerprint of the times the schedule fires at, which
+    /// is equal for equal schedules regardless of how their expressions were
+    /// written.
+    ///
+    /// The fingerprint is stable across releases and platforms. Version 1,
+    /// the current version, is the 64-bit FNV-1a hash of the UTF-8 string
+    /// `jiff-cron-fingerprint-v1:` followed by the schedule's seven fields
+    /// separated by single spaces. Each field is written in its shortest form
+    /// using numeric ordinals, as its `Display` implementation writes it, and
+    /// years are written as `1970-9999` if the schedule has unbounded years.
+    /// Any change to this algorithm will come with a new version.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use std::str::FromStr;
+    ///
+    /// use jiff_cron::Schedule;
+    ///
+    /// let a = Schedule::from_str("0 0 9 * * Mon-Fri").unwrap();
+    /// let b = Schedule::from_str("0  0  9  ?  *  2,3,4,5,6  *").unwrap();
+    /// assert_eq!(a.fingerprint(), b.fingerprint());
+    /// // The FNV-1a hash of "jiff-cron-fingerprint-v1:0 0 9 * * 2-6 *".
+    /// assert_eq!(0xb490_e0ef_d038_f79e, a.fingerprint());
+    /// ```
+    pub fn fingerprint(&self) -> u64 {
+        let canonical = format!("jiff-cron-fingerprint-v1:{}", self.fields.canonical());
+        fnv1a(canonical.as_bytes())
+    }
+
     pub fn timeunitspec_eq(&self, other: &Schedule) -> bool {
         self.fields == other.fields
     }
 
+    /// Delays each fire time by a jitter of less than `max`, derived from
+    /// `key`, such as a host name, so that machines running the same
+    /// expression don't all fire at once, without editing the expression.
+    /// See [`JitteredSchedule`](crate::JitteredSchedule).
+    pub fn with_jitter_key(self, key: impl AsRef<[u8]>, max: SignedDuration) -> JitteredSchedule {
+        JitteredSchedule::new(self, key.as_ref(), max)
+    }
+
     /// Returns a reference to the source cron expression.
     pub fn source(&self) -> &str {
         &self.source
     }
 }
 
+/// Like [`Schedule::search_next_from`], with a [`Seeker`] which is set up
+/// once for several searches.
+fn search_next_with(
+    seeker: &Seeker<'_>,
+    mut start: DateTime,
+    after: &Zoned,
+    budget: SearchBudget,
+) -> Result<Option<Zoned>, Exhausted> {
+    let mut spending = budget.start();
+    let time_zone = after.time_zone();
+    loop {
+        let Some(candidate) = seeker.next_from(start) else {
+            return Ok(None);
+        };
+        spending.spend()?;
+        match candidate.to_zoned(time_zone.clone()) {
+            // Around a transition, a civil time after `after`'s may still
+            // denote an earlier instant.
+            Ok(candidate) if candidate > *after => return Ok(Some(candidate)),
+            _ => {}
+        }
+        match candidate.checked_add(ONE_SECOND) {
+            Ok(next) => start = next,
+            Err(_) => return Ok(None),
+        }
+    }
+}
+
 impl Display for Schedule {
     fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
         write!(f, "{}", self.source)
     }
 }
 
+/// Formats the schedule as its source expression, without formatting its
+/// fields on the device.
+#[cfg(feature = "defmt")]
+impl defmt::Format for Schedule {
+    fn format(&self, f: defmt::Formatter<'_>) {
+        defmt::write!(f, "{=str}", self.source())
+    }
+}
+
 impl PartialEq for Schedule {
     fn eq(&self, other: &Schedule) -> bool {
         self.timeunitspec_eq(other)
     }
 }
 
+/// Schedules are ordered field by field, from seconds to years, so that
+/// schedules ordered equal are equal however they're written. Within a field
+/// `*` sorts first, then the values each field fires at are compared in
+/// order, with unbounded years standing for `1970-9999`. Schedules differing
+/// only in days counted from the end of the month or the nearest weekday,
+/// e.g. `L` and `LW`, fall back to comparing their canonical expressions.
+///
+/// This order is stable between releases, and deterministic, e.g. for keys of
+/// a [`BTreeMap`](std::collections::BTreeMap) written to a config dump, but
+/// has no meaning beyond that.
+///
+/// # Example
+///
+/// ```rust
+/// use std::{collections::BTreeSet, str::FromStr};
+///
+/// use jiff_cron::Schedule;
+///
+/// let schedules: BTreeSet<Schedule> = ["@hourly", "0 0 * * * * *", "0 30 9 * * Mon-Fri"]
+///     .into_iter()
+///     .map(|expression| Schedule::from_str(expression).unwrap())
+///     .collect();
+/// // `0 0 * * * * *` and `@hourly` are equal, and `0 0 *` sorts before
+/// // `0 30 9`.
+/// assert_eq!(2, schedules.len());
+/// let last = schedules.last().unwrap();
+/// assert_eq!("0 30 9 * * Mon-Fri", last.source());
+/// ```
+impl Ord for Schedule {
+    fn cmp(&self, other: &Schedule) -> Ordering {
+        if Arc::ptr_eq(&self.fields, &other.fields) {
+            return Ordering::Equal;
+        }
+        let (a, b) = (&*self.fields, &*other.fields);
+        cmp_field(&a.seconds, &b.seconds)
+            .then_with(|| cmp_field(&a.minutes, &b.minutes))
+            .then_with(|| cmp_field(&a.hours, &b.hours))
+            .then_with(|| cmp_field(&a.days_of_month, &b.days_of_month))
+            .then_with(|| cmp_field(&a.months, &b.months))
+            .then_with(|| cmp_field(&a.days_of_week, &b.days_of_week))
+            .then_with(|| {
+                cmp_ordinals(
+                    !a.unbounded_years && a.years.is_all(),
+                    a.year_ordinals(),
+                    !b.unbounded_years && b.years.is_all(),
+                    b.year_ordinals(),
+                )
+            })
+            .then_with(|| {
+                if a == b {
+                    Ordering::Equal
+                } else {
+                    a.canonical().cmp(&b.canonical())
+                }
+            })
+    }
+}
+
+fn cmp_field<T: TimeUnitField>(a: &T, b: &T) -> Ordering {
+    cmp_ordinals(a.is_all(), a.ordinals(), b.is_all(), b.ordinals())
+}
+
+/// Compares the values two fields fire at, with `*` first.
+fn cmp_ordinals(a_all: bool, a: &OrdinalSet, b_all: bool, b: &OrdinalSet) -> Ordering {
+    b_all.cmp(&a_all).then_with(|| a.iter().cmp(b.iter()))
+}
+
+impl PartialOrd for Schedule {
+    fn partial_cmp(&self, other: &Schedule) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
 #[derive(Clone, Debug, PartialEq, Eq)]
 pub struct ScheduleFields {
     years: Years,
@@ -369,6 +1701,8 @@ pub struct ScheduleFields {
     hours: Hours,
     minutes: Minutes,
     seconds: Seconds,
+    // Only ever set if `years` is `*`.
+    unbounded_years: bool,
 }
 
 impl ScheduleFields {
@@ -389,10 +1723,76 @@ impl ScheduleFields {
             hours,
             minutes,
             seconds,
+            unbounded_years: false,
+        }
+    }
+
+    fn seeker(&self) -> Seeker<'_> {
+        Seeker::new(
+            self.year_ordinals(),
+            self.months.ordinals(),
+            &self.days_of_month,
+            &self.days_of_week,
+            self.hours.ordinals(),
+            self.minutes.ordinals(),
+            self.seconds.ordinals(),
+        )
+    }
+
+    /// Returns the fields as a normalized expression, with unbounded years
+    /// written out as their range.
+    fn canonical(&self) -> String {
+        if !self.unbounded_years {
+            return self.to_string();
+        }
+        format!(
+            "{} {} {} {} {} {} 1970-9999",
+            self.seconds,
+            self.minutes,
+            self.hours,
+            self.days_of_month,
+            self.months,
+            self.days_of_week
+        )
+    }
+
+    fn year_ordinals(&self) -> &OrdinalSet {
+        if self.unbounded_years {
+            &UNBOUNDED_YEARS
+        } else {
+            self.years.ordinals()
         }
     }
 }
 
+impl Display for ScheduleFields {
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        write!(
+            f,
+            "{} {} {} {} {} {} {}",
+            self.seconds,
+            self.minutes,
+            self.hours,
+            self.days_of_month,
+            self.months,
+            self.days_of_week,
+            self.years
+        )
+    }
+}
+
+/// Every year from the year field's minimum up to jiff's maximum.
+static UNBOUNDED_YEARS: OrdinalSet = OrdinalSet::from_inclusive_range(1970, 9999);
+
+/// Returns the smallest difference between consecutive ordinals of `set`.
+fn min_gap(set: &OrdinalSet) -> Option<i64> {
+    set.iter()
+        .zip(set.iter().skip(1))
+        .map(|(earlier, later)| i64::from(later - earlier))
+        .min()
+}
+
+#[derive(Clone, Debug)]
 pub struct ScheduleIterator<'a> {
     schedule: &'a Schedule,
     previous_datetime: Option<Zoned>,
@@ -408,6 +1808,185 @@ impl<'a> ScheduleIterator<'a> {
     }
 }
 
+impl<'a> ScheduleIterator<'a> {
+    /// Returns a checkpoint of the iterator's position, from which
+    /// [`Schedule::resume`] continues iterating in either direction.
+    pub fn cursor(&self) -> IteratorCursor {
+        IteratorCursor::new(self.schedule.fingerprint(), self.previous_datetime.clone())
+    }
+
+    /// Returns an iterator over only the first `limit` fire times of each
+    /// civil day in the iterator's time zone. The rest of a day's fire times
+    /// are skipped without being visited.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use std::str::FromStr;
+    ///
+    /// use jiff_cron::{
+    ///     jiff::{civil::date, tz::TimeZone},
+    ///     Schedule,
+    /// };
+    ///
+    /// let schedule = Schedule::from_str("0 */15 9-17 * * *").unwrap();
+    /// let after = date(2025, 1, 1)
+    ///     .at(0, 0, 0, 0)
+    ///     .to_zoned(TimeZone::UTC)
+    ///     .unwrap();
+    /// let fires: Vec<_> = schedule
+    ///     .after(&after)
+    ///     .limit_per_day(2)
+    ///     .take(3)
+    ///     .map(|fire| fire.datetime())
+    ///     .collect();
+    /// assert_eq!(
+    ///     vec![
+    ///         date(2025, 1, 1).at(9, 0, 0, 0),
+    ///         date(2025, 1, 1).at(9, 15, 0, 0),
+    ///         date(2025, 1, 2).at(9, 0, 0, 0),
+    ///     ],
+    ///     fires
+    /// );
+    /// ```
+    pub fn limit_per_day(self, limit: usize) -> LimitPerDay<ScheduleIterator<'a>> {
+        LimitPerDay::new(self, limit)
+    }
+
+    /// Returns an iterator over only the first fire time of each civil day in
+    /// the iterator's time zone.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use std::str::FromStr;
+    ///
+    /// use jiff_cron::{
+    ///     jiff::{civil::date, tz::TimeZone},
+    ///     Schedule,
+    /// };
+    ///
+    /// let schedule = Schedule::from_str("0 */5 9-17 * * Mon-Fri").unwrap();
+    /// let after = date(2025, 1, 3)
+    ///     .at(12, 0, 0, 0)
+    ///     .to_zoned(TimeZone::UTC)
+    ///     .unwrap();
+    /// let mut firsts = schedule.after(&after).first_of_each_day();
+    /// assert_eq!(
+    ///     date(2025, 1, 3).at(12, 5, 0, 0),
+    ///     firsts.next().unwrap().datetime()
+    /// );
+    /// assert_eq!(
+    ///     date(2025, 1, 6).at(9, 0, 0, 0),
+    ///     firsts.next().unwrap().datetime()
+    /// );
+    /// ```
+    pub fn first_of_each_day(self) -> EachPeriod<ScheduleIterator<'a>> {
+        EachPeriod::first_of_each_day(self)
+    }
+
+    /// Returns an iterator over only the last fire time of each civil day in
+    /// the iterator's time zone, e.g. for sending a summary after a day's
+    /// last tick.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use std::str::FromStr;
+    ///
+    /// use jiff_cron::{
+    ///     jiff::{civil::date, tz::TimeZone},
+    ///     Schedule,
+    /// };
+    ///
+    /// let schedule = Schedule::from_str("0 */5 9-17 * * Mon-Fri").unwrap();
+    /// let after = date(2025, 1, 3)
+    ///     .at(12, 0, 0, 0)
+    ///     .to_zoned(TimeZone::UTC)
+    ///     .unwrap();
+    /// let mut lasts = schedule.after(&after).last_of_each_day();
+    /// assert_eq!(
+    ///     date(2025, 1, 3).at(17, 55, 0, 0),
+    ///     lasts.next().unwrap().datetime()
+    /// );
+    /// assert_eq!(
+    ///     date(2025, 1, 6).at(17, 55, 0, 0),
+    ///     lasts.next().unwrap().datetime()
+    /// );
+    /// ```
+    pub fn last_of_each_day(self) -> EachPeriod<ScheduleIterator<'a>> {
+        EachPeriod::last_of_each_day(self)
+    }
+
+    /// Returns an iterator over only the first fire time of each month in the
+    /// iterator's time zone.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use std::str::FromStr;
+    ///
+    /// use jiff_cron::{
+    ///     jiff::{civil::date, tz::TimeZone},
+    ///     Schedule,
+    /// };
+    ///
+    /// let schedule = Schedule::from_str("0 0 9 * * Mon").unwrap();
+    /// let after = date(2025, 1, 1)
+    ///     .at(0, 0, 0, 0)
+    ///     .to_zoned(TimeZone::UTC)
+    ///     .unwrap();
+    /// let firsts: Vec<_> = schedule
+    ///     .after(&after)
+    ///     .first_of_each_month()
+    ///     .take(2)
+    ///     .map(|fire| fire.date())
+    ///     .collect();
+    /// assert_eq!(vec![date(2025, 1, 6), date(2025, 2, 3)], firsts);
+    /// ```
+    pub fn first_of_each_month(self) -> EachPeriod<ScheduleIterator<'a>> {
+        EachPeriod::first_of_each_month(self)
+    }
+
+    /// Returns an iterator over only the last fire time of each month in the
+    /// iterator's time zone.
+    pub fn last_of_each_month(self) -> EachPeriod<ScheduleIterator<'a>> {
+        EachPeriod::last_of_each_month(self)
+    }
+
+    /// Returns an iterator over `Ok` fire times, which ends with an
+    /// [`ErrorKind::EndOfSchedule`] error if the schedule runs out of fire
+    /// times, as its years field's range ends, telling its end apart from a
+    /// bound such as [`FireTimes::take_until`](crate::FireTimes::take_until)
+    /// being reached.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use std::str::FromStr;
+    ///
+    /// use jiff_cron::{
+    ///     error::ErrorKind,
+    ///     jiff::{civil::date, tz::TimeZone},
+    ///     Schedule,
+    /// };
+    ///
+    /// let schedule = Schedule::from_str("0 0 0 1 1 * 2030").unwrap();
+    /// let after = date(2025, 1, 1)
+    ///     .at(0, 0, 0, 0)
+    ///     .to_zoned(TimeZone::UTC)
+    ///     .unwrap();
+    /// let mut fires = schedule.after(&after).with_end_marker();
+    /// let last = fires.next().unwrap().unwrap();
+    /// let error = fires.next().unwrap().unwrap_err();
+    /// assert_eq!(&ErrorKind::EndOfSchedule { last: Some(last) }, error.kind());
+    /// assert!(fires.next().is_none());
+    /// ```
+    pub fn with_end_marker(self) -> WithEndMarker<ScheduleIterator<'a>> {
+        WithEndMarker::new(self)
+    }
+}
+
 impl Iterator for ScheduleIterator<'_> {
     type Item = Zoned;
 
@@ -421,6 +2000,16 @@ impl Iterator for ScheduleIterator<'_> {
             None
         }
     }
+    fn nth(&mut self, n: usize) -> Option<Zoned> {
+        let previous = self.previous_datetime.take()?;
+
+        if let Some(nth) = self.schedule.nth_after(&previous, n) {
+            self.previous_datetime = Some(nth.clone());
+            Some(nth)
+        } else {
+            None
+        }
+    }
 }
 
 impl DoubleEndedIterator for ScheduleIterator<'_> {
@@ -437,6 +2026,25 @@ impl DoubleEndedIterator for ScheduleIterator<'_> {
 }
 
 /// A `ScheduleIterator` with a static lifetime.
+///
+/// It's `Send + Sync + 'static`, so it can be moved into a spawned task, and
+/// cloning it is cheap, since clones share the schedule: a clone can be
+/// advanced to look ahead without moving the original.
+///
+/// # Example
+///
+/// ```rust
+/// use std::{str::FromStr, thread};
+///
+/// use jiff_cron::{jiff::tz::TimeZone, Schedule};
+///
+/// let schedule = Schedule::from_str("0 0 * * * *").unwrap();
+/// let mut upcoming = schedule.upcoming_owned(TimeZone::UTC);
+/// let next = upcoming.clone().next();
+/// let handle = thread::spawn(move || upcoming.next());
+/// assert_eq!(next, handle.join().unwrap());
+/// ```
+#[derive(Clone, Debug)]
 pub struct OwnedScheduleIterator {
     schedule: Schedule,
     previous_datetime: Option<Zoned>,
@@ -451,6 +2059,44 @@ impl OwnedScheduleIterator {
     }
 }
 
+impl OwnedScheduleIterator {
+    /// Returns a checkpoint of the iterator's position, from which
+    /// [`Schedule::resume_owned`] continues iterating in either direction.
+    pub fn cursor(&self) -> IteratorCursor {
+        IteratorCursor::new(self.schedule.fingerprint(), self.previous_datetime.clone())
+    }
+
+    /// Like [`ScheduleIterator::limit_per_day`].
+    pub fn limit_per_day(self, limit: usize) -> LimitPerDay<OwnedScheduleIterator> {
+        LimitPerDay::new(self, limit)
+    }
+
+    /// Like [`ScheduleIterator::first_of_each_day`].
+    pub fn first_of_each_day(self) -> EachPeriod<OwnedScheduleIterator> {
+        EachPeriod::first_of_each_day(self)
+    }
+
+    /// Like [`ScheduleIterator::last_of_each_day`].
+    pub fn last_of_each_day(self) -> EachPeriod<OwnedScheduleIterator> {
+        EachPeriod::last_of_each_day(self)
+    }
+
+    /// Like [`ScheduleIterator::first_of_each_month`].
+    pub fn first_of_each_month(self) -> EachPeriod<OwnedScheduleIterator> {
+        EachPeriod::first_of_each_month(self)
+    }
+
+    /// Like [`ScheduleIterator::last_of_each_month`].
+    pub fn last_of_each_month(self) -> EachPeriod<OwnedScheduleIterator> {
+        EachPeriod::last_of_each_month(self)
+    }
+
+    /// Like [`ScheduleIterator::with_end_marker`].
+    pub fn with_end_marker(self) -> WithEndMarker<OwnedScheduleIterator> {
+        WithEndMarker::new(self)
+    }
+}
+
 impl Iterator for OwnedScheduleIterator {
     type Item = Zoned;
 
@@ -464,6 +2110,16 @@ impl Iterator for OwnedScheduleIterator {
             None
         }
     }
+    fn nth(&mut self, n: usize) -> Option<Zoned> {
+        let previous = self.previous_datetime.take()?;
+
+        if let Some(nth) = self.schedule.nth_after(&previous, n) {
+            self.previous_datetime = Some(nth.clone());
+            Some(nth)
+        } else {
+            None
+        }
+    }
 }
 
 impl DoubleEndedIterator for OwnedScheduleIterator {
@@ -479,20 +2135,15 @@ impl DoubleEndedIterator for OwnedScheduleIterator {
     }
 }
 
-fn is_leap_year(year: Ordinal) -> bool {
-    let by_four = year % 4 == 0;
-    let by_hundred = year % 100 == 0;
-    let by_four_hundred = year % 400 == 0;
-    by_four && ((!by_hundred) || by_four_hundred)
+impl Seek for ScheduleIterator<'_> {
+    fn seek(&mut self, position: Option<Zoned>) {
+        self.previous_datetime = position;
+    }
 }
 
-fn days_in_month(month: Ordinal, year: Ordinal) -> u32 {
-    let is_leap_year = is_leap_year(year);
-    match month {
-        9 | 4 | 6 | 11 => 30,
-        2 if is_leap_year => 29,
-        2 => 28,
-        _ => 31,
+impl Seek for OwnedScheduleIterator {
+    fn seek(&mut self, position: Option<Zoned>) {
+        self.previous_datetime = position;
     }
 }
 
@@ -563,9 +2214,12 @@ impl<'de> Deserialize<'de> for Schedule {
 
 #[cfg(test)]
 mod test {
-    use std::str::FromStr;
+    use std::{str::FromStr, time::Duration};
 
-    use jiff::{civil::DateTime, SignedDuration, Span};
+    use jiff::{
+        civil::{time, DateTime},
+        SignedDuration, Span,
+    };
     #[cfg(feature = "serde")]
     use serde_test::{assert_tokens, Token};
 
@@ -587,7 +2241,7 @@ mod test {
             &[Token::String(
                 "definitively an invalid value for a cron schedule!",
             )],
-            "Invalid expression: Invalid cron expression.",
+            "Invalid expression: Expected 6 or 7 fields, found 8.",
         );
     }
 
@@ -847,6 +2501,551 @@ mod test {
         assert!(schedule.is_err());
     }
 
+    #[test]
+    fn test_sparse_schedules() {
+        let leap_days = Schedule::from_str("0 0 0 29 Feb *").unwrap();
+        let start = date(2025, 3, 1).to_zoned(TimeZone::UTC).unwrap();
+        let next = leap_days.after(&start).next().unwrap();
+        assert_eq!(date(2028, 2, 29).to_zoned(TimeZone::UTC).unwrap(), next);
+        let prev = leap_days.after(&start).next_back().unwrap();
+        assert_eq!(date(2024, 2, 29).to_zoned(TimeZone::UTC).unwrap(), prev);
+
+        let new_years_eve = Schedule::from_str("59 59 23 31 Dec Fri").unwrap();
+        let next = new_years_eve.after(&start).next().unwrap();
+        let expected = date(2027, 12, 31).at(23, 59, 59, 0);
+        assert_eq!(expected, next.datetime());
+        let prev = new_years_eve.after(&start).next_back().unwrap();
+        let expected = date(2021, 12, 31).at(23, 59, 59, 0);
+        assert_eq!(expected, prev.datetime());
+
+        let never = Schedule::from_str("0 0 0 30 Feb *").unwrap();
+        assert!(never.after(&start).next().is_none());
+        assert!(never.after(&start).next_back().is_none());
+    }
+
+    #[test]
+    fn test_nth_weekdays() {
+        let schedule = Schedule::from_str("0 0 9 * * Fri#-2").unwrap();
+        let start = date(2025, 1, 1).to_zoned(TimeZone::UTC).unwrap();
+        let fires: Vec<_> = schedule
+            .after(&start)
+            .take(3)
+            .map(|fire| fire.date())
+            .collect();
+        // February 2025 has only four Fridays.
+        assert_eq!(
+            vec![date(2025, 1, 24), date(2025, 2, 21), date(2025, 3, 21)],
+            fires
+        );
+        let prev = schedule.after(&start).next_back().unwrap();
+        assert_eq!(date(2024, 12, 20), prev.date());
+        assert!(schedule.includes(date(2025, 1, 24).at(9, 0, 0, 0).in_tz("UTC").unwrap()));
+        assert!(!schedule.includes(date(2025, 1, 31).at(9, 0, 0, 0).in_tz("UTC").unwrap()));
+        assert!(schedule.includes_weekday(Weekday::Friday));
+        assert_eq!("6#-2", schedule.days_of_week().to_string());
+
+        let schedule = Schedule::from_str("0 0 9 * * Mon#1,Fri#-1").unwrap();
+        let fires: Vec<_> = schedule
+            .after(&start)
+            .take(4)
+            .map(|fire| fire.date())
+            .collect();
+        assert_eq!(
+            vec![
+                date(2025, 1, 6),
+                date(2025, 1, 31),
+                date(2025, 2, 3),
+                date(2025, 2, 28)
+            ],
+            fires
+        );
+        assert_eq!(
+            vec![date(2025, 3, 3), date(2025, 3, 28)],
+            schedule.dates_in_month(2025, 3, &TimeZone::UTC)
+        );
+        // The fifth Monday only falls in some months.
+        let schedule = Schedule::from_str("0 0 9 * * Mon#5").unwrap();
+        let next = schedule.after(&start).next().unwrap();
+        assert_eq!(date(2025, 3, 31), next.date());
+        assert_eq!(
+            Schedule::from_str("0 0 9 * * Mon,Mon#2").unwrap(),
+            Schedule::from_str("0 0 9 * * Mon").unwrap()
+        );
+
+        // Names in any case and numbers from Sunday 1, as in Quartz.
+        let second_monday = Schedule::from_str("0 0 9 ? * MON#2").unwrap();
+        assert_eq!(second_monday, Schedule::from_str("0 0 9 ? * 2#2").unwrap());
+        let next = second_monday.after(&start).next().unwrap();
+        assert_eq!(date(2025, 1, 13), next.date());
+        let third_thursday = Schedule::from_str("0 0 9 ? * 5#3").unwrap();
+        let next = third_thursday.after(&start).next().unwrap();
+        assert_eq!(date(2025, 1, 16), next.date());
+
+        // The last occurrence, written as in Quartz.
+        let last_friday = Schedule::from_str("0 0 9 ? * Fri#-1").unwrap();
+        for expression in ["0 0 9 ? * FRIL", "0 0 9 ? * 6L", "0 0 9 ? * fril"] {
+            assert_eq!(last_friday, Schedule::from_str(expression).unwrap());
+        }
+        let next = last_friday.after(&start).next().unwrap();
+        assert_eq!(date(2025, 1, 31), next.date());
+    }
+
+    #[test]
+    fn test_last_days_of_month() {
+        let schedule = Schedule::from_str("0 0 0 L * *").unwrap();
+        let start = date(2024, 1, 15).to_zoned(TimeZone::UTC).unwrap();
+        let fires: Vec<_> = schedule
+            .after(&start)
+            .take(3)
+            .map(|fire| fire.date())
+            .collect();
+        assert_eq!(
+            vec![date(2024, 1, 31), date(2024, 2, 29), date(2024, 3, 31)],
+            fires
+        );
+        let prev = schedule.after(&start).next_back().unwrap();
+        assert_eq!(date(2023, 12, 31), prev.date());
+        assert!(schedule.fires_on(date(2025, 2, 28)));
+        assert!(!schedule.fires_on(date(2025, 3, 30)));
+        assert_eq!("L", schedule.days_of_month().to_string());
+        assert_eq!(
+            Some(SignedDuration::from_hours(24 * 28)),
+            schedule.min_interval()
+        );
+
+        // Along with days of the week, the last day must fall on one.
+        let schedule = Schedule::from_str("0 0 0 15,L * Fri").unwrap();
+        let fires: Vec<_> = schedule
+            .after(&start)
+            .take(2)
+            .map(|fire| fire.date())
+            .collect();
+        assert_eq!(vec![date(2024, 3, 15), date(2024, 5, 31)], fires);
+        assert_eq!(
+            Schedule::from_str("0 0 0 * * *").unwrap(),
+            Schedule::from_str("0 0 0 1-31,L * *").unwrap()
+        );
+
+        // Three days before the last day, leaving time ahead of month end.
+        let schedule = Schedule::from_str("0 0 0 L-3 * *").unwrap();
+        let fires: Vec<_> = schedule
+            .after(&start)
+            .take(3)
+            .map(|fire| fire.date())
+            .collect();
+        assert_eq!(
+            vec![date(2024, 1, 28), date(2024, 2, 26), date(2024, 3, 28)],
+            fires
+        );
+        assert_eq!("L-3", schedule.days_of_month().to_string());
+    }
+
+    #[test]
+    fn test_nearest_weekdays() {
+        let schedule = Schedule::from_str("0 0 9 15W * *").unwrap();
+        let start = date(2025, 1, 1).to_zoned(TimeZone::UTC).unwrap();
+        let fires: Vec<_> = schedule
+            .after(&start)
+            .skip(2)
+            .take(4)
+            .map(|fire| fire.date())
+            .collect();
+        // The 15th falls on a Saturday in March and on a Sunday in June.
+        assert_eq!(
+            vec![
+                date(2025, 3, 14),
+                date(2025, 4, 15),
+                date(2025, 5, 15),
+                date(2025, 6, 16)
+            ],
+            fires
+        );
+        let before = date(2025, 6, 16).to_zoned(TimeZone::UTC).unwrap();
+        let prev = schedule.after(&before).next_back().unwrap();
+        assert_eq!(date(2025, 5, 15), prev.date());
+
+        // The nearest weekday to the 31st is only sought in months having one.
+        let schedule = Schedule::from_str("0 0 9 31W * *").unwrap();
+        let fires: Vec<_> = schedule
+            .after(&start)
+            .take(3)
+            .map(|fire| fire.date())
+            .collect();
+        assert_eq!(
+            vec![date(2025, 1, 31), date(2025, 3, 31), date(2025, 5, 30)],
+            fires
+        );
+
+        // The last weekday of the month, e.g. for payroll.
+        let schedule = Schedule::from_str("0 0 17 LW * *").unwrap();
+        let fires: Vec<_> = schedule
+            .after(&start)
+            .take(8)
+            .map(|fire| fire.date())
+            .collect();
+        assert_eq!(
+            vec![
+                date(2025, 1, 31),
+                date(2025, 2, 28),
+                date(2025, 3, 31),
+                date(2025, 4, 30),
+                date(2025, 5, 30),
+                date(2025, 6, 30),
+                date(2025, 7, 31),
+                date(2025, 8, 29)
+            ],
+            fires
+        );
+        assert_eq!("LW", schedule.days_of_month().to_string());
+    }
+
+    #[test]
+    fn test_next_fire_cache() {
+        let uncached = Schedule::from_str("0 */20 * * * *").unwrap();
+        let cached = uncached.clone().with_next_fire_cache();
+        let start = date(2025, 6, 1).to_zoned(TimeZone::UTC).unwrap();
+        let mut now = start.clone();
+        for _ in 0..90 {
+            assert_eq!(uncached.after(&now).next(), cached.after(&now).next());
+            now = now.checked_add(SignedDuration::from_secs(37)).unwrap();
+        }
+        // Moving backwards, or into another time zone, bypasses the entry.
+        assert_eq!(uncached.after(&start).next(), cached.after(&start).next());
+        let tokyo = start.with_time_zone(TimeZone::get("Asia/Tokyo").unwrap());
+        let next = cached.after(&tokyo).next().unwrap();
+        assert_eq!(tokyo.time_zone(), next.time_zone());
+        assert_eq!(uncached.after(&tokyo).next().unwrap(), next);
+    }
+
+    #[test]
+    fn test_clone_shares_fields() {
+        let schedule = Schedule::from_str("0 0 9 * * Mon-Fri").unwrap();
+        let clone = schedule.clone();
+        assert!(Arc::ptr_eq(&schedule.fields, &clone.fields));
+        assert!(Arc::ptr_eq(&schedule.source, &clone.source));
+
+        let restricted = clone.with_year_range(2030..).unwrap();
+        assert!(!Arc::ptr_eq(&schedule.fields, &restricted.fields));
+        assert_eq!("0 0 9 * * Mon-Fri", schedule.source());
+        assert_eq!("0 0 9 * * Mon-Fri", String::from(schedule));
+    }
+
+    #[test]
+    fn test_parse_many_interns() {
+        let results =
+            Schedule::parse_many(["@daily", "0 0 0 * * *", "@daily", "nonsense", "@hourly"]);
+        let schedules: Vec<_> = results.iter().flatten().collect();
+        assert_eq!(4, schedules.len());
+        assert!(results[3].is_err());
+        assert!(Arc::ptr_eq(&schedules[0].fields, &schedules[1].fields));
+        assert!(!Arc::ptr_eq(&schedules[0].source, &schedules[1].source));
+        assert!(Arc::ptr_eq(&schedules[0].source, &schedules[2].source));
+        assert!(!Arc::ptr_eq(&schedules[0].fields, &schedules[3].fields));
+        assert_eq!(Schedule::from_str("@hourly").unwrap(), *schedules[3]);
+    }
+
+    #[test]
+    fn test_ord() {
+        let schedule = |expression| Schedule::from_str(expression).unwrap();
+        let daily = schedule("@daily");
+        assert_eq!(Ordering::Equal, daily.cmp(&schedule("0 0 0 ? * *")));
+        assert_eq!(Ordering::Equal, daily.cmp(&daily.clone()));
+        assert!(schedule("0 0 0 1 * *") < schedule("0 0 0 2 * *"));
+        assert!(daily < daily.clone().with_unbounded_years());
+        // `*` sorts before digits.
+        assert!(daily < daily.clone().with_year_range(..2025).unwrap());
+        assert!(schedule("@hourly") < daily);
+        assert_ne!(
+            Ordering::Equal,
+            schedule("0 0 0 L * *").cmp(&schedule("0 0 0 LW * *"))
+        );
+
+        let mut schedules = [schedule("@weekly"), schedule("@hourly"), daily.clone()];
+        schedules.sort();
+        assert_eq!(
+            vec!["@hourly", "@daily", "@weekly"],
+            schedules
+                .iter()
+                .map(|schedule| schedule.source())
+                .collect::<Vec<_>>()
+        );
+    }
+
+    #[test]
+    fn test_next_fires() {
+        let daily = Schedule::from_str("@daily").unwrap();
+        let schedules = [
+            daily.clone(),
+            Schedule::from_str("0 */7 * * * Sat").unwrap(),
+            daily.clone().with_year_range(..2025).unwrap(),
+            daily,
+        ];
+        let after = date(2025, 3, 14)
+            .at(15, 9, 26, 535)
+            .to_zoned(TimeZone::get("America/New_York").unwrap())
+            .unwrap();
+        let expected: Vec<_> = schedules
+            .iter()
+            .map(|schedule| schedule.after(&after).next())
+            .collect();
+        assert_eq!(expected, Schedule::next_fires(&schedules, &after));
+        assert_eq!(None, expected[2]);
+    }
+
+    #[test]
+    fn test_nth_matches_stepping() {
+        let time_zone = TimeZone::get("America/New_York").unwrap();
+        let start = date(2025, 3, 7)
+            .at(22, 13, 0, 0)
+            .to_zoned(time_zone)
+            .unwrap();
+        for expression in [
+            "*/20 */7 1-3,22 * * *",
+            "0 30 2 * * *",
+            "15 */10 * 8-10 Mar Sun,Mon",
+            "0 0 12 1 * * 2025",
+        ] {
+            let schedule = Schedule::from_str(expression).unwrap();
+            let stepped: Vec<_> = schedule.after(&start).take(401).collect();
+            for n in [0, 1, 17, 72, 199, 399, 400] {
+                assert_eq!(
+                    stepped.get(n),
+                    schedule.after(&start).nth(n).as_ref(),
+                    "{} {}",
+                    expression,
+                    n
+                );
+            }
+            let mut iter = schedule.after(&start);
+            let _ = iter.nth(50);
+            assert_eq!(stepped.get(51), iter.next().as_ref(), "{}", expression);
+        }
+    }
+
+    #[test]
+    fn test_next_n_matches_stepping() {
+        // Days around both of New York's DST transitions.
+        for start in [date(2025, 3, 8), date(2025, 11, 1)] {
+            let start = start.in_tz("America/New_York").unwrap();
+            for expression in ["0 */20 * * * *", "0 30 1,2 * * *", "0 0 0 1 1 * 2025-2026"] {
+                let schedule = Schedule::from_str(expression).unwrap();
+                let stepped: Vec<_> = schedule.after(&start).take(300).collect();
+                assert_eq!(stepped, schedule.next_n(300, &start), "{}", expression);
+            }
+        }
+        let schedule = Schedule::from_str("@hourly").unwrap();
+        assert!(schedule
+            .next_n(0, &date(2025, 1, 1).in_tz("UTC").unwrap())
+            .is_empty());
+    }
+
+    #[test]
+    fn test_skip_per_second_schedule() {
+        let schedule = Schedule::from_str("* * * * * *").unwrap();
+        let start = date(2025, 1, 1).to_zoned(TimeZone::UTC).unwrap();
+        #[allow(clippy::iter_skip_next)]
+        let next = schedule.after(&start).skip(100_000_000).next().unwrap();
+        assert_eq!(
+            start
+                .checked_add(SignedDuration::from_secs(100_000_001))
+                .unwrap(),
+            next
+        );
+    }
+
+    #[test]
+    fn test_fire_times_after_fold() {
+        let time_zone = TimeZone::get("America/New_York").unwrap();
+        let schedule = Schedule::from_str("0 */20 * * * *").unwrap();
+        // The second 01:10 of the day, after clocks went back from 02:00 EDT.
+        let during_fold = date(2025, 11, 2)
+            .at(1, 10, 0, 0)
+            .to_zoned(time_zone.clone())
+            .unwrap()
+            .checked_add(SignedDuration::from_hours(1))
+            .unwrap();
+        assert_eq!(-5 * 3600, during_fold.offset().seconds());
+
+        let next = schedule.after(&during_fold).next().unwrap();
+        assert_eq!(date(2025, 11, 2).at(2, 0, 0, 0), next.datetime());
+        assert!(next > during_fold);
+        let prev = schedule.after(&during_fold).next_back().unwrap();
+        assert_eq!(date(2025, 11, 2).at(1, 40, 0, 0), prev.datetime());
+        assert_eq!(-4 * 3600, prev.offset().seconds());
+
+        // Straight after clocks went forward, times shifted out of the gap are
+        // still found.
+        let schedule = Schedule::from_str("0 45 2 * * *").unwrap();
+        let after_gap = date(2025, 3, 9)
+            .at(3, 10, 0, 0)
+            .to_zoned(time_zone)
+            .unwrap();
+        let next = schedule.after(&after_gap).next().unwrap();
+        assert_eq!(date(2025, 3, 9).at(3, 45, 0, 0), next.datetime());
+    }
+
+    #[test]
+    fn test_dst_audit() {
+        let time_zone = TimeZone::get("America/New_York").unwrap();
+        let schedule = Schedule::from_str("0 */30 * * * *").unwrap();
+        let audit = schedule.dst_audit(&time_zone, 2025);
+        let resolutions: Vec<_> = audit
+            .iter()
+            .map(|occurrence| (occurrence.scheduled().time(), occurrence.resolution()))
+            .collect();
+        assert_eq!(
+            vec![
+                (time(2, 0, 0, 0), DstResolution::Merged),
+                (time(2, 30, 0, 0), DstResolution::Merged),
+                (time(1, 0, 0, 0), DstResolution::Earlier),
+                (time(1, 30, 0, 0), DstResolution::Earlier),
+            ],
+            resolutions
+        );
+
+        // The audit agrees with the fire times.
+        let start = date(2025, 1, 1).to_zoned(time_zone.clone()).unwrap();
+        let fires: Vec<Zoned> = schedule
+            .after(&start)
+            .take_while(|fire| fire.year() == 2025)
+            .collect();
+        for occurrence in &audit {
+            assert_eq!(
+                1,
+                fires
+                    .iter()
+                    .filter(|&fire| fire == occurrence.fire())
+                    .count()
+            );
+        }
+        let spring: Vec<_> = fires
+            .iter()
+            .filter(|fire| fire.date() == date(2025, 3, 9))
+            .collect();
+        assert_eq!(46, spring.len());
+
+        assert!(schedule.dst_audit(&TimeZone::UTC, 2025).is_empty());
+        // Southern hemisphere transitions are found too.
+        let time_zone = TimeZone::get("Australia/Sydney").unwrap();
+        let schedule = Schedule::from_str("0 15 2 * * *").unwrap();
+        let audit = schedule.dst_audit(&time_zone, 2025);
+        assert_eq!(2, audit.len());
+        assert_eq!(4, audit[0].scheduled().month());
+        assert_eq!(DstResolution::Earlier, audit[0].resolution());
+        assert_eq!(10, audit[1].scheduled().month());
+        assert_eq!(DstResolution::Shifted, audit[1].resolution());
+    }
+
+    #[test]
+    fn test_bounded_search() {
+        let time_zone = TimeZone::get("America/New_York").unwrap();
+        let schedule = Schedule::from_str("0 */20 * * * *").unwrap();
+        let during_fold = date(2025, 11, 2)
+            .at(1, 10, 0, 0)
+            .to_zoned(time_zone)
+            .unwrap()
+            .checked_add(SignedDuration::from_hours(1))
+            .unwrap();
+        // 01:20 and 01:40 in EDT have already passed.
+        let candidates = SearchBudget::candidates;
+        assert!(schedule
+            .try_next_after(&during_fold, candidates(2))
+            .is_err());
+        assert_eq!(
+            schedule.after(&during_fold).next(),
+            schedule
+                .try_next_after(&during_fold, candidates(3))
+                .unwrap()
+        );
+        // 02:00 in EST hasn't been reached yet.
+        assert!(schedule
+            .try_prev_before(&during_fold, candidates(1))
+            .is_err());
+        assert_eq!(
+            schedule.after(&during_fold).next_back(),
+            schedule
+                .try_prev_before(&during_fold, candidates(2))
+                .unwrap()
+        );
+
+        let schedule = Schedule::from_str("0 0 0 1 1 * 2020").unwrap();
+        let after = date(2020, 6, 1)
+            .at(0, 0, 0, 0)
+            .to_zoned(TimeZone::UTC)
+            .unwrap();
+        assert_eq!(
+            None,
+            schedule.try_next_after(&after, candidates(0)).unwrap()
+        );
+        let error = schedule.try_prev_before(&after, candidates(0)).unwrap_err();
+        assert_eq!("JC0009", error.code());
+        assert_eq!("No fire time found within 0 candidates", error.to_string());
+    }
+
+    #[test]
+    fn test_bounded_search_time() {
+        let schedule = Schedule::from_str("0 0 0 1 1 * 2020").unwrap();
+        let after = date(2020, 6, 1)
+            .at(0, 0, 0, 0)
+            .to_zoned(TimeZone::UTC)
+            .unwrap();
+        // Time is checked as each candidate is found, so none are considered
+        // once it's spent.
+        let spent = SearchBudget::time(Duration::ZERO);
+        assert!(schedule.try_prev_before(&after, spent).is_err());
+        let budget = SearchBudget::time(Duration::from_secs(60));
+        assert_eq!(
+            schedule.after(&after).next_back(),
+            schedule.try_prev_before(&after, budget).unwrap()
+        );
+        let budget = budget.with_candidates(0);
+        let error = schedule.try_prev_before(&after, budget).unwrap_err();
+        assert_eq!(&ErrorKind::SearchExhausted(budget), error.kind());
+        assert_eq!(
+            "No fire time found within 0 candidates or 60s",
+            error.to_string()
+        );
+    }
+
+    #[test]
+    fn test_search_from_negative_years() {
+        let schedule = Schedule::from_str("@yearly").unwrap();
+        let before_year_zero = date(-5, 6, 1)
+            .at(0, 0, 0, 0)
+            .to_zoned(TimeZone::UTC)
+            .unwrap();
+        let next = schedule.after(&before_year_zero).next().unwrap();
+        assert_eq!(date(1970, 1, 1), next.date());
+        assert_eq!(None, schedule.after(&before_year_zero).next_back());
+    }
+
+    #[test]
+    fn test_fingerprint() {
+        let daily = Schedule::from_str("@daily").unwrap();
+        assert_eq!(
+            daily.fingerprint(),
+            Schedule::from_str("0 0 0 * * * *").unwrap().fingerprint()
+        );
+        assert_ne!(
+            daily.fingerprint(),
+            Schedule::from_str("0 0 1 * * *").unwrap().fingerprint()
+        );
+        assert_ne!(
+            daily.fingerprint(),
+            daily.clone().with_unbounded_years().fingerprint()
+        );
+        assert_eq!(
+            daily
+                .clone()
+                .with_year_range(2030..=2040)
+                .unwrap()
+                .fingerprint(),
+            Schedule::from_str("0 0 0 * * * 2030-2040")
+                .unwrap()
+                .fingerprint()
+        );
+    }
+
     #[test]
     fn test_no_panic_on_nonexistent_time_after() {
         let schedule_tz: TimeZone = TimeZone::get("Europe/London").unwrap();
@@ -887,6 +3086,290 @@ mod test {
         assert!(next > dt); // test is ensuring line above does not panic
     }
 
+    #[test]
+    fn test_times_on_dst_transitions() {
+        let time_zone = TimeZone::get("Europe/London").unwrap();
+        let schedule = Schedule::from_str("0 30 0-3 * * *").unwrap();
+
+        // 01:30 doesn't exist when the clocks go forward, and is shifted to 02:30.
+        let spring: Vec<_> = schedule
+            .times_on(date(2019, 3, 31), &time_zone)
+            .iter()
+            .map(|time| time.hour())
+            .collect();
+        assert_eq!(vec![0, 2, 3], spring);
+
+        // 01:30 is ambiguous when the clocks go back, and the earlier time is used.
+        let autumn = schedule.times_on(date(2019, 10, 27), &time_zone);
+        assert_eq!(4, autumn.len());
+        assert!(autumn.windows(2).all(|pair| pair[0] < pair[1]));
+    }
+
+    #[test]
+    fn test_owned_iterator_is_send_sync_static_clone() {
+        fn assert_spawnable<T: Send + Sync + Clone + 'static>(_: &T) {}
+
+        let schedule = Schedule::from_str("0 0 9 * * Mon-Fri").unwrap();
+        let after = date(2025, 1, 3)
+            .at(12, 0, 0, 0)
+            .to_zoned(TimeZone::UTC)
+            .unwrap();
+        let mut iterator = schedule.after_owned(after);
+        assert_spawnable(&iterator);
+
+        let mut fork = iterator.clone();
+        let ahead: Vec<_> = fork.by_ref().take(3).collect();
+        assert_eq!(ahead[0], iterator.next().unwrap());
+        assert_eq!(ahead[2].date(), date(2025, 1, 8));
+        assert_eq!(fork.cursor().position(), Some(&ahead[2]));
+    }
+
+    #[test]
+    fn test_surrounding() {
+        let schedule = Schedule::from_str("0 30 9 * * Mon-Fri").unwrap();
+        let at = |day, hour, minute| {
+            date(2025, 1, day)
+                .at(hour, minute, 0, 0)
+                .to_zoned(TimeZone::UTC)
+                .unwrap()
+        };
+        assert_eq!(
+            (Some(at(3, 9, 30)), Some(at(6, 9, 30))),
+            schedule.surrounding(&at(4, 12, 0))
+        );
+        assert_eq!(
+            (Some(at(6, 9, 30)), Some(at(7, 9, 30))),
+            schedule.surrounding(&at(6, 9, 30))
+        );
+
+        let schedule = Schedule::from_str("0 0 0 1 1 * 2020").unwrap();
+        let fire = date(2020, 1, 1)
+            .at(0, 0, 0, 0)
+            .to_zoned(TimeZone::UTC)
+            .unwrap();
+        assert_eq!(
+            (Some(fire.clone()), None),
+            schedule.surrounding(&at(4, 12, 0))
+        );
+        assert_eq!(
+            (None, Some(fire)),
+            schedule.surrounding(&Timestamp::MIN.to_zoned(TimeZone::UTC))
+        );
+    }
+
+    #[test]
+    fn test_matches_within() {
+        let schedule = Schedule::from_str("0 0,10 9 * * *").unwrap();
+        let at = |minute, second| {
+            date(2025, 1, 1)
+                .at(9, minute, second, 0)
+                .to_zoned(TimeZone::UTC)
+                .unwrap()
+        };
+        let tolerance = Span::new().minutes(5);
+        assert_eq!(
+            Some(at(0, 0)),
+            schedule.matches_within(&at(0, 0), tolerance)
+        );
+        assert_eq!(
+            Some(at(0, 0)),
+            schedule.matches_within(&at(5, 0), tolerance)
+        );
+        assert_eq!(
+            Some(at(10, 0)),
+            schedule.matches_within(&at(5, 1), tolerance)
+        );
+        assert_eq!(
+            Some(at(10, 0)),
+            schedule.matches_within(&at(15, 0), -tolerance)
+        );
+        assert_eq!(None, schedule.matches_within(&at(15, 1), tolerance));
+        assert_eq!(None, schedule.matches_within(&at(4, 0), Span::new()));
+    }
+
+    #[test]
+    fn test_deadline_across_dst_transition() {
+        let time_zone = TimeZone::get("Europe/London").unwrap();
+        let schedule = Schedule::from_str("0 30 1 * * *").unwrap();
+        let last_ping = date(2019, 3, 30)
+            .at(1, 30, 0, 0)
+            .to_zoned(time_zone.clone())
+            .unwrap();
+        // 01:30 doesn't exist on the 31st, so the job fires at 02:30 BST.
+        let grace = Span::new().minutes(10);
+        let deadline = schedule.deadline_after(&last_ping, grace).unwrap();
+        assert_eq!(date(2019, 3, 31).at(2, 40, 0, 0), deadline.datetime());
+        assert!(!schedule.is_overdue(&last_ping, &deadline, grace));
+
+        let schedule = Schedule::from_str("0 30 1 1 1 * 2019").unwrap();
+        assert_eq!(None, schedule.deadline_after(&last_ping, grace));
+        assert!(!schedule.is_overdue(&last_ping, &Timestamp::MAX.to_zoned(time_zone), grace));
+    }
+
+    #[test]
+    fn test_min_interval() {
+        let interval = |expression| {
+            Schedule::from_str(expression)
+                .unwrap()
+                .min_interval()
+                .map(|interval| interval.as_secs())
+        };
+        assert_eq!(Some(1), interval("* * * * * *"));
+        assert_eq!(Some(15), interval("0,15,30 * * * * *"));
+        // From 59 seconds past one minute to the next minute.
+        assert_eq!(Some(1), interval("0,59 * * * * *"));
+        assert_eq!(Some(20), interval("10,30,50 0,1 * * * *"));
+        // From 00:59:30 to 01:00:00.
+        assert_eq!(Some(30), interval("0,30 0,59 * * * *"));
+        assert_eq!(Some(3_600), interval("0 0 9-17 * * Mon-Fri"));
+        assert_eq!(Some(8 * 3_600), interval("0 0 9,17 * * Mon-Fri"));
+        assert_eq!(Some(7 * 86_400), interval("0 0 0 * * Mon"));
+        // February is the shortest month.
+        assert_eq!(Some(28 * 86_400), interval("0 0 0 1 * *"));
+        assert_eq!(Some(365 * 86_400), interval("0 0 0 1 1 *"));
+        assert_eq!(None, interval("0 0 0 1 1 * 2030"));
+        assert_eq!(None, interval("0 0 0 31 2 *"));
+    }
+
+    #[test]
+    fn test_phase() {
+        let schedule = Schedule::from_str("0 0 0 * * *").unwrap();
+        let midnight = date(2025, 1, 2)
+            .at(0, 0, 0, 0)
+            .to_zoned(TimeZone::UTC)
+            .unwrap();
+        let phase = schedule.phase(&midnight).unwrap();
+        assert_eq!(&midnight, phase.previous());
+        assert_eq!(0.0, phase.fraction());
+        assert_eq!(SignedDuration::from_hours(24), phase.remaining());
+
+        // The day the clocks go forward is an hour shorter.
+        let time_zone = TimeZone::get("Europe/London").unwrap();
+        let noon = date(2019, 3, 31)
+            .at(12, 0, 0, 0)
+            .to_zoned(time_zone)
+            .unwrap();
+        let phase = schedule.phase(&noon).unwrap();
+        assert_eq!(SignedDuration::from_hours(11), phase.elapsed());
+        assert_eq!(11.0 / 23.0, phase.fraction());
+
+        let schedule = Schedule::from_str("0 0 0 1 1 * 2020").unwrap();
+        assert_eq!(None, schedule.phase(&midnight));
+    }
+
+    #[test]
+    fn test_snapshot_next_across_dst_transition() {
+        let time_zone = TimeZone::get("Europe/London").unwrap();
+        let schedule = Schedule::from_str("0 30 1 * * *").unwrap();
+        let from: Timestamp = "2019-10-26T12:00:00Z".parse().unwrap();
+        assert_eq!(
+            vec![
+                "2019-10-27T01:30:00+01:00",
+                "2019-10-28T01:30:00+00:00",
+                "2019-10-29T01:30:00+00:00",
+            ],
+            schedule.snapshot_next(3, from, &time_zone)
+        );
+        assert_eq!(
+            vec!["2019-10-27 01:30 BST", "2019-10-28 01:30 GMT"],
+            schedule.snapshot_next_with(2, from, &time_zone, "%F %R %Z")
+        );
+        assert!(schedule.snapshot_next(0, from, &time_zone).is_empty());
+    }
+
+    #[test]
+    fn test_dates_in_month() {
+        let schedule = Schedule::from_str("0 0 12 29-31 * *").unwrap();
+        let days = |month| {
+            schedule
+                .dates_in_month(2024, month, &TimeZone::UTC)
+                .iter()
+                .map(|date| date.day())
+                .collect::<Vec<_>>()
+        };
+        assert_eq!(vec![29, 30, 31], days(1));
+        assert_eq!(vec![29], days(2));
+        assert_eq!(vec![29, 30], days(4));
+        assert!(days(0).is_empty());
+
+        let schedule = Schedule::from_str("0 0 12 * * Sat,Sun 2025").unwrap();
+        assert_eq!(10, schedule.dates_in_month(2025, 3, &TimeZone::UTC).len());
+        assert!(schedule.dates_in_month(2026, 3, &TimeZone::UTC).is_empty());
+    }
+
+    #[test]
+    fn test_unbounded_years_end_at_jiff_maximum() {
+        let schedule = Schedule::from_str("0 0 0 * * *")
+            .unwrap()
+            .with_unbounded_years();
+        let after = date(9999, 12, 28)
+            .at(12, 0, 0, 0)
+            .to_zoned(TimeZone::UTC)
+            .unwrap();
+        let dates: Vec<_> = schedule.after(&after).map(|next| next.day()).collect();
+        assert_eq!(vec![29, 30], dates);
+
+        let bounded = Schedule::from_str("0 0 0 * * * 2020-2030").unwrap();
+        assert!(bounded.clone().with_unbounded_years().is_year_bounded());
+        assert_eq!(Some(2030), bounded.year_bound());
+    }
+
+    #[test]
+    fn test_earliest_and_latest() {
+        let schedule = Schedule::from_str("0 0 0 1 Jan * 2030,2040").unwrap();
+        let tokyo = TimeZone::get("Asia/Tokyo").unwrap();
+        let earliest = schedule.earliest(&tokyo).unwrap();
+        assert_eq!(date(2030, 1, 1).to_zoned(tokyo.clone()).unwrap(), earliest);
+        let latest = schedule.latest(&tokyo).unwrap();
+        assert_eq!(date(2040, 1, 1).to_zoned(tokyo).unwrap(), latest);
+
+        let never = Schedule::from_str("0 0 0 30 Feb *").unwrap();
+        assert_eq!(None, never.earliest(&TimeZone::UTC));
+        assert_eq!(None, never.latest(&TimeZone::UTC));
+        let last = Schedule::from_str("59 59 23 31 Dec *").unwrap();
+        let latest = last.latest(&TimeZone::UTC).unwrap();
+        assert_eq!(date(2100, 12, 31).at(23, 59, 59, 0), latest.datetime());
+    }
+
+    #[test]
+    fn test_with_year_range() {
+        let schedule = Schedule::from_str("0 0 0 1 1 * 2020/2").unwrap();
+        let restricted = schedule.clone().with_year_range(2023..2030).unwrap();
+        assert!(restricted.years().iter().eq([2024, 2026, 2028]));
+        assert_eq!(restricted, Schedule::from_str(restricted.source()).unwrap());
+
+        let after = date(2030, 1, 1)
+            .at(0, 0, 0, 0)
+            .to_zoned(TimeZone::UTC)
+            .unwrap();
+        assert!(schedule.after(&after).next().is_some());
+        assert!(restricted.after(&after).next().is_none());
+
+        let unbounded = Schedule::from_str("@yearly")
+            .unwrap()
+            .with_unbounded_years();
+        let bounded = unbounded.clone().with_year_range(2090..=2100).unwrap();
+        assert!(bounded.is_year_bounded());
+        assert_eq!(11, bounded.years().count());
+        assert_eq!(bounded, Schedule::from_str(bounded.source()).unwrap());
+
+        // Neither years past 2100 nor no years at all can be written out.
+        let error = unbounded.with_year_range(2200..=2201).unwrap_err();
+        assert_eq!(
+            &ErrorKind::InvalidOrdinal {
+                unit: TimeUnit::Years,
+                value: 2201,
+                min: 1970,
+                max: 2100
+            },
+            error.kind()
+        );
+        assert_eq!(
+            "JC0001",
+            schedule.with_year_range(2021..2022).unwrap_err().code()
+        );
+    }
+
     #[test]
     fn test_time_unit_spec_equality() {
         // Every week
@@ -0,0 +1,357 @@
+//! Modules for `#[serde(with = "...")]` attributes on fields holding
+//! schedules, whose errors name the expression which failed to parse and,
+//! within collections, where it is.
+//!
+//! This requires the `serde` feature.
+//!
+//! # Example
+//!
+//! ```rust
+//! use std::collections::HashMap;
+//!
+//! use jiff_cron::Schedule;
+//! use serde::{
+//!     de::value::{Error, SeqDeserializer},
+//!     Deserialize,
+//! };
+//!
+//! #[derive(Deserialize)]
+//! struct Config {
+//!     #[serde(with = "jiff_cron::serde::option_schedule", default)]
+//!     cleanup: Option<Schedule>,
+//!     #[serde(with = "jiff_cron::serde::map_schedule")]
+//!     jobs: HashMap<String, Schedule>,
+//! }
+//!
+//! let expressions = SeqDeserializer::<_, Error>::new(["@daily", "0 0 25 * * *"].into_iter());
+//! let error = jiff_cron::serde::vec_schedule::deserialize(expressions).unwrap_err();
+//! assert!(error
+//!     .to_string()
+//!     .starts_with("`0 0 25 * * *` at index 1: Invalid expression"));
+//! ```
+
+use std::{
+    collections::HashMap,
+    fmt::{self, Display},
+    hash::Hash,
+    marker::PhantomData,
+};
+
+use ::serde::{
+    de::{self, Deserialize, Deserializer, MapAccess, SeqAccess, Visitor},
+    Serialize, Serializer,
+};
+
+use crate::schedule::Schedule;
+
+fn parse<E>(expression: &str, location: Option<&dyn Display>) -> Result<Schedule, E>
+where
+    E: de::Error,
+{
+    Schedule::try_from(expression).map_err(|error| match location {
+        Some(location) => E::custom(format_args!("`{}` {}: {}", expression, location, error)),
+        None => E::custom(format_args!("`{}`: {}", expression, error)),
+    })
+}
+
+struct ExpressionVisitor;
+
+impl Visitor<'_> for ExpressionVisitor {
+    type Value = Schedule;
+
+    fn expecting(&self, formatter: &mut fmt::Formatter<'_>) -> fmt::Result {
+        formatter.write_str("a valid cron expression")
+    }
+
+    fn visit_str<E>(self, v: &str) -> Result<Schedule, E>
+    where
+        E: de::Error,
+    {
+        parse(v, None)
+    }
+}
+
+// A schedule deserialized by `schedule::deserialize`, for the other modules.
+struct Expression(Schedule);
+
+impl<'de> Deserialize<'de> for Expression {
+    fn deserialize<D>(deserializer: D) -> Result<Expression, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        schedule::deserialize(deserializer).map(Expression)
+    }
+}
+
+// An expression deserialized without parsing it, so that collections can
+// report where it is.
+struct Source(String);
+
+impl<'de> Deserialize<'de> for Source {
+    fn deserialize<D>(deserializer: D) -> Result<Source, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        String::deserialize(deserializer).map(Source)
+    }
+}
+
+/// For fields of type [`Schedule`].
+pub mod schedule {
+    use super::*;
+
+    /// Serializes a schedule as its source expression.
+    pub fn serialize<S>(schedule: &Schedule, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        schedule.serialize(serializer)
+    }
+
+    /// Deserializes a schedule from a cron expression.
+    pub fn deserialize<'de, D>(deserializer: D) -> Result<Schedule, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        deserializer.deserialize_str(ExpressionVisitor)
+    }
+}
+
+/// For fields of type `Option<Schedule>`, which are `None` if they're null
+/// and, with `#[serde(default)]`, if they're missing.
+pub mod option_schedule {
+    use super::*;
+
+    /// Serializes a schedule as its source expression, or `None` as null.
+    pub fn serialize<S>(schedule: &Option<Schedule>, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        schedule.serialize(serializer)
+    }
+
+    /// Deserializes a schedule from a cron expression, or `None` from null.
+    pub fn deserialize<'de, D>(deserializer: D) -> Result<Option<Schedule>, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        Option::<Expression>::deserialize(deserializer)
+            .map(|expression| expression.map(|Expression(schedule)| schedule))
+    }
+}
+
+/// For fields of type `Vec<Schedule>`, whose errors give the index of the
+/// expression which failed to parse.
+pub mod vec_schedule {
+    use super::*;
+
+    struct SeqVisitor;
+
+    impl<'de> Visitor<'de> for SeqVisitor {
+        type Value = Vec<Schedule>;
+
+        fn expecting(&self, formatter: &mut fmt::Formatter<'_>) -> fmt::Result {
+            formatter.write_str("a sequence of valid cron expressions")
+        }
+
+        fn visit_seq<A>(self, mut seq: A) -> Result<Vec<Schedule>, A::Error>
+        where
+            A: SeqAccess<'de>,
+        {
+            let mut schedules = Vec::with_capacity(seq.size_hint().unwrap_or(0).min(64));
+            while let Some(Source(expression)) = seq.next_element()? {
+                let location = format_args!("at index {}", schedules.len());
+                schedules.push(parse(&expression, Some(&location))?);
+            }
+            Ok(schedules)
+        }
+    }
+
+    /// Serializes schedules as a sequence of their source expressions.
+    pub fn serialize<S>(schedules: &[Schedule], serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        serializer.collect_seq(schedules)
+    }
+
+    /// Deserializes schedules from a sequence of cron expressions.
+    pub fn deserialize<'de, D>(deserializer: D) -> Result<Vec<Schedule>, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        deserializer.deserialize_seq(SeqVisitor)
+    }
+}
+
+/// For fields of type `HashMap<K, Schedule>`, such as schedules by job name,
+/// whose errors give the key of the expression which failed to parse.
+pub mod map_schedule {
+    use super::*;
+
+    struct MapVisitor<K>(PhantomData<K>);
+
+    impl<'de, K> Visitor<'de> for MapVisitor<K>
+    where
+        K: Deserialize<'de> + Display + Eq + Hash,
+    {
+        type Value = HashMap<K, Schedule>;
+
+        fn expecting(&self, formatter: &mut fmt::Formatter<'_>) -> fmt::Result {
+            formatter.write_str("a map of valid cron expressions")
+        }
+
+        fn visit_map<A>(self, mut map: A) -> Result<HashMap<K, Schedule>, A::Error>
+        where
+            A: MapAccess<'de>,
+        {
+            let mut schedules = HashMap::with_capacity(map.size_hint().unwrap_or(0).min(64));
+            while let Some((key, Source(expression))) = map.next_entry::<K, Source>()? {
+                let location = format_args!("for key `{}`", key);
+                let schedule = parse(&expression, Some(&location))?;
+                schedules.insert(key, schedule);
+            }
+            Ok(schedules)
+        }
+    }
+
+    /// Serializes schedules as a map of their source expressions.
+    pub fn serialize<K, S>(
+        schedules: &HashMap<K, Schedule>,
+        serializer: S,
+    ) -> Result<S::Ok, S::Error>
+    where
+        K: Serialize,
+        S: Serializer,
+    {
+        serializer.collect_map(schedules)
+    }
+
+    /// Deserializes schedules from a map of cron expressions.
+    pub fn deserialize<'de, K, D>(deserializer: D) -> Result<HashMap<K, Schedule>, D::Error>
+    where
+        K: Deserialize<'de> + Display + Eq + Hash,
+        D: Deserializer<'de>,
+    {
+        deserializer.deserialize_map(MapVisitor(PhantomData))
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use serde_test::{assert_de_tokens_error, assert_tokens, Token};
+
+    use super::*;
+
+    #[derive(Debug, PartialEq, ::serde::Deserialize, ::serde::Serialize)]
+    struct Config {
+        #[serde(with = "schedule")]
+        backup: Schedule,
+        #[serde(with = "option_schedule", default)]
+        cleanup: Option<Schedule>,
+        #[serde(with = "vec_schedule")]
+        reports: Vec<Schedule>,
+    }
+
+    #[derive(Debug, PartialEq, ::serde::Deserialize, ::serde::Serialize)]
+    struct Jobs {
+        #[serde(with = "map_schedule")]
+        jobs: HashMap<String, Schedule>,
+    }
+
+    fn schedule(expression: &str) -> Schedule {
+        Schedule::try_from(expression).unwrap()
+    }
+
+    #[test]
+    fn test_config_tokens() {
+        let config = Config {
+            backup: schedule("@daily"),
+            cleanup: None,
+            reports: vec![schedule("@weekly"), schedule("0 0 9 1 * *")],
+        };
+        assert_tokens(
+            &config,
+            &[
+                Token::Struct {
+                    name: "Config",
+                    len: 3,
+                },
+                Token::Str("backup"),
+                Token::Str("@daily"),
+                Token::Str("cleanup"),
+                Token::None,
+                Token::Str("reports"),
+                Token::Seq { len: Some(2) },
+                Token::Str("@weekly"),
+                Token::Str("0 0 9 1 * *"),
+                Token::SeqEnd,
+                Token::StructEnd,
+            ],
+        );
+
+        let jobs = Jobs {
+            jobs: HashMap::from([("backup".to_owned(), schedule("@daily"))]),
+        };
+        assert_tokens(
+            &jobs,
+            &[
+                Token::Struct {
+                    name: "Jobs",
+                    len: 1,
+                },
+                Token::Str("jobs"),
+                Token::Map { len: Some(1) },
+                Token::Str("backup"),
+                Token::Str("@daily"),
+                Token::MapEnd,
+                Token::StructEnd,
+            ],
+        );
+    }
+
+    #[test]
+    fn test_errors_name_the_expression() {
+        assert_de_tokens_error::<Config>(
+            &[
+                Token::Struct {
+                    name: "Config",
+                    len: 2,
+                },
+                Token::Str("backup"),
+                Token::Str("0 0 25 * * *"),
+            ],
+            "`0 0 25 * * *`: Invalid expression: Hours must be less than 23. ('25' specified.)",
+        );
+        assert_de_tokens_error::<Config>(
+            &[
+                Token::Struct {
+                    name: "Config",
+                    len: 2,
+                },
+                Token::Str("backup"),
+                Token::Str("@daily"),
+                Token::Str("reports"),
+                Token::Seq { len: Some(2) },
+                Token::Str("@weekly"),
+                Token::Str("0 0 9 32 * *"),
+            ],
+            "`0 0 9 32 * *` at index 1: Invalid expression: Days of Month must be less than 31. \
+             ('32' specified.)",
+        );
+        assert_de_tokens_error::<Jobs>(
+            &[
+                Token::Struct {
+                    name: "Jobs",
+                    len: 1,
+                },
+                Token::Str("jobs"),
+                Token::Map { len: Some(1) },
+                Token::Str("backup"),
+                Token::Str("0 0 0 * * Mun"),
+            ],
+            "`0 0 0 * * Mun` for key `backup`: Invalid expression: 'Mun' is not a valid name for \
+             Days of Week. Did you mean 'Mon'?",
+        );
+    }
+}
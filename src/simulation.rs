@@ -0,0 +1,170 @@
+use std::collections::BTreeMap;
+
+use jiff::{SignedDuration, Span, Zoned};
+
+use crate::{adapters::FireTimes, dst::DstOccurrence, schedule::Schedule};
+
+/// A summary of a schedule's fire times over a horizon, returned by
+/// [`Schedule::simulate`].
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Simulation {
+    total: usize,
+    per_month: BTreeMap<(i16, i8), usize>,
+    first: Option<Zoned>,
+    last: Option<Zoned>,
+    shortest_gap: Option<SignedDuration>,
+    longest_gap: Option<SignedDuration>,
+    dst_affected: Vec<DstOccurrence>,
+}
+
+impl Simulation {
+    pub(crate) fn run(schedule: &Schedule, from: &Zoned, horizon: Span) -> Simulation {
+        let mut simulation = Simulation {
+            total: 0,
+            per_month: BTreeMap::new(),
+            first: None,
+            last: None,
+            shortest_gap: None,
+            longest_gap: None,
+            dst_affected: Vec::new(),
+        };
+        let Ok(end) = from.checked_add(horizon) else {
+            return simulation;
+        };
+        for fire in schedule.after(from).take_until(&end) {
+            simulation.total += 1;
+            *simulation
+                .per_month
+                .entry((fire.year(), fire.month()))
+                .or_default() += 1;
+            if let Some(last) = &simulation.last {
+                let gap = fire.duration_since(last);
+                simulation.shortest_gap = Some(
+                    simulation
+                        .shortest_gap
+                        .map_or(gap, |shortest| shortest.min(gap)),
+                );
+                simulation.longest_gap = Some(
+                    simulation
+                        .longest_gap
+                        .map_or(gap, |longest| longest.max(gap)),
+                );
+            }
+            if simulation.first.is_none() {
+                simulation.first = Some(fire.clone());
+            }
+            simulation.last = Some(fire);
+        }
+        for year in from.year()..=end.year() {
+            simulation.dst_affected.extend(
+                schedule
+                    .dst_audit(from.time_zone(), year)
+                    .into_iter()
+                    .filter(|occurrence| *occurrence.fire() > *from && *occurrence.fire() < end),
+            );
+        }
+        simulation
+    }
+
+    /// Returns how many times the schedule fires.
+    pub fn total(&self) -> usize {
+        self.total
+    }
+
+    /// Returns how many times the schedule fires in each month it fires in,
+    /// keyed by year and month.
+    pub fn per_month(&self) -> &BTreeMap<(i16, i8), usize> {
+        &self.per_month
+    }
+
+    /// Returns the first fire time.
+    pub fn first(&self) -> Option<&Zoned> {
+        self.first.as_ref()
+    }
+
+    /// Returns the last fire time.
+    pub fn last(&self) -> Option<&Zoned> {
+        self.last.as_ref()
+    }
+
+    /// Returns the shortest time between consecutive fire times, if the
+    /// schedule fires at least twice.
+    pub fn shortest_gap(&self) -> Option<SignedDuration> {
+        self.shortest_gap
+    }
+
+    /// Returns the longest time between consecutive fire times, if the
+    /// schedule fires at least twice.
+    pub fn longest_gap(&self) -> Option<SignedDuration> {
+        self.longest_gap
+    }
+
+    /// Returns the fire times affected by DST transitions, see
+    /// [`Schedule::dst_audit`].
+    pub fn dst_affected(&self) -> &[DstOccurrence] {
+        &self.dst_affected
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use std::str::FromStr;
+
+    use jiff::{civil::date, tz::TimeZone, ToSpan};
+
+    use super::*;
+
+    #[test]
+    fn test_simulate() {
+        let time_zone = TimeZone::get("Europe/Berlin").unwrap();
+        let schedule = Schedule::from_str("0 30 2 * * Mon-Fri").unwrap();
+        let from = date(2025, 1, 1).to_zoned(time_zone).unwrap();
+        let simulation = schedule.simulate(&from, 1.year());
+
+        assert_eq!(261, simulation.total());
+        assert_eq!(12, simulation.per_month().len());
+        assert_eq!(Some(&23), simulation.per_month().get(&(2025, 1)));
+        assert_eq!(
+            simulation.total(),
+            simulation.per_month().values().sum::<usize>()
+        );
+        assert_eq!(
+            date(2025, 1, 1).at(2, 30, 0, 0),
+            simulation.first().unwrap().datetime()
+        );
+        assert_eq!(
+            date(2025, 12, 31).at(2, 30, 0, 0),
+            simulation.last().unwrap().datetime()
+        );
+        assert_eq!(
+            Some(SignedDuration::from_hours(24)),
+            simulation.shortest_gap()
+        );
+        // The weekend the clocks go back lasts an hour longer.
+        assert_eq!(
+            Some(SignedDuration::from_hours(73)),
+            simulation.longest_gap()
+        );
+        // 02:30 is skipped on March 30th and repeated on October 26th, both
+        // Sundays.
+        assert!(simulation.dst_affected().is_empty());
+
+        let schedule = Schedule::from_str("0 30 2 * * *").unwrap();
+        let simulation = schedule.simulate(&from, 1.year());
+        assert_eq!(365, simulation.total());
+        assert_eq!(2, simulation.dst_affected().len());
+        assert_eq!(
+            Some(SignedDuration::from_hours(23)),
+            simulation.shortest_gap()
+        );
+        assert_eq!(
+            Some(SignedDuration::from_hours(25)),
+            simulation.longest_gap()
+        );
+
+        let schedule = Schedule::from_str("0 0 0 1 1 * 2030").unwrap();
+        let simulation = schedule.simulate(&from, 1.year());
+        assert_eq!(0, simulation.total());
+        assert!(simulation.first().is_none() && simulation.shortest_gap().is_none());
+    }
+}
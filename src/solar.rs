@@ -0,0 +1,330 @@
+use jiff::{civil::Date, tz::TimeZone, Span, Timestamp, Zoned};
+
+// Days without a sunrise or sunset are skipped, but past the polar circles
+// the sun may not rise or set for months, so searching stops after more than
+// a year without one.
+const MAX_DAYS_WITHOUT_EVENT: usize = 370;
+
+// The Julian day of the Unix epoch, and of the J2000 epoch at noon.
+const UNIX_EPOCH_JULIAN_DAY: f64 = 2_440_587.5;
+const J2000: f64 = 2_451_545.0;
+
+/// The crossing of the horizon by the sun that a [`SolarSchedule`] fires at.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum SolarEvent {
+    /// The upper edge of the sun appearing in the morning.
+    Sunrise,
+    /// The upper edge of the sun disappearing in the evening.
+    Sunset,
+}
+
+/// A schedule firing at sunrise or sunset each day at a location, optionally
+/// shifted by an offset, such as 30 minutes before sunset.
+///
+/// Fire times are computed with the sunrise equation, which is accurate to
+/// within a minute or two away from the polar circles. Days on which the sun
+/// doesn't rise or set, such as during polar night, are skipped.
+///
+/// This requires the `solar` feature.
+///
+/// # Example
+///
+/// ```rust
+/// use jiff_cron::{
+///     jiff::{civil::date, tz::TimeZone, ToSpan},
+///     SolarEvent, SolarSchedule,
+/// };
+///
+/// // Half an hour before sunset in London.
+/// let schedule =
+///     SolarSchedule::new(SolarEvent::Sunset, 51.5072, -0.1276).with_offset((-30).minutes());
+/// let time_zone = TimeZone::get("Europe/London").unwrap();
+/// let now = date(2024, 6, 21)
+///     .at(12, 0, 0, 0)
+///     .to_zoned(time_zone)
+///     .unwrap();
+/// let next = schedule.after(&now).next().unwrap();
+/// assert_eq!(date(2024, 6, 21), next.date());
+/// assert_eq!(20, next.hour());
+/// assert!((49..=53).contains(&next.minute()));
+/// ```
+#[derive(Clone, Debug)]
+pub struct SolarSchedule {
+    event: SolarEvent,
+    latitude: f64,
+    longitude: f64,
+    offset: Span,
+}
+
+impl SolarSchedule {
+    /// Creates a schedule firing at `event` each day at the location given by
+    /// `latitude` and `longitude` in degrees, north and east being positive.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `latitude` isn't within -90 to 90 degrees, or `longitude`
+    /// isn't within -180 to 180 degrees.
+    pub fn new(event: SolarEvent, latitude: f64, longitude: f64) -> SolarSchedule {
+        assert!(
+            (-90.0..=90.0).contains(&latitude),
+            "latitude must be within -90 to 90 degrees"
+        );
+        assert!(
+            (-180.0..=180.0).contains(&longitude),
+            "longitude must be within -180 to 180 degrees"
+        );
+        SolarSchedule {
+            event,
+            latitude,
+            longitude,
+            offset: Span::new(),
+        }
+    }
+
+    /// Shifts each fire time by `offset`, which is negative to fire before
+    /// the event. The offset is added in the time zone of the fire times.
+    pub fn with_offset(mut self, offset: Span) -> SolarSchedule {
+        self.offset = offset;
+        self
+    }
+
+    /// Returns the event the schedule fires at.
+    pub fn event(&self) -> SolarEvent {
+        self.event
+    }
+
+    /// Returns the latitude of the location in degrees.
+    pub fn latitude(&self) -> f64 {
+        self.latitude
+    }
+
+    /// Returns the longitude of the location in degrees.
+    pub fn longitude(&self) -> f64 {
+        self.longitude
+    }
+
+    /// Returns the offset applied to each fire time.
+    pub fn offset(&self) -> Span {
+        self.offset
+    }
+
+    /// Returns an iterator over the fire times after `after`, in its time
+    /// zone.
+    pub fn after(&self, after: &Zoned) -> SolarIterator<'_> {
+        // An event on an earlier day may still fire after `after` once it's
+        // offset.
+        let start = after
+            .checked_sub(self.offset.abs())
+            .unwrap_or_else(|_| after.clone())
+            .date();
+        SolarIterator {
+            schedule: self,
+            date: start.yesterday().ok(),
+            previous: after.clone(),
+        }
+    }
+
+    /// Like [`SolarSchedule::after`], starting from the current time.
+    pub fn upcoming(&self, time_zone: TimeZone) -> SolarIterator<'_> {
+        self.after(&Zoned::now().with_time_zone(time_zone))
+    }
+
+    /// Returns the fire time for the event on `date` in `time_zone`, if the
+    /// event happens on that day.
+    pub fn on(&self, date: Date, time_zone: &TimeZone) -> Option<Zoned> {
+        let timestamp = self.event_on(date, time_zone)?;
+        timestamp
+            .to_zoned(time_zone.clone())
+            .checked_add(self.offset)
+            .ok()
+    }
+
+    fn event_on(&self, date: Date, time_zone: &TimeZone) -> Option<Timestamp> {
+        // The solar day counted from UTC midnight may put the event on a
+        // neighbouring civil day far from the time zone's meridian, such as in
+        // Kiritimati, so the days either side are tried as well.
+        let midnight = date.to_zoned(TimeZone::UTC).ok()?.timestamp();
+        let julian_day = midnight.as_second() as f64 / 86_400.0 + UNIX_EPOCH_JULIAN_DAY;
+        let day = (julian_day - J2000 + 0.0008).ceil();
+        [day, day - 1.0, day + 1.0].into_iter().find_map(|day| {
+            let timestamp = self.event_in_solar_day(day)?;
+            (timestamp.to_zoned(time_zone.clone()).date() == date).then_some(timestamp)
+        })
+    }
+
+    // Solves the sunrise equation for the `day`th solar day since J2000.
+    fn event_in_solar_day(&self, day: f64) -> Option<Timestamp> {
+        let mean_solar_time = day - self.longitude / 360.0;
+        let mean_anomaly = (357.5291 + 0.985_600_28 * mean_solar_time).rem_euclid(360.0);
+        let m = mean_anomaly.to_radians();
+        let center = 1.9148 * m.sin() + 0.02 * (2.0 * m).sin() + 0.0003 * (3.0 * m).sin();
+        let ecliptic_longitude = (mean_anomaly + center + 180.0 + 102.9372)
+            .rem_euclid(360.0)
+            .to_radians();
+        let transit =
+            J2000 + mean_solar_time + 0.0053 * m.sin() - 0.0069 * (2.0 * ecliptic_longitude).sin();
+        let declination = (ecliptic_longitude.sin() * 23.4397_f64.to_radians().sin()).asin();
+
+        let latitude = self.latitude.to_radians();
+        let cos_hour_angle = ((-0.833_f64).to_radians().sin() - latitude.sin() * declination.sin())
+            / (latitude.cos() * declination.cos());
+        if !(-1.0..=1.0).contains(&cos_hour_angle) {
+            return None;
+        }
+        let hour_angle = cos_hour_angle.acos().to_degrees() / 360.0;
+        let julian_day = match self.event {
+            SolarEvent::Sunrise => transit - hour_angle,
+            SolarEvent::Sunset => transit + hour_angle,
+        };
+        let seconds = ((julian_day - UNIX_EPOCH_JULIAN_DAY) * 86_400.0).round() as i64;
+        Timestamp::from_second(seconds).ok()
+    }
+}
+
+/// An iterator over the fire times of a [`SolarSchedule`], returned by
+/// [`SolarSchedule::after`].
+#[derive(Clone, Debug)]
+pub struct SolarIterator<'a> {
+    schedule: &'a SolarSchedule,
+    date: Option<Date>,
+    previous: Zoned,
+}
+
+impl Iterator for SolarIterator<'_> {
+    type Item = Zoned;
+
+    fn next(&mut self) -> Option<Zoned> {
+        let time_zone = self.previous.time_zone().clone();
+        let mut days_without_event = 0;
+        while days_without_event <= MAX_DAYS_WITHOUT_EVENT {
+            let date = self.date?;
+            self.date = date.tomorrow().ok();
+            match self.schedule.on(date, &time_zone) {
+                Some(fire) if fire > self.previous => {
+                    self.previous = fire.clone();
+                    return Some(fire);
+                }
+                Some(_) => days_without_event = 0,
+                None => days_without_event += 1,
+            }
+        }
+        self.date = None;
+        None
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use jiff::{civil::date, ToSpan};
+
+    use super::*;
+
+    fn minutes_apart(a: &Zoned, b: &Zoned) -> i64 {
+        (a.timestamp().as_second() - b.timestamp().as_second()).abs() / 60
+    }
+
+    #[test]
+    fn test_sunrise_and_sunset() {
+        let time_zone = TimeZone::get("Europe/London").unwrap();
+        let midnight = date(2024, 6, 21).to_zoned(time_zone.clone()).unwrap();
+        let sunrise = SolarSchedule::new(SolarEvent::Sunrise, 51.5072, -0.1276);
+        let sunset = SolarSchedule::new(SolarEvent::Sunset, 51.5072, -0.1276);
+        let expected = date(2024, 6, 21)
+            .at(4, 43, 0, 0)
+            .to_zoned(time_zone.clone());
+        assert!(
+            minutes_apart(
+                &expected.unwrap(),
+                &sunrise.after(&midnight).next().unwrap()
+            ) <= 2
+        );
+        let expected = date(2024, 6, 21)
+            .at(21, 21, 0, 0)
+            .to_zoned(time_zone.clone());
+        assert!(minutes_apart(&expected.unwrap(), &sunset.after(&midnight).next().unwrap()) <= 2);
+
+        // Sydney in winter, far from the UTC date.
+        let time_zone = TimeZone::get("Australia/Sydney").unwrap();
+        let midnight = date(2024, 6, 21).to_zoned(time_zone.clone()).unwrap();
+        let sunrise = SolarSchedule::new(SolarEvent::Sunrise, -33.8688, 151.2093);
+        let expected = date(2024, 6, 21).at(7, 0, 0, 0).to_zoned(time_zone.clone());
+        assert!(
+            minutes_apart(
+                &expected.unwrap(),
+                &sunrise.after(&midnight).next().unwrap()
+            ) <= 2
+        );
+    }
+
+    #[test]
+    fn test_daily_and_ascending() {
+        let time_zone = TimeZone::get("America/New_York").unwrap();
+        let start = date(2024, 3, 1).to_zoned(time_zone).unwrap();
+        let schedule =
+            SolarSchedule::new(SolarEvent::Sunset, 40.7128, -74.006).with_offset((-30).minutes());
+        let fires: Vec<Zoned> = schedule.after(&start).take(60).collect();
+        assert!(fires.windows(2).all(|pair| pair[0] < pair[1]));
+        let mut date = start.date();
+        for fire in &fires {
+            assert_eq!(date, fire.date());
+            date = date.tomorrow().unwrap();
+        }
+        // The DST transition on March 10th shifts sunset an hour later.
+        assert_eq!(17, fires[8].hour());
+        assert_eq!(18, fires[9].hour());
+    }
+
+    #[test]
+    fn test_offset_past_midnight() {
+        let start = date(2024, 6, 21).at(0, 30, 0, 0).in_tz("UTC").unwrap();
+        let schedule = SolarSchedule::new(SolarEvent::Sunset, 0.0, 0.0).with_offset(6.hours());
+        // Sunset at the equator is just after 18:00, so this fires just after
+        // midnight, including the night `start` is in.
+        let first = schedule.after(&start).next().unwrap();
+        assert_eq!(date(2024, 6, 22), first.date());
+        let start = date(2024, 6, 21).at(0, 0, 0, 0).in_tz("UTC").unwrap();
+        let first = schedule.after(&start).next().unwrap();
+        assert_eq!(date(2024, 6, 21), first.date());
+    }
+
+    #[test]
+    fn test_polar_night() {
+        // Tromsø has no sunrise from late November to mid January.
+        let time_zone = TimeZone::get("Europe/Oslo").unwrap();
+        let start = date(2024, 12, 1).to_zoned(time_zone).unwrap();
+        let schedule = SolarSchedule::new(SolarEvent::Sunrise, 69.6492, 18.9553);
+        let first = schedule.after(&start).next().unwrap();
+        assert_eq!((2025, 1), (first.year(), first.month()));
+        assert!((13..=17).contains(&first.day()));
+
+        // Near the pole the sun doesn't set for months.
+        let start = date(2024, 6, 1).in_tz("UTC").unwrap();
+        let schedule = SolarSchedule::new(SolarEvent::Sunset, 80.0, 0.0);
+        let first = schedule.after(&start).next().unwrap();
+        assert_eq!(8, first.month());
+    }
+
+    #[test]
+    fn test_far_from_time_zone_meridian() {
+        // Kiritimati is at UTC+14 but far east of that meridian, and Apia at
+        // UTC+13, so their local days straddle two UTC days.
+        for (time_zone, latitude, longitude) in [
+            ("Pacific/Kiritimati", 1.87, -157.36),
+            ("Pacific/Apia", -13.83, -171.76),
+            ("UTC", 0.0, 170.0),
+        ] {
+            let time_zone = TimeZone::get(time_zone).unwrap();
+            let start = date(2024, 6, 1).to_zoned(time_zone).unwrap();
+            for event in [SolarEvent::Sunrise, SolarEvent::Sunset] {
+                let schedule = SolarSchedule::new(event, latitude, longitude);
+                let fires: Vec<Zoned> = schedule.after(&start).take(10).collect();
+                assert_eq!(10, fires.len());
+                let mut date = start.date();
+                for fire in &fires {
+                    assert_eq!(date, fire.date());
+                    date = date.tomorrow().unwrap();
+                }
+            }
+        }
+    }
+}
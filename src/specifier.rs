@@ -1,10 +1,18 @@
+use std::fmt::{self, Display, Formatter};
+
 use crate::ordinal::*;
 
+/// A single element of a field's list, such as `*`, `5`, `1-5` or `Mon-Fri`.
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum Specifier {
+    /// Every ordinal supported by the unit of time: `*` (or `?`).
     All,
+    /// A single ordinal, e.g. `5`.
     Point(Ordinal),
+    /// An inclusive range of ordinals, e.g. `1-5`.
     Range(Ordinal, Ordinal),
+    /// An inclusive range of named ordinals, e.g. `Mon-Fri`.
     NamedRange(String, String),
 }
 
@@ -14,14 +22,162 @@ pub enum Specifier {
 // - all: '*/2'
 // - range: '10-2/2'
 // - named range: 'Mon-Thurs/2'
+// - offset: '*/15+5'
 //
 // Without this separation we would end up with invalid combinations such as
 // 'Mon/2'
+/// An element of a field's comma-separated list, which may apply a step to a
+/// [`Specifier`]. Each element takes its own step, so `1-5/2,10,20-30/5` is
+/// parsed as three root specifiers.
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum RootSpecifier {
+    /// A plain [`Specifier`], e.g. `1-5`.
     Specifier(Specifier),
+    /// Every `n`th ordinal of a [`Specifier`], e.g. `*/15` or `1-5/2`. A
+    /// [`Specifier::Point`] implies a range running to the unit's maximum, so
+    /// `5/15` describes `5,20,35,50` for minutes.
     Period(Specifier, u32),
+    /// Every `n`th ordinal of a [`Specifier`], starting the given number of
+    /// ordinals after its first, e.g. `*/15+5`. This describes `5,20,35,50`
+    /// for minutes, and `*/7+1` describes `2,9,16,23,30` for days of the
+    /// month. The offset must be smaller than the step.
+    OffsetPeriod(Specifier, u32, u32),
+    /// A single named ordinal, e.g. `Mon`.
     NamedPoint(String),
+    /// The `n`th occurrence of a day of the week within each month, e.g.
+    /// `Mon#2` for the second Monday, counting back from the end of the
+    /// month if `n` is negative, e.g. `Fri#-2` for the second-to-last Friday.
+    /// The day of the week is a [`Specifier::Point`] or a
+    /// [`RootSpecifier::NamedPoint`], and `n` is from `1` to `5` or from `-5`
+    /// to `-1`. The last occurrence may also be written as in Quartz, e.g.
+    /// `FriL` or `6L` for the last Friday, as days of the week are numbered
+    /// from Sunday 1.
+    NthWeekday(Box<RootSpecifier>, i32),
+    /// The day `n` days before the last day of each month, e.g. `L` for the
+    /// last day and `L-3` for three days before it, which only the days of
+    /// the month support. `n` is less than `31`, and months with fewer than
+    /// `n + 1` days are skipped.
+    LastDay(u32),
+    /// The weekday, Monday to Friday, nearest to a day of each month, e.g.
+    /// `15W`, which only the days of the month support. A Saturday moves to
+    /// the Friday before and a Sunday to the Monday after, unless that falls
+    /// in another month, in which case the day moves the other way. Months
+    /// without the day are skipped.
+    NearestWeekday(Ordinal),
+    /// The last weekday, Monday to Friday, of each month, `LW`, which only
+    /// the days of the month support.
+    LastWeekday,
+    /// A value derived from a hash key, as in Jenkins, e.g. `H`, which may be
+    /// limited to a [`Specifier::Range`], e.g. `H(0-29)`, and stepped, e.g.
+    /// `H/15` for every 15 minutes from a hashed minute below 15. These are
+    /// only accepted once resolved with
+    /// [`ParseOptions::with_hash_key`](crate::ParseOptions::with_hash_key).
+    Hashed(Specifier, Option<u32>),
+}
+
+impl Specifier {
+    /// Creates a [`Specifier::Range`] from `start` to `end`, inclusively.
+    pub fn range(start: Ordinal, end: Ordinal) -> Specifier {
+        Specifier::Range(start, end)
+    }
+
+    /// Creates a [`Specifier::NamedRange`] from `start` to `end`, inclusively.
+    pub fn named_range(start: impl Into<String>, end: impl Into<String>) -> Specifier {
+        Specifier::NamedRange(start.into(), end.into())
+    }
+}
+
+impl RootSpecifier {
+    /// Creates a [`RootSpecifier::Period`] including every `step`th ordinal
+    /// of `specifier`.
+    pub fn period(specifier: Specifier, step: u32) -> RootSpecifier {
+        RootSpecifier::Period(specifier, step)
+    }
+
+    /// Creates a [`RootSpecifier::OffsetPeriod`] including every `step`th
+    /// ordinal of `specifier`, skipping its first `offset` ordinals.
+    pub fn offset_period(specifier: Specifier, step: u32, offset: u32) -> RootSpecifier {
+        RootSpecifier::OffsetPeriod(specifier, step, offset)
+    }
+
+    /// Creates a [`RootSpecifier::NamedPoint`] for `name`.
+    pub fn named_point(name: impl Into<String>) -> RootSpecifier {
+        RootSpecifier::NamedPoint(name.into())
+    }
+
+    /// Creates a [`RootSpecifier::NthWeekday`] for the `nth` occurrence of
+    /// `weekday` within each month.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use std::str::FromStr;
+    ///
+    /// use jiff_cron::{DaysOfWeek, RootSpecifier};
+    ///
+    /// let field = DaysOfWeek::from_specifiers(&[RootSpecifier::nth_weekday(
+    ///     RootSpecifier::named_point("Fri"),
+    ///     -2,
+    /// )])
+    /// .unwrap();
+    /// assert_eq!(DaysOfWeek::from_str("Fri#-2").unwrap(), field);
+    /// ```
+    pub fn nth_weekday(weekday: impl Into<RootSpecifier>, nth: i32) -> RootSpecifier {
+        RootSpecifier::NthWeekday(Box::new(weekday.into()), nth)
+    }
+
+    /// Returns true if the specifier describes a day depending on the layout
+    /// of its month rather than ordinals.
+    pub(crate) fn is_month_day(&self) -> bool {
+        matches!(
+            self,
+            RootSpecifier::NthWeekday(..)
+                | RootSpecifier::LastDay(_)
+                | RootSpecifier::NearestWeekday(_)
+                | RootSpecifier::LastWeekday
+        )
+    }
+}
+
+impl Display for Specifier {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        match self {
+            Specifier::All => f.write_str("*"),
+            Specifier::Point(ordinal) => write!(f, "{}", ordinal),
+            Specifier::Range(start, end) => write!(f, "{}-{}", start, end),
+            Specifier::NamedRange(start, end) => write!(f, "{}-{}", start, end),
+        }
+    }
+}
+
+/// Writes the specifier as it's parsed.
+impl Display for RootSpecifier {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        match self {
+            RootSpecifier::Specifier(specifier) => write!(f, "{}", specifier),
+            RootSpecifier::Period(specifier, step) => write!(f, "{}/{}", specifier, step),
+            RootSpecifier::OffsetPeriod(specifier, step, offset) => {
+                write!(f, "{}/{}+{}", specifier, step, offset)
+            }
+            RootSpecifier::NamedPoint(name) => f.write_str(name),
+            RootSpecifier::NthWeekday(weekday, nth) => write!(f, "{}#{}", weekday, nth),
+            RootSpecifier::LastDay(0) => f.write_str("L"),
+            RootSpecifier::LastDay(offset) => write!(f, "L-{}", offset),
+            RootSpecifier::NearestWeekday(day) => write!(f, "{}W", day),
+            RootSpecifier::LastWeekday => f.write_str("LW"),
+            RootSpecifier::Hashed(range, step) => {
+                f.write_str("H")?;
+                if let Specifier::Range(..) = range {
+                    write!(f, "({})", range)?;
+                }
+                match step {
+                    Some(step) => write!(f, "/{}", step),
+                    None => Ok(()),
+                }
+            }
+        }
+    }
 }
 
 impl From<Specifier> for RootSpecifier {
@@ -29,3 +185,9 @@ impl From<Specifier> for RootSpecifier {
         Self::Specifier(specifier)
     }
 }
+
+impl From<Ordinal> for RootSpecifier {
+    fn from(ordinal: Ordinal) -> Self {
+        Self::Specifier(Specifier::Point(ordinal))
+    }
+}
@@ -0,0 +1,236 @@
+use std::str::FromStr;
+
+use crate::{
+    error::{Error, ErrorKind},
+    schedule::Schedule,
+};
+
+// The shorthands systemd expands, as the calendar events they stand for.
+const SHORTHANDS: [(&str, &str); 9] = [
+    ("minutely", "*-*-* *:*:00"),
+    ("hourly", "*-*-* *:00:00"),
+    ("daily", "*-*-* 00:00:00"),
+    ("monthly", "*-*-01 00:00:00"),
+    ("weekly", "Mon *-*-* 00:00:00"),
+    ("yearly", "*-01-01 00:00:00"),
+    ("annually", "*-01-01 00:00:00"),
+    ("quarterly", "*-01,04,07,10-01 00:00:00"),
+    ("semiannually", "*-01,07-01 00:00:00"),
+];
+
+/// Parses a systemd calendar event, as in `OnCalendar=`, by rewriting it as
+/// a cron expression of the same fields.
+pub(crate) fn parse(expression: &str) -> Result<Schedule, Error> {
+    let cron = to_cron(expression).map_err(|message| invalid(expression, message))?;
+    // Errors of the fields are reported without spans, which would point
+    // into the rewritten expression.
+    Schedule::from_str(&cron).map_err(|error| invalid_kind(expression, error.kind().clone()))
+}
+
+fn to_cron(expression: &str) -> Result<String, String> {
+    let trimmed = expression.trim();
+    if trimmed.is_empty() {
+        return Err("The calendar event is empty".to_owned());
+    }
+    if let Some(&(_, event)) = SHORTHANDS
+        .iter()
+        .find(|(shorthand, _)| shorthand.eq_ignore_ascii_case(trimmed))
+    {
+        return to_cron(event);
+    }
+    let mut words = trimmed.split_whitespace().peekable();
+    let mut weekdays = "*".to_owned();
+    if let Some(word) = words.next_if(|word| word.starts_with(char::is_alphabetic)) {
+        weekdays = weekdays_component(word)?;
+    }
+    let (mut years, mut months, mut days) = ("*".to_owned(), "*".to_owned(), "*".to_owned());
+    if let Some(word) = words.next_if(|word| !word.contains(':')) {
+        (years, months, days) = date(word)?;
+    }
+    let (mut hours, mut minutes, mut seconds) = ("0".to_owned(), "0".to_owned(), "0".to_owned());
+    if let Some(word) = words.next_if(|word| word.contains(':')) {
+        (hours, minutes, seconds) = time(word)?;
+    }
+    if let Some(word) = words.next() {
+        return Err(format!(
+            "'{}' is not supported, as schedules take their time zone when iterated",
+            word
+        ));
+    }
+    let mut cron = format!(
+        "{} {} {} {} {} {}",
+        seconds, minutes, hours, days, months, weekdays
+    );
+    if years != "*" {
+        cron.push(' ');
+        cron.push_str(&years);
+    }
+    Ok(cron)
+}
+
+// `[year-]month-day`, where `~` in place of the last `-` counts the day from
+// the end of the month, e.g. `*-02~03` for the third-to-last day of February.
+fn date(word: &str) -> Result<(String, String, String), String> {
+    let (word, from_end) = match word.split_once('~') {
+        Some((word, day)) => (word, Some(day)),
+        None => (word, None),
+    };
+    let mut parts: Vec<&str> = word.split('-').collect();
+    let day = match from_end {
+        Some(day) => {
+            let day: u32 = day
+                .parse()
+                .ok()
+                .filter(|day| (1..=31).contains(day))
+                .ok_or_else(|| format!("'~{}' must be a single day from 1 to 31", day))?;
+            match day {
+                1 => "L".to_owned(),
+                day => format!("L-{}", day - 1),
+            }
+        }
+        None => component(parts.pop().unwrap_or_default())?,
+    };
+    let (years, months) = match parts[..] {
+        [years, months] => (component(years)?, component(months)?),
+        [months] => ("*".to_owned(), component(months)?),
+        _ => return Err(format!("'{}' is not a valid date", word)),
+    };
+    Ok((years, months, day))
+}
+
+// `hour:minute[:second]`, where seconds default to `00`.
+fn time(word: &str) -> Result<(String, String, String), String> {
+    match word.split(':').collect::<Vec<_>>()[..] {
+        [hours, minutes] => Ok((component(hours)?, component(minutes)?, "0".to_owned())),
+        [hours, minutes, seconds] if !seconds.contains('.') => {
+            Ok((component(hours)?, component(minutes)?, component(seconds)?))
+        }
+        _ => Err(format!("'{}' is not a valid time of whole seconds", word)),
+    }
+}
+
+// systemd weeks run from Monday to Sunday, while cron numbers Sunday first, so
+// a range up to Sunday, such as `Sat..Sun`, is split at Saturday.
+const WEEKDAYS: [&str; 7] = [
+    "Monday",
+    "Tuesday",
+    "Wednesday",
+    "Thursday",
+    "Friday",
+    "Saturday",
+    "Sunday",
+];
+
+// A list of weekdays and ranges of them, written in cron syntax.
+fn weekdays_component(text: &str) -> Result<String, String> {
+    let mut cron = Vec::new();
+    for item in text.split(',') {
+        let Some((start, end)) = item.split_once("..") else {
+            cron.push(WEEKDAYS[weekday(item)?][..3].to_owned());
+            continue;
+        };
+        let (start, end) = (weekday(start)?, weekday(end)?);
+        // A range from a later weekday to an earlier one wraps past Sunday.
+        let runs = match start <= end {
+            true => vec![(start, end)],
+            false => vec![(start, 6), (0, end)],
+        };
+        for (start, end) in runs {
+            let (end, sunday) = match end == 6 && start < 6 {
+                true => (5, true),
+                false => (end, false),
+            };
+            cron.push(match start == end {
+                true => WEEKDAYS[start][..3].to_owned(),
+                false => format!("{}-{}", &WEEKDAYS[start][..3], &WEEKDAYS[end][..3]),
+            });
+            if sunday {
+                cron.push("Sun".to_owned());
+            }
+        }
+    }
+    Ok(cron.join(","))
+}
+
+// The index from Monday of a weekday, abbreviated or in full.
+fn weekday(name: &str) -> Result<usize, String> {
+    WEEKDAYS
+        .iter()
+        .position(|weekday| {
+            weekday.eq_ignore_ascii_case(name) || weekday[..3].eq_ignore_ascii_case(name)
+        })
+        .ok_or_else(|| format!("'{}' is not a valid weekday", name))
+}
+
+// A list of values, ranges and repetitions, written in cron syntax.
+fn component(text: &str) -> Result<String, String> {
+    if text.is_empty() || text.contains('-') {
+        return Err(format!("'{}' is not a valid component", text));
+    }
+    Ok(text.replace("..", "-"))
+}
+
+fn invalid(expression: &str, message: String) -> Error {
+    invalid_kind(expression, ErrorKind::Expression(message))
+}
+
+fn invalid_kind(expression: &str, kind: ErrorKind) -> Error {
+    Error::from(kind).with_expression(expression)
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_to_cron() {
+        for (event, cron) in [
+            ("Mon..Fri *-*-1..7 09:00:00", "00 00 09 1-7 * Mon-Fri"),
+            ("Sat,Sun 10:30", "0 30 10 * * Sat,Sun"),
+            ("*:0/15", "0 0/15 * * * *"),
+            ("2025-12-25", "0 0 0 25 12 * 2025"),
+            ("*-02~03 12:00", "0 00 12 L-2 02 *"),
+            ("*-*~1", "0 0 0 L * *"),
+            ("weekly", "00 00 00 * * Mon"),
+            ("Quarterly", "00 00 00 01 01,04,07,10 *"),
+            ("Fri *-*-* *:*:30", "30 * * * * Fri"),
+            ("Sat..Sun", "0 0 0 * * Sat,Sun"),
+            ("Mon..Sun", "0 0 0 * * Mon-Sat,Sun"),
+            ("Fri..Mon", "0 0 0 * * Fri-Sat,Sun,Mon"),
+            ("Tuesday..Thursday,sun", "0 0 0 * * Tue-Thu,Sun"),
+        ] {
+            assert_eq!(Ok(cron.to_owned()), to_cron(event), "{}", event);
+        }
+        assert!(to_cron("*-*-* 12:00 Europe/Berlin").is_err());
+        assert!(to_cron("Mon..Someday").is_err());
+        assert!(to_cron("*-*-* 12:00:00.5").is_err());
+        assert!(to_cron("*-02~0").is_err());
+    }
+
+    #[test]
+    fn test_parse() {
+        use jiff::civil::{
+            date,
+            Weekday::{Saturday, Sunday},
+        };
+
+        let schedule = parse("Mon..Fri *-*-1..7 09:00:00").unwrap();
+        let start = date(2025, 1, 1).in_tz("UTC").unwrap();
+        let next = schedule.after(&start).next().unwrap();
+        assert_eq!(date(2025, 1, 1).at(9, 0, 0, 0), next.datetime());
+
+        // Weekday ranges ending on Sunday include the whole weekend.
+        let schedule = parse("Sat..Sun 12:00").unwrap();
+        let weekdays: Vec<_> = schedule
+            .after(&start)
+            .take(4)
+            .map(|fire| fire.weekday())
+            .collect();
+        assert_eq!(vec![Saturday, Sunday, Saturday, Sunday], weekdays);
+
+        let error = parse("*-13-01").unwrap_err();
+        assert_eq!("JC0003", error.code());
+        assert_eq!(Some("*-13-01"), error.expression());
+        assert_eq!(None, error.span());
+    }
+}
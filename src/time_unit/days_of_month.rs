@@ -1,25 +1,139 @@
-use std::{borrow::Cow, sync::LazyLock};
+use std::fmt::{self, Display, Formatter};
 
+use jiff::civil::{Date, Weekday};
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+
+#[cfg(feature = "serde")]
+use crate::time_unit::deserialize_field;
 use crate::{
+    error::*,
     ordinal::{Ordinal, OrdinalSet},
-    time_unit::TimeUnitField,
+    specifier::RootSpecifier,
+    time_unit::{fmt_field, MonthDay, OrdinalIter, TimeUnit, TimeUnitField, TimeUnitSpec},
 };
 
-static ALL: LazyLock<OrdinalSet> = LazyLock::new(DaysOfMonth::supported_ordinals);
-
-#[derive(Clone, Debug, Eq)]
+/// The set of the days of the month included in a
+/// [`Schedule`](crate::Schedule).
+///
+/// Besides days of the month by number, days depending on the length of each
+/// month may be included, such as the last day for `L`, the day before it for
+/// `L-1` or the weekday nearest to the 15th for `15W`. The ordinals of
+/// [`TimeUnitSpec`] are only the days by number;
+/// [`DaysOfMonth::includes_date`] and [`DaysOfMonth::resolved_for`] consider
+/// both.
+#[derive(Clone, Debug, PartialEq, Eq)]
 pub struct DaysOfMonth {
-    ordinals: Option<OrdinalSet>,
+    ordinals: OrdinalSet,
+    // Sorted, and empty if every day is included anyway.
+    month_days: Vec<MonthDay>,
+}
+
+impl DaysOfMonth {
+    /// Returns the included days which exist in the provided month, in
+    /// ascending order, e.g. omitting the 31st for April. Returns no days if
+    /// the year or month is invalid.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use std::str::FromStr;
+    ///
+    /// use jiff_cron::DaysOfMonth;
+    ///
+    /// let days_of_month = DaysOfMonth::from_str("15,29-31").unwrap();
+    /// assert_eq!(vec![15, 29, 30, 31], days_of_month.resolved_for(2024, 1));
+    /// assert_eq!(vec![15, 29], days_of_month.resolved_for(2024, 2));
+    /// assert_eq!(vec![15], days_of_month.resolved_for(2025, 2));
+    /// assert!(days_of_month.resolved_for(2025, 13).is_empty());
+    ///
+    /// let days_of_month = DaysOfMonth::from_str("1,L").unwrap();
+    /// assert_eq!(vec![1, 28], days_of_month.resolved_for(2025, 2));
+    /// ```
+    pub fn resolved_for(&self, year: i16, month: i8) -> Vec<i8> {
+        let first = match Date::new(year, month, 1) {
+            Ok(first) => first,
+            Err(_) => return Vec::new(),
+        };
+        let days_in_month = first.days_in_month();
+        if self.month_days.is_empty() {
+            return self
+                .range(..=days_in_month as Ordinal)
+                .map(|day| day as i8)
+                .collect();
+        }
+        let weekday = first.weekday();
+        (1..=days_in_month)
+            .filter(|&day| self.includes_day_in(day, weekday.wrapping_add(day - 1), days_in_month))
+            .collect()
+    }
+
+    /// Returns true if the provided date's day of the month is included, or
+    /// it's an included day depending on the length of its month.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use std::str::FromStr;
+    ///
+    /// use jiff_cron::{jiff::civil::date, DaysOfMonth};
+    ///
+    /// let days_of_month = DaysOfMonth::from_str("L").unwrap();
+    /// assert!(days_of_month.includes_date(date(2024, 2, 29)));
+    /// assert!(!days_of_month.includes_date(date(2025, 3, 30)));
+    /// ```
+    pub fn includes_date(&self, date: Date) -> bool {
+        self.includes_day_in(date.day(), date.weekday(), date.days_in_month())
+    }
+
+    /// Like [`DaysOfMonth::includes_date`], for `day`, a `weekday` within a
+    /// month of `days_in_month` days of any calendar system.
+    pub(crate) fn includes_day_in(&self, day: i8, weekday: Weekday, days_in_month: i8) -> bool {
+        self.includes(day as Ordinal)
+            || self
+                .month_days
+                .iter()
+                .any(|month_day| month_day.includes(day, weekday, days_in_month))
+    }
+
+    /// Returns the included days depending on the length of their month.
+    pub(crate) fn month_days(&self) -> &[MonthDay] {
+        &self.month_days
+    }
+
+    /// Creates the set of the days of the month described by `specifiers`, as
+    /// if they were parsed from a field's comma-separated list.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use std::str::FromStr;
+    ///
+    /// use jiff_cron::{DaysOfMonth, RootSpecifier, Specifier, TimeUnitSpec};
+    ///
+    /// // Equivalent to "1,15"
+    /// let field = DaysOfMonth::from_specifiers(&[
+    ///     RootSpecifier::from(Specifier::Point(1)),
+    ///     RootSpecifier::from(Specifier::Point(15)),
+    /// ])
+    /// .unwrap();
+    /// assert_eq!(2, field.count());
+    /// assert_eq!(DaysOfMonth::from_str("1,15").unwrap(), field);
+    /// ```
+    pub fn from_specifiers(specifiers: &[RootSpecifier]) -> Result<DaysOfMonth, Error> {
+        <DaysOfMonth as TimeUnitField>::from_specifiers(specifiers)
+    }
 }
 
 impl TimeUnitField for DaysOfMonth {
     fn from_optional_ordinal_set(ordinal_set: Option<OrdinalSet>) -> Self {
         DaysOfMonth {
-            ordinals: ordinal_set,
+            ordinals: ordinal_set.unwrap_or_else(DaysOfMonth::supported_ordinals),
+            month_days: Vec::new(),
         }
     }
-    fn name() -> Cow<'static, str> {
-        Cow::from("Days of Month")
+    fn unit() -> TimeUnit {
+        TimeUnit::DaysOfMonth
     }
     fn inclusive_min() -> Ordinal {
         1
@@ -28,15 +142,82 @@ impl TimeUnitField for DaysOfMonth {
         31
     }
     fn ordinals(&self) -> &OrdinalSet {
-        match &self.ordinals {
-            Some(ordinal_set) => ordinal_set,
-            None => &ALL,
+        &self.ordinals
+    }
+    fn month_day_from_root_specifier(root_specifier: &RootSpecifier) -> Result<MonthDay, Error> {
+        match *root_specifier {
+            RootSpecifier::LastDay(offset) if offset < Self::inclusive_max() => {
+                Ok(MonthDay::LastDay {
+                    offset: offset as i8,
+                })
+            }
+            RootSpecifier::LastWeekday => Ok(MonthDay::LastWeekday),
+            RootSpecifier::NearestWeekday(day) => Ok(MonthDay::NearestWeekday {
+                day: Self::validate_ordinal(day)? as i8,
+            }),
+            _ => Err(Self::unsupported_specifier(root_specifier)),
+        }
+    }
+    fn with_month_days(mut self, mut month_days: Vec<MonthDay>) -> Self {
+        // Days depending on the length of the month are dropped if every day
+        // is included anyway, so that equal fields compare equal.
+        if self.is_all() {
+            month_days.clear();
+        }
+        month_days.sort_unstable();
+        month_days.dedup();
+        self.month_days = month_days;
+        self
+    }
+}
+
+impl<'a> IntoIterator for &'a DaysOfMonth {
+    type Item = Ordinal;
+    type IntoIter = OrdinalIter<'a>;
+
+    fn into_iter(self) -> OrdinalIter<'a> {
+        self.iter()
+    }
+}
+
+impl Display for DaysOfMonth {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        let mut separator = "";
+        if !self.ordinals.is_empty() {
+            fmt_field(self, f)?;
+            separator = ",";
+        }
+        for month_day in &self.month_days {
+            write!(f, "{}{}", separator, month_day)?;
+            separator = ",";
         }
+        Ok(())
+    }
+}
+
+#[cfg(feature = "defmt")]
+impl defmt::Format for DaysOfMonth {
+    fn format(&self, f: defmt::Formatter<'_>) {
+        defmt::write!(f, "{}", defmt::Display2Format(self))
+    }
+}
+
+#[cfg(feature = "serde")]
+impl Serialize for DaysOfMonth {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        serializer.collect_str(self)
     }
 }
 
-impl PartialEq for DaysOfMonth {
-    fn eq(&self, other: &DaysOfMonth) -> bool {
-        self.ordinals() == other.ordinals()
+#[cfg(feature = "serde")]
+impl<'de> Deserialize<'de> for DaysOfMonth {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        deserialize_field(deserializer)
     }
 }
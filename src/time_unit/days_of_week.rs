@@ -1,26 +1,186 @@
-use std::{borrow::Cow, sync::LazyLock};
+use std::fmt::{self, Display, Formatter};
 
+use jiff::civil::{Date, Weekday};
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+
+#[cfg(feature = "serde")]
+use crate::time_unit::deserialize_field;
 use crate::{
     error::*,
     ordinal::{Ordinal, OrdinalSet},
-    time_unit::TimeUnitField,
+    specifier::{RootSpecifier, Specifier},
+    time_unit::{
+        fmt_field, lowercase_name, MonthDay, OrdinalIter, TimeUnit, TimeUnitField, TimeUnitSpec,
+    },
 };
 
-static ALL: LazyLock<OrdinalSet> = LazyLock::new(DaysOfWeek::supported_ordinals);
-
-#[derive(Clone, Debug, Eq)]
+/// The set of the days of the week included in a [`Schedule`](crate::Schedule).
+///
+/// Besides whole days of the week, some occurrences of a day of the week
+/// within each month may be included, such as the second-to-last Friday for
+/// `Fri#-2`. The ordinals of [`TimeUnitSpec`] are only the whole days of the
+/// week; [`DaysOfWeek::includes_date`] considers both.
+#[derive(Clone, Debug, PartialEq, Eq)]
 pub struct DaysOfWeek {
-    ordinals: Option<OrdinalSet>,
+    ordinals: OrdinalSet,
+    // Sorted, and only occurrences of days of the week not in `ordinals`.
+    month_days: Vec<MonthDay>,
+}
+
+const NAMES: [(&str, &str); 7] = [
+    ("Sunday", "Sun"),
+    ("Monday", "Mon"),
+    ("Tuesday", "Tue"),
+    ("Wednesday", "Wed"),
+    ("Thursday", "Thu"),
+    ("Friday", "Fri"),
+    ("Saturday", "Sat"),
+];
+
+impl DaysOfWeek {
+    /// Creates the set of the days of the week described by `specifiers`, as if
+    /// they were parsed from a field's comma-separated list.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use std::str::FromStr;
+    ///
+    /// use jiff_cron::{DaysOfWeek, RootSpecifier, Specifier, TimeUnitSpec};
+    ///
+    /// // Equivalent to "Mon-Fri"
+    /// let field = DaysOfWeek::from_specifiers(&[RootSpecifier::from(Specifier::NamedRange(
+    ///     "Mon".into(),
+    ///     "Fri".into(),
+    /// ))])
+    /// .unwrap();
+    /// assert_eq!(5, field.count());
+    /// assert_eq!(DaysOfWeek::from_str("Mon-Fri").unwrap(), field);
+    /// ```
+    pub fn from_specifiers(specifiers: &[RootSpecifier]) -> Result<DaysOfWeek, Error> {
+        <DaysOfWeek as TimeUnitField>::from_specifiers(specifiers)
+    }
+
+    /// Returns the full and abbreviated English name of each day of the week,
+    /// starting with Sunday as ordinal `1`. Both forms are accepted by the
+    /// parser in any case.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use jiff_cron::DaysOfWeek;
+    ///
+    /// assert_eq!(("Monday", "Mon"), DaysOfWeek::names()[2 - 1]);
+    /// ```
+    pub fn names() -> &'static [(&'static str, &'static str)] {
+        &NAMES
+    }
+
+    /// Creates the set of days of the week containing each of `weekdays`.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use jiff_cron::{jiff::civil::Weekday, DaysOfWeek, TimeUnitSpec};
+    ///
+    /// let weekend = DaysOfWeek::from_weekdays([Weekday::Saturday, Weekday::Sunday]);
+    /// assert_eq!(2, weekend.count());
+    /// assert!(weekend.includes_weekday(Weekday::Sunday));
+    /// ```
+    pub fn from_weekdays<I>(weekdays: I) -> DaysOfWeek
+    where
+        I: IntoIterator<Item = Weekday>,
+    {
+        DaysOfWeek::from_ordinal_set(
+            weekdays
+                .into_iter()
+                .map(|weekday| weekday.to_sunday_one_offset() as Ordinal)
+                .collect(),
+        )
+    }
+
+    /// Provides an iterator which will return each included day of the week,
+    /// starting with Sunday.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use std::str::FromStr;
+    ///
+    /// use jiff_cron::{jiff::civil::Weekday, Schedule};
+    ///
+    /// let schedule = Schedule::from_str("0 0 9 * * Mon,Wed,Fri").unwrap();
+    /// let mut weekdays = schedule.days_of_week().weekdays();
+    /// assert_eq!(Some(Weekday::Monday), weekdays.next());
+    /// assert_eq!(Some(Weekday::Wednesday), weekdays.next());
+    /// assert_eq!(Some(Weekday::Friday), weekdays.next());
+    /// assert_eq!(None, weekdays.next());
+    /// ```
+    pub fn weekdays(&self) -> impl DoubleEndedIterator<Item = Weekday> + '_ {
+        self.iter().map(weekday_from_ordinal)
+    }
+
+    /// Returns true if the provided day of the week is included, even if only
+    /// some of its occurrences within each month are, e.g. for `Fri#-2`.
+    pub fn includes_weekday(&self, weekday: Weekday) -> bool {
+        let ordinal = weekday.to_sunday_one_offset() as Ordinal;
+        self.includes(ordinal)
+            || self
+                .month_days
+                .iter()
+                .any(|month_day| month_day.weekday() == Some(ordinal))
+    }
+
+    /// Returns true if the provided date's day of the week is included, or it's
+    /// an included occurrence of its day of the week within its month.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use std::str::FromStr;
+    ///
+    /// use jiff_cron::{jiff::civil::date, DaysOfWeek};
+    ///
+    /// // The second-to-last Friday, and every Monday.
+    /// let days_of_week = DaysOfWeek::from_str("Fri#-2,Mon").unwrap();
+    /// assert!(days_of_week.includes_date(date(2025, 1, 24)));
+    /// assert!(days_of_week.includes_date(date(2025, 1, 27)));
+    /// assert!(!days_of_week.includes_date(date(2025, 1, 31)));
+    /// ```
+    pub fn includes_date(&self, date: Date) -> bool {
+        self.includes_day_in(date.day(), date.weekday(), date.days_in_month())
+    }
+
+    /// Like [`DaysOfWeek::includes_date`], for `day`, a `weekday` within a
+    /// month of `days_in_month` days of any calendar system.
+    pub(crate) fn includes_day_in(&self, day: i8, weekday: Weekday, days_in_month: i8) -> bool {
+        self.includes(weekday.to_sunday_one_offset() as Ordinal)
+            || self
+                .month_days
+                .iter()
+                .any(|month_day| month_day.includes(day, weekday, days_in_month))
+    }
+
+    /// Returns the included occurrences of days of the week within months.
+    pub(crate) fn month_days(&self) -> &[MonthDay] {
+        &self.month_days
+    }
+}
+
+fn weekday_from_ordinal(ordinal: Ordinal) -> Weekday {
+    Weekday::from_sunday_one_offset(ordinal as i8).expect("days of week are validated to be 1-7")
 }
 
 impl TimeUnitField for DaysOfWeek {
     fn from_optional_ordinal_set(ordinal_set: Option<OrdinalSet>) -> Self {
         DaysOfWeek {
-            ordinals: ordinal_set,
+            ordinals: ordinal_set.unwrap_or_else(DaysOfWeek::supported_ordinals),
+            month_days: Vec::new(),
         }
     }
-    fn name() -> Cow<'static, str> {
-        Cow::from("Days of Week")
+    fn unit() -> TimeUnit {
+        TimeUnit::DaysOfWeek
     }
     fn inclusive_min() -> Ordinal {
         1
@@ -30,7 +190,8 @@ impl TimeUnitField for DaysOfWeek {
     }
     fn ordinal_from_name(name: &str) -> Result<Ordinal, Error> {
         //TODO: Use phf crate
-        let ordinal = match name.to_lowercase().as_ref() {
+        let mut buffer = Default::default();
+        let ordinal = match lowercase_name(name, &mut buffer).unwrap_or_default() {
             "sun" | "sunday" => 1,
             "mon" | "monday" => 2,
             "tue" | "tues" | "tuesday" => 3,
@@ -39,25 +200,104 @@ impl TimeUnitField for DaysOfWeek {
             "fri" | "friday" => 6,
             "sat" | "saturday" => 7,
             _ => {
-                return Err(ErrorKind::Expression(format!(
-                    "'{}' is not a valid day of the week.",
-                    name
-                ))
+                return Err(ErrorKind::UnknownName {
+                    unit: TimeUnit::DaysOfWeek,
+                    name: name.to_owned(),
+                }
                 .into())
             }
         };
         Ok(ordinal)
     }
     fn ordinals(&self) -> &OrdinalSet {
-        match &self.ordinals {
-            Some(ordinal_set) => ordinal_set,
-            None => &ALL,
+        &self.ordinals
+    }
+    fn month_day_from_root_specifier(root_specifier: &RootSpecifier) -> Result<MonthDay, Error> {
+        let RootSpecifier::NthWeekday(weekday, nth) = root_specifier else {
+            return Err(Self::unsupported_specifier(root_specifier));
+        };
+        let weekday = match **weekday {
+            RootSpecifier::Specifier(Specifier::Point(ordinal)) => ordinal,
+            RootSpecifier::NamedPoint(ref name) => Self::ordinal_from_name(name)?,
+            _ => return Err(Self::unsupported_specifier(root_specifier)),
+        };
+        let weekday = Self::validate_ordinal(weekday)?;
+        let max = i32::from(MonthDay::MAX_OCCURRENCES);
+        if *nth == 0 || !(-max..=max).contains(nth) {
+            return Err(ErrorKind::InvalidOccurrence {
+                unit: Self::unit(),
+                nth: *nth,
+            }
+            .into());
         }
+        Ok(MonthDay::NthWeekday {
+            weekday,
+            nth: *nth as i8,
+        })
+    }
+    fn with_month_days(mut self, mut month_days: Vec<MonthDay>) -> Self {
+        // Occurrences of days of the week which are included anyway are
+        // dropped, so that equal fields compare equal.
+        month_days.retain(|month_day| {
+            month_day
+                .weekday()
+                .into_iter()
+                .all(|weekday| !self.ordinals.contains(&weekday))
+        });
+        month_days.sort_unstable();
+        month_days.dedup();
+        self.month_days = month_days;
+        self
+    }
+}
+
+impl<'a> IntoIterator for &'a DaysOfWeek {
+    type Item = Ordinal;
+    type IntoIter = OrdinalIter<'a>;
+
+    fn into_iter(self) -> OrdinalIter<'a> {
+        self.iter()
+    }
+}
+
+impl Display for DaysOfWeek {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        let mut separator = "";
+        if !self.ordinals.is_empty() {
+            fmt_field(self, f)?;
+            separator = ",";
+        }
+        for month_day in &self.month_days {
+            write!(f, "{}{}", separator, month_day)?;
+            separator = ",";
+        }
+        Ok(())
+    }
+}
+
+#[cfg(feature = "defmt")]
+impl defmt::Format for DaysOfWeek {
+    fn format(&self, f: defmt::Formatter<'_>) {
+        defmt::write!(f, "{}", defmt::Display2Format(self))
+    }
+}
+
+#[cfg(feature = "serde")]
+impl Serialize for DaysOfWeek {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        serializer.collect_str(self)
     }
 }
 
-impl PartialEq for DaysOfWeek {
-    fn eq(&self, other: &DaysOfWeek) -> bool {
-        self.ordinals() == other.ordinals()
+#[cfg(feature = "serde")]
+impl<'de> Deserialize<'de> for DaysOfWeek {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        deserialize_field(deserializer)
     }
 }
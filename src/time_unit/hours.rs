@@ -1,25 +1,53 @@
-use std::{borrow::Cow, sync::LazyLock};
+use std::fmt::{self, Display, Formatter};
 
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+
+#[cfg(feature = "serde")]
+use crate::time_unit::deserialize_field;
 use crate::{
+    error::*,
     ordinal::{Ordinal, OrdinalSet},
-    time_unit::TimeUnitField,
+    specifier::RootSpecifier,
+    time_unit::{fmt_field, OrdinalIter, TimeUnit, TimeUnitField, TimeUnitSpec},
 };
 
-static ALL: LazyLock<OrdinalSet> = LazyLock::new(Hours::supported_ordinals);
-
-#[derive(Clone, Debug, Eq)]
+/// The set of the hours of the day included in a [`Schedule`](crate::Schedule).
+#[derive(Clone, Debug, PartialEq, Eq)]
 pub struct Hours {
-    ordinals: Option<OrdinalSet>,
+    ordinals: OrdinalSet,
+}
+
+impl Hours {
+    /// Creates the set of the hours of the day described by `specifiers`, as if
+    /// they were parsed from a field's comma-separated list.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use std::str::FromStr;
+    ///
+    /// use jiff_cron::{Hours, RootSpecifier, Specifier, TimeUnitSpec};
+    ///
+    /// // Equivalent to "9-17/4"
+    /// let field =
+    ///     Hours::from_specifiers(&[RootSpecifier::Period(Specifier::Range(9, 17), 4)]).unwrap();
+    /// assert_eq!(3, field.count());
+    /// assert_eq!(Hours::from_str("9-17/4").unwrap(), field);
+    /// ```
+    pub fn from_specifiers(specifiers: &[RootSpecifier]) -> Result<Hours, Error> {
+        <Hours as TimeUnitField>::from_specifiers(specifiers)
+    }
 }
 
 impl TimeUnitField for Hours {
     fn from_optional_ordinal_set(ordinal_set: Option<OrdinalSet>) -> Self {
         Hours {
-            ordinals: ordinal_set,
+            ordinals: ordinal_set.unwrap_or_else(Hours::supported_ordinals),
         }
     }
-    fn name() -> Cow<'static, str> {
-        Cow::from("Hours")
+    fn unit() -> TimeUnit {
+        TimeUnit::Hours
     }
     fn inclusive_min() -> Ordinal {
         0
@@ -28,15 +56,48 @@ impl TimeUnitField for Hours {
         23
     }
     fn ordinals(&self) -> &OrdinalSet {
-        match &self.ordinals {
-            Some(ordinal_set) => ordinal_set,
-            None => &ALL,
-        }
+        &self.ordinals
+    }
+}
+
+impl<'a> IntoIterator for &'a Hours {
+    type Item = Ordinal;
+    type IntoIter = OrdinalIter<'a>;
+
+    fn into_iter(self) -> OrdinalIter<'a> {
+        self.iter()
+    }
+}
+
+impl Display for Hours {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        fmt_field(self, f)
+    }
+}
+
+#[cfg(feature = "defmt")]
+impl defmt::Format for Hours {
+    fn format(&self, f: defmt::Formatter<'_>) {
+        defmt::write!(f, "{}", defmt::Display2Format(self))
+    }
+}
+
+#[cfg(feature = "serde")]
+impl Serialize for Hours {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        serializer.collect_str(self)
     }
 }
 
-impl PartialEq for Hours {
-    fn eq(&self, other: &Hours) -> bool {
-        self.ordinals() == other.ordinals()
+#[cfg(feature = "serde")]
+impl<'de> Deserialize<'de> for Hours {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        deserialize_field(deserializer)
     }
 }
@@ -1,25 +1,57 @@
-use std::{borrow::Cow, sync::LazyLock};
+use std::fmt::{self, Display, Formatter};
 
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+
+#[cfg(feature = "serde")]
+use crate::time_unit::deserialize_field;
 use crate::{
+    error::*,
     ordinal::{Ordinal, OrdinalSet},
-    time_unit::TimeUnitField,
+    specifier::RootSpecifier,
+    time_unit::{fmt_field, OrdinalIter, TimeUnit, TimeUnitField, TimeUnitSpec},
 };
 
-static ALL: LazyLock<OrdinalSet> = LazyLock::new(Minutes::supported_ordinals);
-
-#[derive(Clone, Debug, Eq)]
+/// The set of the minutes of the hour included in a
+/// [`Schedule`](crate::Schedule).
+#[derive(Clone, Debug, PartialEq, Eq)]
 pub struct Minutes {
-    ordinals: Option<OrdinalSet>,
+    ordinals: OrdinalSet,
+}
+
+impl Minutes {
+    /// Creates the set of the minutes of the hour described by `specifiers`, as
+    /// if they were parsed from a field's comma-separated list.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use std::str::FromStr;
+    ///
+    /// use jiff_cron::{Minutes, RootSpecifier, Specifier, TimeUnitSpec};
+    ///
+    /// // Equivalent to "0,30"
+    /// let field = Minutes::from_specifiers(&[
+    ///     RootSpecifier::from(Specifier::Point(0)),
+    ///     RootSpecifier::from(Specifier::Point(30)),
+    /// ])
+    /// .unwrap();
+    /// assert_eq!(2, field.count());
+    /// assert_eq!(Minutes::from_str("0,30").unwrap(), field);
+    /// ```
+    pub fn from_specifiers(specifiers: &[RootSpecifier]) -> Result<Minutes, Error> {
+        <Minutes as TimeUnitField>::from_specifiers(specifiers)
+    }
 }
 
 impl TimeUnitField for Minutes {
     fn from_optional_ordinal_set(ordinal_set: Option<OrdinalSet>) -> Self {
         Minutes {
-            ordinals: ordinal_set,
+            ordinals: ordinal_set.unwrap_or_else(Minutes::supported_ordinals),
         }
     }
-    fn name() -> Cow<'static, str> {
-        Cow::from("Minutes")
+    fn unit() -> TimeUnit {
+        TimeUnit::Minutes
     }
     fn inclusive_min() -> Ordinal {
         0
@@ -28,15 +60,48 @@ impl TimeUnitField for Minutes {
         59
     }
     fn ordinals(&self) -> &OrdinalSet {
-        match &self.ordinals {
-            Some(ordinal_set) => ordinal_set,
-            None => &ALL,
-        }
+        &self.ordinals
+    }
+}
+
+impl<'a> IntoIterator for &'a Minutes {
+    type Item = Ordinal;
+    type IntoIter = OrdinalIter<'a>;
+
+    fn into_iter(self) -> OrdinalIter<'a> {
+        self.iter()
+    }
+}
+
+impl Display for Minutes {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        fmt_field(self, f)
+    }
+}
+
+#[cfg(feature = "defmt")]
+impl defmt::Format for Minutes {
+    fn format(&self, f: defmt::Formatter<'_>) {
+        defmt::write!(f, "{}", defmt::Display2Format(self))
+    }
+}
+
+#[cfg(feature = "serde")]
+impl Serialize for Minutes {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        serializer.collect_str(self)
     }
 }
 
-impl PartialEq for Minutes {
-    fn eq(&self, other: &Minutes) -> bool {
-        self.ordinals() == other.ordinals()
+#[cfg(feature = "serde")]
+impl<'de> Deserialize<'de> for Minutes {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        deserialize_field(deserializer)
     }
 }
@@ -2,53 +2,127 @@ mod days_of_month;
 mod days_of_week;
 mod hours;
 mod minutes;
+mod month_day;
 mod months;
 mod seconds;
 mod years;
 
-use std::{borrow::Cow, collections::btree_set, iter, ops::RangeBounds};
+use std::{
+    borrow::Cow,
+    fmt::{self, Formatter},
+    iter,
+    ops::{Bound, RangeBounds},
+    str,
+};
+
+#[cfg(feature = "serde")]
+use serde::de::{self, SeqAccess, Visitor};
 
+pub(crate) use self::month_day::MonthDay;
 pub use self::{
     days_of_month::DaysOfMonth, days_of_week::DaysOfWeek, hours::Hours, minutes::Minutes,
     months::Months, seconds::Seconds, years::Years,
 };
 use crate::{
     error::*,
-    ordinal::{Ordinal, OrdinalSet},
+    ordinal::{self, Ordinal, OrdinalSet},
     specifier::{RootSpecifier, Specifier},
 };
 
+/// The unit of time of one of a schedule's fields.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum TimeUnit {
+    /// The first field, from `0` to `59`.
+    Seconds,
+    /// The second field, from `0` to `59`.
+    Minutes,
+    /// The third field, from `0` to `23`.
+    Hours,
+    /// The fourth field, from `1` to `31`.
+    DaysOfMonth,
+    /// The fifth field, from `1` (January) to `12` (December).
+    Months,
+    /// The sixth field, from `1` (Sunday) to `7` (Saturday).
+    DaysOfWeek,
+    /// The optional seventh field, from `1970` to `2100`.
+    Years,
+}
+
+impl TimeUnit {
+    /// Every unit, in the order of a cron expression's fields.
+    pub const ALL: [TimeUnit; 7] = [
+        TimeUnit::Seconds,
+        TimeUnit::Minutes,
+        TimeUnit::Hours,
+        TimeUnit::DaysOfMonth,
+        TimeUnit::Months,
+        TimeUnit::DaysOfWeek,
+        TimeUnit::Years,
+    ];
+
+    /// Returns the unit's name as used in error messages, e.g. `Days of Week`.
+    pub fn name(self) -> &'static str {
+        match self {
+            TimeUnit::Seconds => "Seconds",
+            TimeUnit::Minutes => "Minutes",
+            TimeUnit::Hours => "Hours",
+            TimeUnit::DaysOfMonth => "Days of Month",
+            TimeUnit::Months => "Months",
+            TimeUnit::DaysOfWeek => "Days of Week",
+            TimeUnit::Years => "Years",
+        }
+    }
+}
+
+impl fmt::Display for TimeUnit {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        f.write_str(self.name())
+    }
+}
+
+#[cfg(feature = "defmt")]
+impl defmt::Format for TimeUnit {
+    fn format(&self, f: defmt::Formatter<'_>) {
+        defmt::write!(f, "{=str}", self.name())
+    }
+}
+
+/// An iterator over the ordinals included in a [`TimeUnitSpec`], in ascending
+/// order.
 pub struct OrdinalIter<'a> {
-    set_iter: btree_set::Iter<'a, Ordinal>,
+    set_iter: ordinal::Iter<'a>,
 }
 
 impl Iterator for OrdinalIter<'_> {
     type Item = Ordinal;
     fn next(&mut self) -> Option<Ordinal> {
-        self.set_iter.next().copied()
+        self.set_iter.next()
     }
 }
 
 impl DoubleEndedIterator for OrdinalIter<'_> {
     fn next_back(&mut self) -> Option<Self::Item> {
-        self.set_iter.next_back().copied()
+        self.set_iter.next_back()
     }
 }
 
+/// An iterator over the ordinals included in a [`TimeUnitSpec`] within a
+/// given range, in ascending order.
 pub struct OrdinalRangeIter<'a> {
-    range_iter: btree_set::Range<'a, Ordinal>,
+    range_iter: ordinal::Iter<'a>,
 }
 
 impl Iterator for OrdinalRangeIter<'_> {
     type Item = Ordinal;
     fn next(&mut self) -> Option<Ordinal> {
-        self.range_iter.next().copied()
+        self.range_iter.next()
     }
 }
 
 impl DoubleEndedIterator for OrdinalRangeIter<'_> {
     fn next_back(&mut self) -> Option<Self::Item> {
-        self.range_iter.next_back().copied()
+        self.range_iter.next_back()
     }
 }
 
@@ -89,6 +163,13 @@ impl DoubleEndedIterator for OrdinalRangeIter<'_> {
 /// assert_eq!(Some(2020), five_year_plan.next());
 /// assert_eq!(Some(2021), five_year_plan.next());
 /// assert_eq!(None, five_year_plan.next());
+///
+/// // References to the field types are iterable directly
+/// let mut total = 0;
+/// for year in schedule.years() {
+///     total += year;
+/// }
+/// assert_eq!(total, schedule.years().iter().sum());
 /// ```
 pub trait TimeUnitSpec {
     /// Returns true if the provided ordinal was included in the schedule spec
@@ -157,6 +238,7 @@ pub trait TimeUnitSpec {
     /// ```
     fn range<R>(&self, range: R) -> OrdinalRangeIter<'_>
     where
+        Self: Sized,
         R: RangeBounds<Ordinal>;
 
     /// Returns the number of ordinals included in the associated schedule
@@ -192,6 +274,156 @@ pub trait TimeUnitSpec {
     /// assert_eq!(true, schedule.months().is_all());
     /// ```
     fn is_all(&self) -> bool;
+
+    /// Returns true if every ordinal included in this TimeUnitSpec is also
+    /// included in `other`.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use std::str::FromStr;
+    ///
+    /// use jiff_cron::{Schedule, TimeUnitSpec};
+    ///
+    /// let office_hours = Schedule::from_str("0 0 8-18 * * *").unwrap();
+    /// let schedule = Schedule::from_str("0 0 9,12,17 * * *").unwrap();
+    ///
+    /// assert_eq!(true, schedule.hours().is_subset(office_hours.hours()));
+    /// assert_eq!(false, office_hours.hours().is_subset(schedule.hours()));
+    /// ```
+    fn is_subset<S>(&self, other: &S) -> bool
+    where
+        Self: Sized,
+        S: TimeUnitSpec;
+
+    /// Returns true if every ordinal included in `other` is also included in
+    /// this TimeUnitSpec.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use std::str::FromStr;
+    ///
+    /// use jiff_cron::{Schedule, TimeUnitSpec};
+    ///
+    /// let office_hours = Schedule::from_str("0 0 8-18 * * *").unwrap();
+    /// let schedule = Schedule::from_str("0 0 9,12,17 * * *").unwrap();
+    ///
+    /// assert_eq!(true, office_hours.hours().is_superset(schedule.hours()));
+    /// assert_eq!(false, schedule.hours().is_superset(office_hours.hours()));
+    /// ```
+    fn is_superset<S>(&self, other: &S) -> bool
+    where
+        Self: Sized,
+        S: TimeUnitSpec;
+
+    /// Returns true if every ordinal within the specified range is included.
+    /// Bounds outside of the ordinals supported by the unit of time are
+    /// ignored.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use std::str::FromStr;
+    ///
+    /// use jiff_cron::{Schedule, TimeUnitSpec};
+    ///
+    /// let expression = "0 0 8-18 * * *";
+    /// let schedule = Schedule::from_str(expression).expect("Failed to parse expression.");
+    ///
+    /// assert_eq!(true, schedule.hours().contains_range(9..=17));
+    /// assert_eq!(false, schedule.hours().contains_range(6..=12));
+    /// assert_eq!(true, schedule.days_of_month().contains_range(..));
+    /// ```
+    fn contains_range<R>(&self, range: R) -> bool
+    where
+        Self: Sized,
+        R: RangeBounds<Ordinal>;
+
+    /// Returns the smallest included ordinal which is strictly greater than
+    /// the provided ordinal, if any.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use std::str::FromStr;
+    ///
+    /// use jiff_cron::{Schedule, TimeUnitSpec};
+    ///
+    /// let schedule = Schedule::from_str("0 */15 * * * *").unwrap();
+    ///
+    /// assert_eq!(Some(30), schedule.minutes().next_ordinal_after(17));
+    /// assert_eq!(Some(15), schedule.minutes().next_ordinal_after(0));
+    /// assert_eq!(None, schedule.minutes().next_ordinal_after(45));
+    /// ```
+    fn next_ordinal_after(&self, ordinal: Ordinal) -> Option<Ordinal>;
+
+    /// Returns the largest included ordinal which is strictly less than the
+    /// provided ordinal, if any.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use std::str::FromStr;
+    ///
+    /// use jiff_cron::{Schedule, TimeUnitSpec};
+    ///
+    /// let schedule = Schedule::from_str("0 */15 * * * *").unwrap();
+    ///
+    /// assert_eq!(Some(15), schedule.minutes().prev_ordinal_before(17));
+    /// assert_eq!(Some(30), schedule.minutes().prev_ordinal_before(45));
+    /// assert_eq!(None, schedule.minutes().prev_ordinal_before(0));
+    /// ```
+    fn prev_ordinal_before(&self, ordinal: Ordinal) -> Option<Ordinal>;
+
+    /// Returns the name of the unit of time being described.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use std::str::FromStr;
+    ///
+    /// use jiff_cron::{Schedule, TimeUnitSpec};
+    ///
+    /// let schedule = Schedule::from_str("* * * * * *").unwrap();
+    ///
+    /// assert_eq!("Days of Month", schedule.days_of_month().unit_name());
+    /// ```
+    fn unit_name(&self) -> Cow<'static, str>;
+
+    /// Returns the smallest ordinal supported by the unit of time being
+    /// described, regardless of which ordinals are included.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use std::str::FromStr;
+    ///
+    /// use jiff_cron::{Schedule, TimeUnitSpec};
+    ///
+    /// let schedule = Schedule::from_str("0 0 12 * * *").unwrap();
+    ///
+    /// assert_eq!(0, schedule.hours().min());
+    /// assert_eq!(1, schedule.months().min());
+    /// ```
+    fn min(&self) -> Ordinal;
+
+    /// Returns the largest ordinal supported by the unit of time being
+    /// described, regardless of which ordinals are included.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use std::str::FromStr;
+    ///
+    /// use jiff_cron::{Schedule, TimeUnitSpec};
+    ///
+    /// let schedule = Schedule::from_str("0 0 12 * * *").unwrap();
+    ///
+    /// assert_eq!(23, schedule.hours().max());
+    /// assert_eq!(12, schedule.months().max());
+    /// ```
+    fn max(&self) -> Ordinal;
 }
 
 impl<T> TimeUnitSpec for T
@@ -208,6 +440,7 @@ where
     }
     fn range<R>(&'_ self, range: R) -> OrdinalRangeIter<'_>
     where
+        Self: Sized,
         R: RangeBounds<Ordinal>,
     {
         OrdinalRangeIter {
@@ -222,6 +455,183 @@ where
         let max_supported_ordinals = Self::inclusive_max() - Self::inclusive_min() + 1;
         self.ordinals().len() == max_supported_ordinals as usize
     }
+
+    fn is_subset<S>(&self, other: &S) -> bool
+    where
+        Self: Sized,
+        S: TimeUnitSpec,
+    {
+        self.iter().all(|ordinal| other.includes(ordinal))
+    }
+
+    fn is_superset<S>(&self, other: &S) -> bool
+    where
+        Self: Sized,
+        S: TimeUnitSpec,
+    {
+        other.iter().all(|ordinal| self.includes(ordinal))
+    }
+
+    fn contains_range<R>(&self, range: R) -> bool
+    where
+        Self: Sized,
+        R: RangeBounds<Ordinal>,
+    {
+        let (start, end) = ordinal::inclusive_bounds(&range);
+        let start = start.max(Self::inclusive_min());
+        let end = end.min(Self::inclusive_max());
+        start > end || self.ordinals().range(start..=end).count() == (end - start + 1) as usize
+    }
+
+    fn next_ordinal_after(&self, ordinal: Ordinal) -> Option<Ordinal> {
+        self.ordinals()
+            .range((Bound::Excluded(ordinal), Bound::Unbounded))
+            .next()
+    }
+
+    fn prev_ordinal_before(&self, ordinal: Ordinal) -> Option<Ordinal> {
+        self.ordinals().range(..ordinal).next_back()
+    }
+
+    fn unit_name(&self) -> Cow<'static, str> {
+        Self::name()
+    }
+
+    fn min(&self) -> Ordinal {
+        Self::inclusive_min()
+    }
+
+    fn max(&self) -> Ordinal {
+        Self::inclusive_max()
+    }
+}
+
+/// The length of the longest month or weekday name, `September` and
+/// `Wednesday`.
+const MAX_NAME_LEN: usize = 9;
+
+/// Lowercases `name` into `buffer` without allocating, returning `None` if
+/// it's too long to be the name of a month or weekday.
+pub(crate) fn lowercase_name<'b>(
+    name: &str,
+    buffer: &'b mut [u8; MAX_NAME_LEN],
+) -> Option<&'b str> {
+    let lowercase = buffer.get_mut(..name.len())?;
+    lowercase.copy_from_slice(name.as_bytes());
+    lowercase.make_ascii_lowercase();
+    str::from_utf8(lowercase).ok()
+}
+
+/// Writes the shortest expression for `field` out of `*`, a stepped range or a
+/// list of points and ranges.
+pub(crate) fn fmt_field<T>(field: &T, f: &mut Formatter<'_>) -> fmt::Result
+where
+    T: TimeUnitField,
+{
+    if field.is_all() {
+        return f.write_str("*");
+    }
+    let ordinals: Vec<Ordinal> = field.iter().collect();
+    if let Some(step) = common_step(&ordinals) {
+        let (first, last) = (ordinals[0], ordinals[ordinals.len() - 1]);
+        let runs_to_max = last + step > T::inclusive_max();
+        return match (first == T::inclusive_min(), runs_to_max) {
+            (true, true) => write!(f, "*/{}", step),
+            (false, true) => write!(f, "{}/{}", first, step),
+            _ => write!(f, "{}-{}/{}", first, last, step),
+        };
+    }
+    let mut separator = "";
+    let mut rest = &ordinals[..];
+    while let Some(&start) = rest.first() {
+        let run = rest
+            .iter()
+            .zip(start..)
+            .take_while(|(ordinal, expected)| **ordinal == *expected)
+            .count();
+        match run {
+            1 => write!(f, "{}{}", separator, start)?,
+            2 => write!(f, "{}{},{}", separator, start, start + 1)?,
+            _ => write!(f, "{}{}-{}", separator, start, start + run as Ordinal - 1)?,
+        }
+        separator = ",";
+        rest = &rest[run..];
+    }
+    Ok(())
+}
+
+/// Returns the step between `ordinals` if there are at least three of them,
+/// evenly spaced more than one apart.
+fn common_step(ordinals: &[Ordinal]) -> Option<Ordinal> {
+    if ordinals.len() < 3 {
+        return None;
+    }
+    let step = ordinals[1] - ordinals[0];
+    let evenly_spaced = ordinals.windows(2).all(|pair| pair[1] - pair[0] == step);
+    (step > 1 && evenly_spaced).then_some(step)
+}
+
+/// Deserializes a field from its expression, e.g. `"MON-FRI"`. Self-describing
+/// formats may provide a sequence of ordinals instead, e.g. `[2, 3, 4, 5, 6]`.
+#[cfg(feature = "serde")]
+pub(crate) fn deserialize_field<'de, D, T>(deserializer: D) -> Result<T, D::Error>
+where
+    D: de::Deserializer<'de>,
+    T: TimeUnitField + std::str::FromStr<Err = Error>,
+{
+    if deserializer.is_human_readable() {
+        deserializer.deserialize_any(FieldVisitor::new())
+    } else {
+        deserializer.deserialize_str(FieldVisitor::new())
+    }
+}
+
+#[cfg(feature = "serde")]
+struct FieldVisitor<T> {
+    field: std::marker::PhantomData<T>,
+}
+
+#[cfg(feature = "serde")]
+impl<T> FieldVisitor<T> {
+    fn new() -> FieldVisitor<T> {
+        FieldVisitor {
+            field: std::marker::PhantomData,
+        }
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<'de, T> Visitor<'de> for FieldVisitor<T>
+where
+    T: TimeUnitField + std::str::FromStr<Err = Error>,
+{
+    type Value = T;
+
+    fn expecting(&self, formatter: &mut Formatter<'_>) -> fmt::Result {
+        write!(
+            formatter,
+            "a valid cron field or a sequence of ordinals for {}",
+            T::name()
+        )
+    }
+
+    fn visit_str<E>(self, v: &str) -> Result<Self::Value, E>
+    where
+        E: de::Error,
+    {
+        T::from_str(v).map_err(de::Error::custom)
+    }
+
+    fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error>
+    where
+        A: SeqAccess<'de>,
+    {
+        let mut ordinals = OrdinalSet::new();
+        while let Some(ordinal) = seq.next_element()? {
+            ordinals.insert(T::validate_ordinal(ordinal).map_err(de::Error::custom)?);
+        }
+        Ok(T::from_ordinal_set(ordinals))
+    }
 }
 
 pub trait TimeUnitField
@@ -229,17 +639,21 @@ where
     Self: Sized,
 {
     fn from_optional_ordinal_set(ordinal_set: Option<OrdinalSet>) -> Self;
-    fn name() -> Cow<'static, str>;
+    fn unit() -> TimeUnit;
     fn inclusive_min() -> Ordinal;
     fn inclusive_max() -> Ordinal;
     fn ordinals(&self) -> &OrdinalSet;
 
+    fn name() -> Cow<'static, str> {
+        Cow::Borrowed(Self::unit().name())
+    }
+
     fn from_ordinal(ordinal: Ordinal) -> Self {
         Self::from_ordinal_set(iter::once(ordinal).collect())
     }
 
     fn supported_ordinals() -> OrdinalSet {
-        (Self::inclusive_min()..Self::inclusive_max() + 1).collect()
+        OrdinalSet::from_inclusive_range(Self::inclusive_min(), Self::inclusive_max())
     }
 
     fn all() -> Self {
@@ -250,33 +664,74 @@ where
         Self::from_optional_ordinal_set(Some(ordinal_set))
     }
 
+    fn from_specifiers(specifiers: &[RootSpecifier]) -> Result<Self, Error> {
+        if specifiers.len() == 1 && specifiers[0] == RootSpecifier::from(Specifier::All) {
+            return Ok(Self::all());
+        }
+        let mut ordinals = OrdinalSet::new();
+        let mut month_days = Vec::new();
+        for specifier in specifiers {
+            if specifier.is_month_day() {
+                month_days.push(Self::month_day_from_root_specifier(specifier)?);
+                continue;
+            }
+            let specifier_ordinals: OrdinalSet = Self::ordinals_from_root_specifier(specifier)?;
+            for ordinal in &specifier_ordinals {
+                Self::validate_ordinal(ordinal)?;
+            }
+            // A lone specifier's set is used as is rather than copied.
+            if specifiers.len() == 1 {
+                return Ok(Self::from_ordinal_set(specifier_ordinals));
+            }
+            ordinals.extend(specifier_ordinals);
+        }
+        Ok(Self::from_ordinal_set(ordinals).with_month_days(month_days))
+    }
+
+    /// Converts a specifier for a day depending on the layout of its month,
+    /// such as `Fri#-2` or `L`, which only the days of the month and of the
+    /// week support.
+    fn month_day_from_root_specifier(root_specifier: &RootSpecifier) -> Result<MonthDay, Error> {
+        Err(Self::unsupported_specifier(root_specifier))
+    }
+
+    fn with_month_days(self, month_days: Vec<MonthDay>) -> Self {
+        debug_assert!(month_days.is_empty());
+        self
+    }
+
+    fn unsupported_specifier(root_specifier: &RootSpecifier) -> Error {
+        ErrorKind::InvalidField {
+            unit: Self::unit(),
+            field: root_specifier.to_string(),
+        }
+        .into()
+    }
+
     fn ordinal_from_name(name: &str) -> Result<Ordinal, Error> {
-        Err(ErrorKind::Expression(format!(
-            "The '{}' field does not support using names. '{}' specified.",
-            Self::name(),
-            name
-        ))
+        Err(ErrorKind::UnknownName {
+            unit: Self::unit(),
+            name: name.to_owned(),
+        }
         .into())
     }
+
     fn validate_ordinal(ordinal: Ordinal) -> Result<Ordinal, Error> {
-        //println!("validate_ordinal for {} => {}", Self::name(), ordinal);
-        match ordinal {
-            i if i < Self::inclusive_min() => Err(ErrorKind::Expression(format!(
-                "{} must be greater than or equal to {}. ('{}' specified.)",
-                Self::name(),
-                Self::inclusive_min(),
-                i
-            ))
-            .into()),
-            i if i > Self::inclusive_max() => Err(ErrorKind::Expression(format!(
-                "{} must be less than {}. ('{}' specified.)",
-                Self::name(),
-                Self::inclusive_max(),
-                i
-            ))
-            .into()),
-            i => Ok(i),
+        if (Self::inclusive_min()..=Self::inclusive_max()).contains(&ordinal) {
+            Ok(ordinal)
+        } else {
+            Err(Self::invalid_ordinal(i64::from(ordinal)))
+        }
+    }
+
+    fn invalid_ordinal(value: i64) -> Error {
+        ErrorKind::InvalidOrdinal {
+            unit: Self::unit(),
+            value,
+            min: Self::inclusive_min(),
+            max: Self::inclusive_max(),
         }
+        .into()
     }
 
     fn ordinals_from_specifier(specifier: &Specifier) -> Result<OrdinalSet, Error> {
@@ -286,67 +741,205 @@ where
             All => Ok(Self::supported_ordinals()),
             Point(ordinal) => Ok(([ordinal]).iter().cloned().collect()),
             Range(start, end) => {
-                match (Self::validate_ordinal(start), Self::validate_ordinal(end)) {
-                    (Ok(start), Ok(end)) if start <= end => Ok((start..end + 1).collect()),
-                    _ => Err(ErrorKind::Expression(format!(
-                        "Invalid range for {}: {}-{}",
-                        Self::name(),
-                        start,
-                        end
-                    ))
-                    .into()),
+                let (start, end) = (Self::validate_ordinal(start)?, Self::validate_ordinal(end)?);
+                if start > end {
+                    return Err(Self::invalid_range(start, end));
                 }
+                Ok((start..=end).collect())
             }
             NamedRange(ref start_name, ref end_name) => {
-                let start = Self::ordinal_from_name(start_name)?;
-                let end = Self::ordinal_from_name(end_name)?;
-                match (Self::validate_ordinal(start), Self::validate_ordinal(end)) {
-                    (Ok(start), Ok(end)) if start <= end => Ok((start..end + 1).collect()),
-                    _ => Err(ErrorKind::Expression(format!(
-                        "Invalid named range for {}: {}-{}",
-                        Self::name(),
-                        start_name,
-                        end_name
-                    ))
-                    .into()),
+                let start = Self::validate_ordinal(Self::ordinal_from_name(start_name)?)?;
+                let end = Self::validate_ordinal(Self::ordinal_from_name(end_name)?)?;
+                if start > end {
+                    return Err(Self::invalid_range(start_name, end_name));
                 }
+                Ok((start..=end).collect())
+            }
+        }
+    }
+
+    fn invalid_range(start: impl fmt::Display, end: impl fmt::Display) -> Error {
+        ErrorKind::InvalidRange {
+            unit: Self::unit(),
+            start: start.to_string(),
+            end: end.to_string(),
+        }
+        .into()
+    }
+
+    fn ordinals_from_period(
+        start: &Specifier,
+        step: u32,
+        offset: u32,
+    ) -> Result<OrdinalSet, Error> {
+        if step == 0 {
+            return Err(ErrorKind::ZeroStep { unit: Self::unit() }.into());
+        }
+        if step > Self::inclusive_max() {
+            return Err(ErrorKind::InvalidStep {
+                unit: Self::unit(),
+                step,
+                max: Self::inclusive_max(),
+            }
+            .into());
+        }
+        if offset >= step {
+            return Err(ErrorKind::InvalidOffset {
+                unit: Self::unit(),
+                offset,
+                step,
             }
+            .into());
         }
+
+        let base_set: OrdinalSet = match start {
+            // A point prior to a period implies a range whose start is the specified
+            // point and terminating inclusively with the inclusive max
+            Specifier::Point(start) => {
+                let start = Self::validate_ordinal(*start)?;
+                (start..=Self::inclusive_max()).collect()
+            }
+            specifier => Self::ordinals_from_specifier(specifier)?,
+        };
+        Ok(base_set
+            .into_iter()
+            .skip(offset as usize)
+            .step_by(step as usize)
+            .collect())
     }
 
     fn ordinals_from_root_specifier(root_specifier: &RootSpecifier) -> Result<OrdinalSet, Error> {
         let ordinals = match root_specifier {
             RootSpecifier::Specifier(specifier) => Self::ordinals_from_specifier(specifier)?,
-            RootSpecifier::Period(_, 0) => Err(ErrorKind::Expression(
-                "range step cannot be zero".to_string(),
-            ))?,
-            RootSpecifier::Period(start, step) => {
-                if *step < 1 || *step > Self::inclusive_max() {
-                    return Err(ErrorKind::Expression(format!(
-                        "{} must be between 1 and {}. ('{}' specified.)",
-                        Self::name(),
-                        Self::inclusive_max(),
-                        step,
-                    ))
-                    .into());
-                }
-
-                let base_set = match start {
-                    // A point prior to a period implies a range whose start is the specified
-                    // point and terminating inclusively with the inclusive max
-                    Specifier::Point(start) => {
-                        let start = Self::validate_ordinal(*start)?;
-                        (start..=Self::inclusive_max()).collect()
-                    }
-                    specifier => Self::ordinals_from_specifier(specifier)?,
-                };
-                base_set.into_iter().step_by(*step as usize).collect()
+            RootSpecifier::Period(start, step) => Self::ordinals_from_period(start, *step, 0)?,
+            RootSpecifier::OffsetPeriod(start, step, offset) => {
+                Self::ordinals_from_period(start, *step, *offset)?
             }
             RootSpecifier::NamedPoint(ref name) => ([Self::ordinal_from_name(name)?])
                 .iter()
                 .cloned()
                 .collect::<OrdinalSet>(),
+            // `H` is resolved before parsing given a hash key, leaving only
+            // invalid ranges and steps of it, which are reported first.
+            RootSpecifier::Hashed(range, step) => {
+                Self::ordinals_from_period(range, step.unwrap_or(1), 0)?;
+                return Err(ErrorKind::HashWithoutKey {
+                    unit: Self::unit(),
+                    field: root_specifier.to_string(),
+                }
+                .into());
+            }
+            RootSpecifier::NthWeekday(..)
+            | RootSpecifier::LastDay(_)
+            | RootSpecifier::NearestWeekday(_)
+            | RootSpecifier::LastWeekday => {
+                return Err(Self::unsupported_specifier(root_specifier))
+            }
         };
         Ok(ordinals)
     }
 }
+
+#[cfg(test)]
+mod test {
+    use std::str::FromStr;
+
+    use super::*;
+
+    fn assert_display<T>(expression: &str, expected: &str)
+    where
+        T: TimeUnitField + FromStr + fmt::Display + PartialEq + fmt::Debug,
+        T::Err: fmt::Debug,
+    {
+        let field = T::from_str(expression).unwrap();
+        let displayed = field.to_string();
+        assert_eq!(expected, displayed);
+        assert_eq!(field, T::from_str(&displayed).unwrap());
+    }
+
+    #[test]
+    fn test_display_fields() {
+        assert_display::<Seconds>("*", "*");
+        assert_display::<Seconds>("0-59", "*");
+        assert_display::<Seconds>("*/15", "*/15");
+        assert_display::<Minutes>("5/15", "5/15");
+        assert_display::<Minutes>("0,20,40", "*/20");
+        assert_display::<Minutes>("10-40/10", "10-40/10");
+        assert_display::<Hours>("9,10,11,12,17", "9-12,17");
+        assert_display::<Hours>("3,4", "3,4");
+        assert_display::<Hours>("7", "7");
+        assert_display::<DaysOfMonth>("?", "*");
+        assert_display::<DaysOfMonth>("1,15", "1,15");
+        assert_display::<Months>("Jan-Mar,Dec", "1-3,12");
+        assert_display::<DaysOfWeek>("MON-FRI", "2-6");
+        assert_display::<DaysOfWeek>("1-7", "*");
+        assert_display::<DaysOfWeek>("Fri#-2,Mon", "2,6#-2");
+        assert_display::<DaysOfWeek>("Mon#1,Fri#-1", "2#1,6#-1");
+        assert_display::<DaysOfWeek>("Mon-Fri,Fri#2", "2-6");
+        assert_display::<DaysOfMonth>("L,1,15", "1,15,L");
+        assert_display::<DaysOfMonth>("15w,1W,L", "L,1W,15W");
+        assert_display::<DaysOfMonth>("lw,1", "1,LW");
+        assert_display::<DaysOfMonth>("L-0,L-3,L- 1", "L,L-1,L-3");
+        assert_display::<Years>("2018/2", "2018/2");
+        assert_display::<Years>("2020,2024", "2020,2024");
+    }
+
+    #[test]
+    fn test_names_are_parsed() {
+        for (ordinal, (full, abbreviated)) in (1..).zip(Months::names()) {
+            assert_eq!(ordinal, Months::ordinal_from_name(full).unwrap());
+            assert_eq!(ordinal, Months::ordinal_from_name(abbreviated).unwrap());
+        }
+        for (ordinal, (full, abbreviated)) in (1..).zip(DaysOfWeek::names()) {
+            assert_eq!(ordinal, DaysOfWeek::ordinal_from_name(full).unwrap());
+            assert_eq!(ordinal, DaysOfWeek::ordinal_from_name(abbreviated).unwrap());
+        }
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn test_ser_de_field_tokens() {
+        use serde_test::{assert_de_tokens, assert_tokens, Configure, Token};
+
+        let days_of_week = DaysOfWeek::from_str("Mon-Fri").unwrap();
+        assert_tokens(&days_of_week.clone().readable(), &[Token::Str("2-6")]);
+        assert_tokens(&days_of_week.clone().compact(), &[Token::Str("2-6")]);
+        assert_de_tokens(
+            &days_of_week.readable(),
+            &[
+                Token::Seq { len: Some(5) },
+                Token::U32(2),
+                Token::U32(3),
+                Token::U32(4),
+                Token::U32(5),
+                Token::U32(6),
+                Token::SeqEnd,
+            ],
+        );
+        assert_tokens(&Seconds::all().readable(), &[Token::Str("*")]);
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn test_invalid_ser_de_field_tokens() {
+        use serde_test::{assert_de_tokens_error, Readable, Token};
+
+        assert_de_tokens_error::<Readable<Hours>>(
+            &[Token::Str("25")],
+            "Invalid expression: Hours must be less than 23. ('25' specified.)",
+        );
+        assert_de_tokens_error::<Readable<Hours>>(
+            &[Token::Seq { len: Some(1) }, Token::U32(24), Token::SeqEnd],
+            "Invalid expression: Hours must be less than 23. ('24' specified.)",
+        );
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn test_ser_de_field_postcard() {
+        let serialized =
+            postcard::to_stdvec(&Minutes::from_str("5/15").unwrap()).expect("serializable field");
+        let minutes: Minutes = postcard::from_bytes(&serialized).expect("deserializable field");
+        assert_eq!(Minutes::from_str("5,20,35,50").unwrap(), minutes);
+    }
+}
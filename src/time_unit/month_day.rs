@@ -0,0 +1,175 @@
+use std::fmt::{self, Display, Formatter};
+
+use jiff::civil::Weekday;
+
+use crate::ordinal::Ordinal;
+#[cfg(feature = "serde")]
+use crate::time_unit::TimeUnit;
+
+/// A day whose position depends on the layout of its month, such as the last
+/// Friday, which the days of the month or of the week include alongside their
+/// ordinals.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum MonthDay {
+    /// The `nth` occurrence of a day of the week, numbered from Sunday 1,
+    /// within its month, counting back from the end of the month if `nth` is
+    /// negative, e.g. `6#-1` for the last Friday.
+    NthWeekday { weekday: Ordinal, nth: i8 },
+    /// The day `offset` days before the last day of the month, e.g. `L` or
+    /// `L-3`.
+    LastDay { offset: i8 },
+    /// The weekday nearest to a day of the month, within the month, e.g.
+    /// `15W`.
+    NearestWeekday { day: i8 },
+    /// The last weekday of the month, `LW`.
+    LastWeekday,
+}
+
+impl MonthDay {
+    /// The largest number of times a day of the week occurs within a month.
+    pub(crate) const MAX_OCCURRENCES: i8 = 5;
+
+    /// Returns true if `day`, a `weekday` within a month of `days_in_month`
+    /// days, is this day. Only the layout of the month is considered, so
+    /// months of other calendar systems are supported too.
+    pub(crate) fn includes(self, day: i8, weekday: Weekday, days_in_month: i8) -> bool {
+        match self {
+            MonthDay::NthWeekday {
+                weekday: ordinal,
+                nth,
+            } => {
+                ordinal == weekday.to_sunday_one_offset() as Ordinal
+                    && (nth == (day - 1) / 7 + 1 || nth == -((days_in_month - day) / 7 + 1))
+            }
+            MonthDay::LastDay { offset } => day == days_in_month - offset,
+            MonthDay::NearestWeekday { day: nearest_to } => {
+                if nearest_to > days_in_month {
+                    return false;
+                }
+                let nearest = match weekday.wrapping_add(nearest_to - day) {
+                    Weekday::Saturday if nearest_to == 1 => 3,
+                    Weekday::Saturday => nearest_to - 1,
+                    Weekday::Sunday if nearest_to == days_in_month => nearest_to - 2,
+                    Weekday::Sunday => nearest_to + 1,
+                    _ => nearest_to,
+                };
+                day == nearest
+            }
+            MonthDay::LastWeekday => {
+                let last = match weekday.wrapping_add(days_in_month - day) {
+                    Weekday::Saturday => days_in_month - 1,
+                    Weekday::Sunday => days_in_month - 2,
+                    _ => days_in_month,
+                };
+                day == last
+            }
+        }
+    }
+
+    /// Returns the day of the week this day always falls on, if any.
+    pub(crate) fn weekday(self) -> Option<Ordinal> {
+        match self {
+            MonthDay::NthWeekday { weekday, .. } => Some(weekday),
+            MonthDay::LastDay { .. } | MonthDay::NearestWeekday { .. } | MonthDay::LastWeekday => {
+                None
+            }
+        }
+    }
+
+    /// Returns the field including this day.
+    #[cfg(feature = "serde")]
+    pub(crate) fn unit(self) -> TimeUnit {
+        match self {
+            MonthDay::NthWeekday { .. } => TimeUnit::DaysOfWeek,
+            MonthDay::LastDay { .. } | MonthDay::NearestWeekday { .. } | MonthDay::LastWeekday => {
+                TimeUnit::DaysOfMonth
+            }
+        }
+    }
+}
+
+impl Display for MonthDay {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        match self {
+            MonthDay::NthWeekday { weekday, nth } => write!(f, "{}#{}", weekday, nth),
+            MonthDay::LastDay { offset: 0 } => f.write_str("L"),
+            MonthDay::LastDay { offset } => write!(f, "L-{}", offset),
+            MonthDay::NearestWeekday { day } => write!(f, "{}W", day),
+            MonthDay::LastWeekday => f.write_str("LW"),
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use jiff::civil::{date, Date};
+
+    use super::*;
+
+    fn includes(month_day: MonthDay, date: Date) -> bool {
+        month_day.includes(date.day(), date.weekday(), date.days_in_month())
+    }
+
+    #[test]
+    fn test_nth_weekday() {
+        // Fridays in January 2025 fall on the 3rd, 10th, 17th, 24th and 31st.
+        let friday = |nth| MonthDay::NthWeekday { weekday: 6, nth };
+        assert!(includes(friday(1), date(2025, 1, 3)));
+        assert!(includes(friday(5), date(2025, 1, 31)));
+        assert!(includes(friday(-1), date(2025, 1, 31)));
+        assert!(includes(friday(-2), date(2025, 1, 24)));
+        assert!(includes(friday(-5), date(2025, 1, 3)));
+        assert!(!includes(friday(-2), date(2025, 1, 17)));
+        // Thursday the 30th.
+        assert!(!includes(friday(-1), date(2025, 1, 30)));
+        // February 2025 has only four Fridays.
+        assert!(includes(friday(-2), date(2025, 2, 21)));
+        assert!(!includes(friday(5), date(2025, 2, 28)));
+    }
+
+    #[test]
+    fn test_last_day() {
+        let last = |offset| MonthDay::LastDay { offset };
+        assert!(includes(last(0), date(2024, 2, 29)));
+        assert!(!includes(last(0), date(2024, 2, 28)));
+        assert!(includes(last(0), date(2025, 2, 28)));
+        assert!(includes(last(0), date(2025, 4, 30)));
+        assert!(!includes(last(0), date(2025, 4, 29)));
+        assert!(includes(last(3), date(2025, 4, 27)));
+        assert!(includes(last(3), date(2024, 2, 26)));
+        // February 2025 has no 29 days before its last.
+        assert!(includes(last(30), date(2025, 1, 1)));
+        assert!((1..=28).all(|day| !includes(last(30), date(2025, 2, day))));
+    }
+
+    #[test]
+    fn test_nearest_weekday() {
+        let nearest = |day| MonthDay::NearestWeekday { day };
+        // Saturday the 15th of March 2025 moves to Friday.
+        assert!(includes(nearest(15), date(2025, 3, 14)));
+        assert!(!includes(nearest(15), date(2025, 3, 15)));
+        // Sunday the 15th of June 2025 moves to Monday.
+        assert!(includes(nearest(15), date(2025, 6, 16)));
+        // Wednesday the 15th of January.
+        assert!(includes(nearest(15), date(2025, 1, 15)));
+        // Saturday the 1st of March 2025 moves forward, staying in March.
+        assert!(includes(nearest(1), date(2025, 3, 3)));
+        assert!(!includes(nearest(1), date(2025, 2, 28)));
+        // Sunday the 30th of November 2025 moves back to Friday.
+        assert!(includes(nearest(30), date(2025, 11, 28)));
+        // February has no 30th.
+        assert!(!includes(nearest(30), date(2025, 2, 28)));
+    }
+
+    #[test]
+    fn test_last_weekday() {
+        // Friday the 31st of January 2025.
+        assert!(includes(MonthDay::LastWeekday, date(2025, 1, 31)));
+        // Sunday the 31st of August 2025 moves back to Friday.
+        assert!(includes(MonthDay::LastWeekday, date(2025, 8, 29)));
+        assert!(!includes(MonthDay::LastWeekday, date(2025, 8, 31)));
+        // Saturday the 31st of May 2025.
+        assert!(includes(MonthDay::LastWeekday, date(2025, 5, 30)));
+        assert!(!includes(MonthDay::LastWeekday, date(2025, 5, 29)));
+    }
+}
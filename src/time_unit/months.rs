@@ -1,26 +1,142 @@
-use std::{borrow::Cow, sync::LazyLock};
+use std::fmt::{self, Display, Formatter};
 
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+
+#[cfg(feature = "serde")]
+use crate::time_unit::deserialize_field;
 use crate::{
     error::*,
     ordinal::{Ordinal, OrdinalSet},
-    time_unit::TimeUnitField,
+    specifier::RootSpecifier,
+    time_unit::{fmt_field, lowercase_name, OrdinalIter, TimeUnit, TimeUnitField, TimeUnitSpec},
 };
 
-static ALL: LazyLock<OrdinalSet> = LazyLock::new(Months::supported_ordinals);
-
-#[derive(Clone, Debug, Eq)]
+/// The set of the months of the year included in a
+/// [`Schedule`](crate::Schedule).
+#[derive(Clone, Debug, PartialEq, Eq)]
 pub struct Months {
-    ordinals: Option<OrdinalSet>,
+    ordinals: OrdinalSet,
+}
+
+const NAMES: [(&str, &str); 12] = [
+    ("January", "Jan"),
+    ("February", "Feb"),
+    ("March", "Mar"),
+    ("April", "Apr"),
+    ("May", "May"),
+    ("June", "Jun"),
+    ("July", "Jul"),
+    ("August", "Aug"),
+    ("September", "Sep"),
+    ("October", "Oct"),
+    ("November", "Nov"),
+    ("December", "Dec"),
+];
+
+impl Months {
+    /// Creates the set of the months of the year described by `specifiers`, as
+    /// if they were parsed from a field's comma-separated list.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use std::str::FromStr;
+    ///
+    /// use jiff_cron::{Months, RootSpecifier, Specifier, TimeUnitSpec};
+    ///
+    /// // Equivalent to "Jun-Aug"
+    /// let field = Months::from_specifiers(&[RootSpecifier::from(Specifier::NamedRange(
+    ///     "Jun".into(),
+    ///     "Aug".into(),
+    /// ))])
+    /// .unwrap();
+    /// assert_eq!(3, field.count());
+    /// assert_eq!(Months::from_str("Jun-Aug").unwrap(), field);
+    /// ```
+    pub fn from_specifiers(specifiers: &[RootSpecifier]) -> Result<Months, Error> {
+        <Months as TimeUnitField>::from_specifiers(specifiers)
+    }
+
+    /// Returns the full and abbreviated English name of each month, starting
+    /// with January. Both forms are accepted by the parser in any case.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use jiff_cron::Months;
+    ///
+    /// assert_eq!(("March", "Mar"), Months::names()[3 - 1]);
+    /// ```
+    pub fn names() -> &'static [(&'static str, &'static str)] {
+        &NAMES
+    }
+
+    /// Creates the set of months containing each of `months`, using jiff's
+    /// representation of months (`1` for January through `12` for December).
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use jiff_cron::{Months, TimeUnitSpec};
+    ///
+    /// let summer = Months::from_civil_months([6, 7, 8]).unwrap();
+    /// assert_eq!(3, summer.count());
+    /// assert!(Months::from_civil_months([13]).is_err());
+    /// ```
+    pub fn from_civil_months<I>(months: I) -> Result<Months, Error>
+    where
+        I: IntoIterator<Item = i8>,
+    {
+        let mut ordinals = OrdinalSet::new();
+        for month in months {
+            let ordinal =
+                Ordinal::try_from(month).map_err(|_| Months::invalid_ordinal(i64::from(month)))?;
+            ordinals.insert(Months::validate_ordinal(ordinal)?);
+        }
+        Ok(Months::from_ordinal_set(ordinals))
+    }
+
+    /// Provides an iterator which will return each included month as accepted
+    /// by jiff's date constructors, in order from January to December.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use std::str::FromStr;
+    ///
+    /// use jiff_cron::{jiff::civil::date, Schedule};
+    ///
+    /// let schedule = Schedule::from_str("0 0 0 1 Jun-Aug *").unwrap();
+    /// let first_days: Vec<_> = schedule
+    ///     .months()
+    ///     .civil_months()
+    ///     .map(|month| date(2025, month, 1))
+    ///     .collect();
+    /// assert_eq!(
+    ///     vec![date(2025, 6, 1), date(2025, 7, 1), date(2025, 8, 1)],
+    ///     first_days
+    /// );
+    /// ```
+    pub fn civil_months(&self) -> impl DoubleEndedIterator<Item = i8> + '_ {
+        self.iter().map(|ordinal| ordinal as i8)
+    }
+
+    /// Returns true if the provided month, as represented by jiff, is
+    /// included.
+    pub fn includes_civil_month(&self, month: i8) -> bool {
+        Ordinal::try_from(month).is_ok_and(|ordinal| self.includes(ordinal))
+    }
 }
 
 impl TimeUnitField for Months {
     fn from_optional_ordinal_set(ordinal_set: Option<OrdinalSet>) -> Self {
         Months {
-            ordinals: ordinal_set,
+            ordinals: ordinal_set.unwrap_or_else(Months::supported_ordinals),
         }
     }
-    fn name() -> Cow<'static, str> {
-        Cow::from("Months")
+    fn unit() -> TimeUnit {
+        TimeUnit::Months
     }
     fn inclusive_min() -> Ordinal {
         1
@@ -30,7 +146,8 @@ impl TimeUnitField for Months {
     }
     fn ordinal_from_name(name: &str) -> Result<Ordinal, Error> {
         //TODO: Use phf crate
-        let ordinal = match name.to_lowercase().as_ref() {
+        let mut buffer = Default::default();
+        let ordinal = match lowercase_name(name, &mut buffer).unwrap_or_default() {
             "jan" | "january" => 1,
             "feb" | "february" => 2,
             "mar" | "march" => 3,
@@ -44,23 +161,58 @@ impl TimeUnitField for Months {
             "nov" | "november" => 11,
             "dec" | "december" => 12,
             _ => {
-                return Err(
-                    ErrorKind::Expression(format!("'{}' is not a valid month name.", name)).into(),
-                )
+                return Err(ErrorKind::UnknownName {
+                    unit: TimeUnit::Months,
+                    name: name.to_owned(),
+                }
+                .into())
             }
         };
         Ok(ordinal)
     }
     fn ordinals(&self) -> &OrdinalSet {
-        match &self.ordinals {
-            Some(ordinal_set) => ordinal_set,
-            None => &ALL,
-        }
+        &self.ordinals
+    }
+}
+
+impl<'a> IntoIterator for &'a Months {
+    type Item = Ordinal;
+    type IntoIter = OrdinalIter<'a>;
+
+    fn into_iter(self) -> OrdinalIter<'a> {
+        self.iter()
+    }
+}
+
+impl Display for Months {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        fmt_field(self, f)
+    }
+}
+
+#[cfg(feature = "defmt")]
+impl defmt::Format for Months {
+    fn format(&self, f: defmt::Formatter<'_>) {
+        defmt::write!(f, "{}", defmt::Display2Format(self))
+    }
+}
+
+#[cfg(feature = "serde")]
+impl Serialize for Months {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        serializer.collect_str(self)
     }
 }
 
-impl PartialEq for Months {
-    fn eq(&self, other: &Months) -> bool {
-        self.ordinals() == other.ordinals()
+#[cfg(feature = "serde")]
+impl<'de> Deserialize<'de> for Months {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        deserialize_field(deserializer)
     }
 }
@@ -1,25 +1,53 @@
-use std::{borrow::Cow, sync::LazyLock};
+use std::fmt::{self, Display, Formatter};
 
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+
+#[cfg(feature = "serde")]
+use crate::time_unit::deserialize_field;
 use crate::{
+    error::*,
     ordinal::{Ordinal, OrdinalSet},
-    time_unit::TimeUnitField,
+    specifier::RootSpecifier,
+    time_unit::{fmt_field, OrdinalIter, TimeUnit, TimeUnitField, TimeUnitSpec},
 };
 
-static ALL: LazyLock<OrdinalSet> = LazyLock::new(Seconds::supported_ordinals);
-
-#[derive(Clone, Debug, Eq)]
+/// The set of the seconds of the minute included in a
+/// [`Schedule`](crate::Schedule).
+#[derive(Clone, Debug, PartialEq, Eq)]
 pub struct Seconds {
-    ordinals: Option<OrdinalSet>,
+    ordinals: OrdinalSet,
+}
+
+impl Seconds {
+    /// Creates the set of the seconds of the minute described by `specifiers`,
+    /// as if they were parsed from a field's comma-separated list.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use std::str::FromStr;
+    ///
+    /// use jiff_cron::{RootSpecifier, Seconds, Specifier, TimeUnitSpec};
+    ///
+    /// // Equivalent to "*/15"
+    /// let field = Seconds::from_specifiers(&[RootSpecifier::Period(Specifier::All, 15)]).unwrap();
+    /// assert_eq!(4, field.count());
+    /// assert_eq!(Seconds::from_str("*/15").unwrap(), field);
+    /// ```
+    pub fn from_specifiers(specifiers: &[RootSpecifier]) -> Result<Seconds, Error> {
+        <Seconds as TimeUnitField>::from_specifiers(specifiers)
+    }
 }
 
 impl TimeUnitField for Seconds {
     fn from_optional_ordinal_set(ordinal_set: Option<OrdinalSet>) -> Self {
         Seconds {
-            ordinals: ordinal_set,
+            ordinals: ordinal_set.unwrap_or_else(Seconds::supported_ordinals),
         }
     }
-    fn name() -> Cow<'static, str> {
-        Cow::from("Seconds")
+    fn unit() -> TimeUnit {
+        TimeUnit::Seconds
     }
     fn inclusive_min() -> Ordinal {
         0
@@ -28,15 +56,48 @@ impl TimeUnitField for Seconds {
         59
     }
     fn ordinals(&self) -> &OrdinalSet {
-        match &self.ordinals {
-            Some(ordinal_set) => ordinal_set,
-            None => &ALL,
-        }
+        &self.ordinals
+    }
+}
+
+impl<'a> IntoIterator for &'a Seconds {
+    type Item = Ordinal;
+    type IntoIter = OrdinalIter<'a>;
+
+    fn into_iter(self) -> OrdinalIter<'a> {
+        self.iter()
+    }
+}
+
+impl Display for Seconds {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        fmt_field(self, f)
+    }
+}
+
+#[cfg(feature = "defmt")]
+impl defmt::Format for Seconds {
+    fn format(&self, f: defmt::Formatter<'_>) {
+        defmt::write!(f, "{}", defmt::Display2Format(self))
+    }
+}
+
+#[cfg(feature = "serde")]
+impl Serialize for Seconds {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        serializer.collect_str(self)
     }
 }
 
-impl PartialEq for Seconds {
-    fn eq(&self, other: &Seconds) -> bool {
-        self.ordinals() == other.ordinals()
+#[cfg(feature = "serde")]
+impl<'de> Deserialize<'de> for Seconds {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        deserialize_field(deserializer)
     }
 }
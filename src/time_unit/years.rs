@@ -1,29 +1,53 @@
-use std::{borrow::Cow, sync::LazyLock};
+use std::fmt::{self, Display, Formatter};
 
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+
+#[cfg(feature = "serde")]
+use crate::time_unit::deserialize_field;
 use crate::{
+    error::*,
     ordinal::{Ordinal, OrdinalSet},
-    time_unit::TimeUnitField,
+    specifier::RootSpecifier,
+    time_unit::{fmt_field, OrdinalIter, TimeUnit, TimeUnitField, TimeUnitSpec},
 };
 
-static ALL: LazyLock<OrdinalSet> = LazyLock::new(Years::supported_ordinals);
-
-#[derive(Clone, Debug, Eq)]
+/// The set of the years included in a [`Schedule`](crate::Schedule).
+#[derive(Clone, Debug, PartialEq, Eq)]
 pub struct Years {
-    ordinals: Option<OrdinalSet>,
+    ordinals: OrdinalSet,
+}
+
+impl Years {
+    /// Creates the set of the years described by `specifiers`, as if
+    /// they were parsed from a field's comma-separated list.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use std::str::FromStr;
+    ///
+    /// use jiff_cron::{RootSpecifier, Specifier, TimeUnitSpec, Years};
+    ///
+    /// // Equivalent to "2030"
+    /// let field = Years::from_specifiers(&[RootSpecifier::from(Specifier::Point(2030))]).unwrap();
+    /// assert_eq!(1, field.count());
+    /// assert_eq!(Years::from_str("2030").unwrap(), field);
+    /// ```
+    pub fn from_specifiers(specifiers: &[RootSpecifier]) -> Result<Years, Error> {
+        <Years as TimeUnitField>::from_specifiers(specifiers)
+    }
 }
 
 impl TimeUnitField for Years {
     fn from_optional_ordinal_set(ordinal_set: Option<OrdinalSet>) -> Self {
         Years {
-            ordinals: ordinal_set,
+            ordinals: ordinal_set.unwrap_or_else(Years::supported_ordinals),
         }
     }
-    fn name() -> Cow<'static, str> {
-        Cow::from("Years")
+    fn unit() -> TimeUnit {
+        TimeUnit::Years
     }
-
-    // TODO: Using the default impl, this will make a set w/100+ items each time "*"
-    // is used. This is obviously suboptimal.
     fn inclusive_min() -> Ordinal {
         1970
     }
@@ -31,15 +55,48 @@ impl TimeUnitField for Years {
         2100
     }
     fn ordinals(&self) -> &OrdinalSet {
-        match &self.ordinals {
-            Some(ordinal_set) => ordinal_set,
-            None => &ALL,
-        }
+        &self.ordinals
+    }
+}
+
+impl<'a> IntoIterator for &'a Years {
+    type Item = Ordinal;
+    type IntoIter = OrdinalIter<'a>;
+
+    fn into_iter(self) -> OrdinalIter<'a> {
+        self.iter()
+    }
+}
+
+impl Display for Years {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        fmt_field(self, f)
+    }
+}
+
+#[cfg(feature = "defmt")]
+impl defmt::Format for Years {
+    fn format(&self, f: defmt::Formatter<'_>) {
+        defmt::write!(f, "{}", defmt::Display2Format(self))
+    }
+}
+
+#[cfg(feature = "serde")]
+impl Serialize for Years {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        serializer.collect_str(self)
     }
 }
 
-impl PartialEq for Years {
-    fn eq(&self, other: &Years) -> bool {
-        self.ordinals() == other.ordinals()
+#[cfg(feature = "serde")]
+impl<'de> Deserialize<'de> for Years {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        deserialize_field(deserializer)
     }
 }
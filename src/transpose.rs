@@ -0,0 +1,236 @@
+use std::fmt;
+
+use jiff::{
+    civil::{date, Time},
+    tz::{Offset, TimeZone},
+    Timestamp, Zoned,
+};
+
+use crate::{
+    error::{Error, ErrorKind},
+    ordinal::{Ordinal, OrdinalSet},
+    schedule::Schedule,
+    time_unit::{DaysOfWeek, Hours, Minutes, Seconds, TimeUnitField, TimeUnitSpec},
+};
+
+const SECONDS_PER_DAY: i32 = 86_400;
+
+/// Why a schedule can't be expressed in another time zone, returned within
+/// [`ErrorKind::NotTransposable`] by [`Schedule::transpose`].
+#[derive(Clone, Debug, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum TransposeObstacle {
+    /// A time zone's offset changes during the schedule's years, e.g. for
+    /// DST, so no single schedule fires at the same instants in both.
+    VaryingOffset {
+        /// When the offset first changes, in the time zone it changes in
+        at: Zoned,
+    },
+    /// The shifted times of day aren't every combination of some hours,
+    /// minutes and seconds, e.g. `0 0,30 9,10` shifted by 30 minutes.
+    SplitTimes,
+    /// Some times of day are shifted to the next or previous day, but others
+    /// aren't.
+    MixedDays,
+    /// Every time of day is shifted to the next or previous day, but the days
+    /// of the month, months or years can't be shifted with them.
+    ShiftedDates,
+}
+
+impl fmt::Display for TransposeObstacle {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            TransposeObstacle::VaryingOffset { at } => {
+                write!(f, "The time zone's offset changes at {}", at)
+            }
+            TransposeObstacle::SplitTimes => {
+                write!(f, "The shifted times of day can't be written as fields")
+            }
+            TransposeObstacle::MixedDays => {
+                write!(f, "Only some times of day are shifted to another day")
+            }
+            TransposeObstacle::ShiftedDates => write!(
+                f,
+                "The times of day are shifted to another day, but the dates can't be"
+            ),
+        }
+    }
+}
+
+pub(crate) fn transpose(
+    schedule: &Schedule,
+    from: &TimeZone,
+    to: &TimeZone,
+) -> Result<Schedule, Error> {
+    let first_year = schedule.years().iter().next().unwrap_or(1970);
+    // A day early, so that fire times on the first day are covered in every
+    // time zone.
+    let start = date(first_year as i16, 1, 1)
+        .yesterday()
+        .and_then(|day| day.to_zoned(TimeZone::UTC))
+        .map(|start| start.timestamp())
+        .unwrap_or(Timestamp::MIN);
+    let shift = fixed_offset(to, start)?.seconds() - fixed_offset(from, start)?.seconds();
+    let Some(times) = schedule.daily_times() else {
+        // A schedule never firing never fires in any time zone.
+        return Ok(schedule.clone());
+    };
+
+    // Schedules firing every day can have their times of day shifted to
+    // different days.
+    let every_day = schedule.days_of_month().is_all()
+        && schedule.months().is_all()
+        && schedule.days_of_week().is_all()
+        && schedule.years().is_all();
+    let mut days: Option<i32> = None;
+    let mut hours = OrdinalSet::new();
+    let mut minutes = OrdinalSet::new();
+    let mut seconds = OrdinalSet::new();
+    for time in &times {
+        let shifted = seconds_of_day(*time) + shift;
+        let day = shifted.div_euclid(SECONDS_PER_DAY);
+        if *days.get_or_insert(day) != day && !every_day {
+            return Err(obstacle(TransposeObstacle::MixedDays));
+        }
+        let shifted = shifted.rem_euclid(SECONDS_PER_DAY) as Ordinal;
+        hours.insert(shifted / 3600);
+        minutes.insert(shifted / 60 % 60);
+        seconds.insert(shifted % 60);
+    }
+    if hours.len() * minutes.len() * seconds.len() != times.len() {
+        return Err(obstacle(TransposeObstacle::SplitTimes));
+    }
+
+    let days = days.unwrap_or(0);
+    let days_of_week = if days == 0 || every_day {
+        schedule.days_of_week().clone()
+    } else {
+        // Occurrences within the month, e.g. of `Fri#-1`, aren't shifted
+        // along with their days of the week, as the next day may fall in the
+        // next month.
+        if !schedule.days_of_month().is_all()
+            || !schedule.months().is_all()
+            || !schedule.years().is_all()
+            || !schedule.days_of_week().month_days().is_empty()
+        {
+            return Err(obstacle(TransposeObstacle::ShiftedDates));
+        }
+        // Days of the week are numbered from Sunday 1.
+        DaysOfWeek::from_ordinal_set(
+            schedule
+                .days_of_week()
+                .iter()
+                .map(|weekday| (weekday as i32 - 1 + days).rem_euclid(7) as Ordinal + 1)
+                .collect(),
+        )
+    };
+    let transposed = Schedule::from_fields(
+        Seconds::from_ordinal_set(seconds),
+        Minutes::from_ordinal_set(minutes),
+        Hours::from_ordinal_set(hours),
+        schedule.days_of_month().clone(),
+        schedule.months().clone(),
+        days_of_week,
+        schedule.years().clone(),
+    );
+    if schedule.is_year_bounded() {
+        Ok(transposed)
+    } else {
+        Ok(transposed.with_unbounded_years())
+    }
+}
+
+/// Returns the offset of `time_zone` from `start` on, if it never changes.
+fn fixed_offset(time_zone: &TimeZone, start: Timestamp) -> Result<Offset, Error> {
+    match time_zone.following(start).next() {
+        Some(transition) => Err(obstacle(TransposeObstacle::VaryingOffset {
+            at: transition.timestamp().to_zoned(time_zone.clone()),
+        })),
+        None => Ok(time_zone.to_offset(start)),
+    }
+}
+
+fn seconds_of_day(time: Time) -> i32 {
+    i32::from(time.hour()) * 3600 + i32::from(time.minute()) * 60 + i32::from(time.second())
+}
+
+fn obstacle(obstacle: TransposeObstacle) -> Error {
+    ErrorKind::NotTransposable(obstacle).into()
+}
+
+#[cfg(test)]
+mod test {
+    use std::str::FromStr;
+
+    use super::*;
+
+    fn transposed(expression: &str, from: &str, to: &str) -> Result<String, ErrorKind> {
+        let from = TimeZone::get(from).unwrap();
+        let to = TimeZone::get(to).unwrap();
+        Schedule::from_str(expression)
+            .unwrap()
+            .transpose(&from, &to)
+            .map(|schedule| schedule.to_string())
+            .map_err(|error| error.kind().clone())
+    }
+
+    #[test]
+    fn test_transpose() {
+        assert_eq!(
+            Ok("0 30 14 * * 2-6 *".to_owned()),
+            transposed("0 0 9 * * Mon-Fri", "UTC", "Asia/Kolkata")
+        );
+        // Shifted across midnight, along with the days of the week.
+        assert_eq!(
+            Ok("0 0 1 * * 4,7 *".to_owned()),
+            transposed("0 0 16 * * Tue,Fri", "UTC", "Asia/Tokyo")
+        );
+        assert_eq!(
+            Ok("0 0 22 * * 1 *".to_owned()),
+            transposed("0 0 7 * * Mon", "Asia/Tokyo", "UTC")
+        );
+        assert_eq!(
+            Ok("0 30 * * * * *".to_owned()),
+            transposed("0 0 * * * *", "UTC", "Asia/Kolkata")
+        );
+
+        assert_eq!(
+            Err(ErrorKind::NotTransposable(TransposeObstacle::MixedDays)),
+            transposed("0 0 9,20 * * Mon", "UTC", "Asia/Tokyo")
+        );
+        assert_eq!(
+            Err(ErrorKind::NotTransposable(TransposeObstacle::SplitTimes)),
+            transposed("0 0,45 9 * * *", "UTC", "Asia/Kolkata")
+        );
+        assert_eq!(
+            Err(ErrorKind::NotTransposable(TransposeObstacle::ShiftedDates)),
+            transposed("0 0 20 1 * *", "UTC", "Asia/Tokyo")
+        );
+        assert_eq!(
+            Err(ErrorKind::NotTransposable(TransposeObstacle::ShiftedDates)),
+            transposed("0 0 20 * * Fri#-1", "UTC", "Asia/Tokyo")
+        );
+        assert!(matches!(
+            transposed("0 0 9 * * *", "UTC", "Europe/Berlin"),
+            Err(ErrorKind::NotTransposable(
+                TransposeObstacle::VaryingOffset { .. }
+            ))
+        ));
+    }
+
+    #[test]
+    fn test_transposed_fire_times() {
+        let utc = TimeZone::UTC;
+        let tokyo = TimeZone::get("Asia/Tokyo").unwrap();
+        let schedule = Schedule::from_str("0 0 18 * * Sat").unwrap();
+        let transposed = schedule.transpose(&utc, &tokyo).unwrap();
+        let start = date(2025, 1, 1).to_zoned(utc).unwrap();
+        let expected: Vec<_> = schedule.after(&start).take(10).collect();
+        let fires: Vec<_> = transposed
+            .after(&start.with_time_zone(tokyo))
+            .take(10)
+            .collect();
+        let instants = |fires: Vec<Zoned>| fires.iter().map(Zoned::timestamp).collect::<Vec<_>>();
+        assert_eq!(instants(expected), instants(fires));
+    }
+}
@@ -0,0 +1,244 @@
+use std::{
+    fmt::{self, Display, Formatter},
+    iter::Peekable,
+    str::FromStr,
+};
+
+use jiff::{tz::TimeZone, Zoned};
+
+use crate::{
+    error::Error,
+    recurrence::{Recurrence, RecurrenceIterator},
+    schedule::Schedule,
+};
+
+/// The union of several schedules, firing whenever any of them does, for
+/// jobs which run on either of a few patterns.
+///
+/// A union is written as its expressions separated by semicolons. Unions of
+/// any other [`Recurrence`], such as a `ScheduleUnion<Box<dyn Recurrence>>`
+/// mixing schedules with sunsets, are created with [`ScheduleUnion::new`].
+///
+/// # Example
+///
+/// ```rust
+/// use std::str::FromStr;
+///
+/// use jiff_cron::{
+///     jiff::{civil::date, tz::TimeZone},
+///     ScheduleUnion,
+/// };
+///
+/// let union = ScheduleUnion::from_str("0 0 9 * * Mon-Fri; 0 0 10 * * Sat").unwrap();
+/// let after = date(2025, 1, 3)
+///     .at(12, 0, 0, 0)
+///     .to_zoned(TimeZone::UTC)
+///     .unwrap();
+/// let fires: Vec<_> = union
+///     .after(&after)
+///     .take(2)
+///     .map(|fire| fire.datetime())
+///     .collect();
+/// assert_eq!(
+///     vec![
+///         date(2025, 1, 4).at(10, 0, 0, 0),
+///         date(2025, 1, 6).at(9, 0, 0, 0)
+///     ],
+///     fires
+/// );
+/// ```
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct ScheduleUnion<R = Schedule> {
+    schedules: Vec<R>,
+}
+
+impl<R> ScheduleUnion<R>
+where
+    R: Recurrence,
+{
+    /// Creates the union of `schedules`.
+    pub fn new(schedules: Vec<R>) -> ScheduleUnion<R> {
+        ScheduleUnion { schedules }
+    }
+
+    /// Returns the schedules making up the union.
+    pub fn schedules(&self) -> &[R] {
+        &self.schedules
+    }
+
+    /// Provides an iterator over the fire times of every schedule after
+    /// `after`, in chronological order. Fire times shared by several
+    /// schedules are returned once.
+    pub fn after(&self, after: &Zoned) -> UnionIterator<'_, R> {
+        UnionIterator {
+            iters: self
+                .schedules
+                .iter()
+                .map(|schedule| schedule.iter_after(after).peekable())
+                .collect(),
+        }
+    }
+
+    /// Like [`ScheduleUnion::after`], starting from the current time.
+    pub fn upcoming(&self, time_zone: TimeZone) -> UnionIterator<'_, R> {
+        self.after(&Zoned::now().with_time_zone(time_zone))
+    }
+
+    /// Returns whether any of the schedules includes `date_time`, see
+    /// [`Recurrence::includes`].
+    pub fn includes(&self, date_time: &Zoned) -> bool {
+        self.schedules
+            .iter()
+            .any(|schedule| schedule.includes(date_time))
+    }
+}
+
+impl ScheduleUnion {
+    /// Parses each of `expressions` into the union of their schedules,
+    /// failing with the error of the first invalid one.
+    pub fn from_exprs(expressions: &[&str]) -> Result<ScheduleUnion, Error> {
+        let schedules = expressions
+            .iter()
+            .map(|expression| Schedule::from_str(expression))
+            .collect::<Result<_, _>>()?;
+        Ok(ScheduleUnion { schedules })
+    }
+}
+
+impl FromStr for ScheduleUnion {
+    type Err = Error;
+
+    /// Parses expressions separated by semicolons. The span of an error
+    /// within an invalid expression is relative to the whole text.
+    fn from_str(expressions: &str) -> Result<ScheduleUnion, Error> {
+        let mut schedules = Vec::new();
+        let mut offset = 0;
+        for expression in expressions.split(';') {
+            match Schedule::from_str(expression) {
+                Ok(schedule) => schedules.push(schedule),
+                Err(error) => return Err(error.within(expressions, offset)),
+            }
+            offset += expression.len() + 1;
+        }
+        Ok(ScheduleUnion { schedules })
+    }
+}
+
+impl Display for ScheduleUnion {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        for (index, schedule) in self.schedules.iter().enumerate() {
+            if index > 0 {
+                f.write_str("; ")?;
+            }
+            f.write_str(schedule.source().trim())?;
+        }
+        Ok(())
+    }
+}
+
+impl From<Vec<Schedule>> for ScheduleUnion {
+    fn from(schedules: Vec<Schedule>) -> ScheduleUnion {
+        ScheduleUnion::new(schedules)
+    }
+}
+
+/// An iterator over the merged fire times of a [`ScheduleUnion`].
+#[derive(Clone, Debug)]
+pub struct UnionIterator<'a, R = Schedule>
+where
+    R: Recurrence,
+{
+    iters: Vec<Peekable<RecurrenceIterator<'a, R>>>,
+}
+
+impl<R> Iterator for UnionIterator<'_, R>
+where
+    R: Recurrence,
+{
+    type Item = Zoned;
+
+    fn next(&mut self) -> Option<Zoned> {
+        let next = self
+            .iters
+            .iter_mut()
+            .filter_map(|iter| iter.peek())
+            .min()?
+            .clone();
+        for iter in &mut self.iters {
+            iter.next_if_eq(&next);
+        }
+        Some(next)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use jiff::civil::date;
+
+    use super::*;
+
+    #[test]
+    fn test_union_merges_shared_fire_times() {
+        let union = ScheduleUnion::from_exprs(&["0 0 * * * *", "0 */30 * * * *"]).unwrap();
+        let after = date(2025, 1, 1)
+            .at(0, 0, 0, 0)
+            .to_zoned(TimeZone::UTC)
+            .unwrap();
+        let minutes: Vec<_> = union
+            .after(&after)
+            .take(4)
+            .map(|fire| fire.minute())
+            .collect();
+        assert_eq!(vec![30, 0, 30, 0], minutes);
+        assert!(union.includes(&after));
+
+        let union = ScheduleUnion::from_exprs(&["0 0 0 1 1 * 2020", "0 0 0 1 1 * 2021"]).unwrap();
+        assert_eq!(0, union.after(&after).count());
+        assert_eq!(
+            0,
+            ScheduleUnion::<Schedule>::new(Vec::new())
+                .after(&after)
+                .count()
+        );
+    }
+
+    #[test]
+    fn test_union_from_str() {
+        let union = ScheduleUnion::from_str(" @daily ;0 0 12 * * Sat,Sun").unwrap();
+        assert_eq!(2, union.schedules().len());
+        assert_eq!("@daily; 0 0 12 * * Sat,Sun", union.to_string());
+
+        let expressions = "@daily; 0 0 12 * * Caturday";
+        let error = ScheduleUnion::from_str(expressions).unwrap_err();
+        assert_eq!(Some(expressions), error.expression());
+        assert_eq!(Some("Caturday"), error.token());
+        assert_eq!(Some(19..27), error.span());
+
+        assert!(ScheduleUnion::from_str("@daily;").is_err());
+    }
+
+    #[cfg(feature = "solar")]
+    #[test]
+    fn test_union_of_recurrences() {
+        use crate::{SolarEvent, SolarSchedule};
+
+        let union: ScheduleUnion<Box<dyn Recurrence>> = ScheduleUnion::new(vec![
+            Box::new(Schedule::from_str("0 0 12 * * *").unwrap()),
+            Box::new(SolarSchedule::new(SolarEvent::Sunset, 0.0, 0.0)),
+        ]);
+        let after = date(2025, 1, 1)
+            .at(0, 0, 0, 0)
+            .to_zoned(TimeZone::UTC)
+            .unwrap();
+        let hours: Vec<_> = union
+            .after(&after)
+            .take(4)
+            .map(|fire| fire.hour())
+            .collect();
+        assert_eq!(vec![12, 18, 12, 18], hours);
+        let noon = date(2025, 1, 1).at(12, 0, 0, 0).in_tz("UTC").unwrap();
+        assert!(union.includes(&noon));
+        let sunset = union.prev_before(&after).unwrap();
+        assert_eq!((date(2024, 12, 31), 18), (sunset.date(), sunset.hour()));
+    }
+}
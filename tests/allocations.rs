@@ -0,0 +1,86 @@
+//! Regression tests for the number of heap allocations made while parsing.
+//!
+//! These live in their own test binary so the counting allocator doesn't
+//! affect any other tests.
+
+use std::{
+    alloc::{GlobalAlloc, Layout, System},
+    cell::Cell,
+};
+
+struct CountingAllocator;
+
+thread_local! {
+    static ALLOCATIONS: Cell<usize> = const { Cell::new(0) };
+}
+
+unsafe impl GlobalAlloc for CountingAllocator {
+    unsafe fn alloc(&self, layout: Layout) -> *mut u8 {
+        let _ = ALLOCATIONS.try_with(|count| count.set(count.get() + 1));
+        System.alloc(layout)
+    }
+
+    unsafe fn dealloc(&self, ptr: *mut u8, layout: Layout) {
+        System.dealloc(ptr, layout)
+    }
+
+    unsafe fn realloc(&self, ptr: *mut u8, layout: Layout, new_size: usize) -> *mut u8 {
+        let _ = ALLOCATIONS.try_with(|count| count.set(count.get() + 1));
+        System.realloc(ptr, layout, new_size)
+    }
+}
+
+#[global_allocator]
+static ALLOCATOR: CountingAllocator = CountingAllocator;
+
+/// Returns the number of allocations made on this thread while running `f`.
+fn count_allocations<T>(f: impl FnOnce() -> T) -> usize {
+    let before = ALLOCATIONS.with(Cell::get);
+    let value = f();
+    let after = ALLOCATIONS.with(Cell::get);
+    drop(value);
+    after - before
+}
+
+#[cfg(test)]
+mod tests {
+    use std::str::FromStr;
+
+    use jiff_cron::{DaysOfWeek, Schedule};
+
+    use super::count_allocations;
+
+    #[test]
+    fn test_parse_allocations() {
+        // Every schedule allocates a copy of its source and the two `Arc`s
+        // shared between its clones. Names and lists of more than one
+        // specifier are allocated while parsing, as are sparse years.
+        for (expression, max_allocations) in [
+            ("@daily", 3),
+            ("0 0 0 1 Jan * 2030", 4),
+            ("0 */15 9-17 * * Mon-Fri *", 5),
+            ("0 30 9,12,15 1,15 May-Aug Mon,Wed,Fri 2018/2", 20),
+        ] {
+            let allocations = count_allocations(|| Schedule::from_str(expression).unwrap());
+            assert!(
+                allocations <= max_allocations,
+                "parsing '{}' allocated {} times, expected at most {}",
+                expression,
+                allocations,
+                max_allocations
+            );
+        }
+    }
+
+    #[test]
+    fn test_field_allocations() {
+        assert_eq!(0, count_allocations(|| DaysOfWeek::from_str("*").unwrap()));
+        assert_eq!(
+            0,
+            count_allocations(|| DaysOfWeek::from_str("2-6").unwrap())
+        );
+        // Only the names themselves are copied, not their lowercase forms.
+        let allocations = count_allocations(|| DaysOfWeek::from_str("MON-FRI").unwrap());
+        assert_eq!(2, allocations);
+    }
+}
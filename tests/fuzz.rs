@@ -0,0 +1,181 @@
+//! A deterministic fuzzing harness for the query engine: random schedules are
+//! searched from random instants, including extreme years and the instants
+//! around DST transitions, checking that searches neither panic nor return
+//! fire times on the wrong side of where they started.
+
+use std::str::FromStr;
+
+use jiff::{tz::TimeZone, SignedDuration, Timestamp, Zoned};
+use jiff_cron::{Schedule, SearchBudget};
+
+/// How many schedules and instants are tried, unless overridden by the
+/// `JIFF_CRON_FUZZ_ITERATIONS` environment variable.
+const ITERATIONS: u64 = 2_000;
+
+const TIME_ZONES: [&str; 6] = [
+    "UTC",
+    "Europe/London",
+    "America/New_York",
+    "Australia/Lord_Howe",
+    "Pacific/Kiritimati",
+    "America/St_Johns",
+];
+
+/// A xorshift generator, so failures reproduce from the printed seed.
+struct Rng(u64);
+
+impl Rng {
+    fn next(&mut self) -> u64 {
+        self.0 ^= self.0 << 13;
+        self.0 ^= self.0 >> 7;
+        self.0 ^= self.0 << 17;
+        self.0
+    }
+
+    fn below(&mut self, n: u64) -> u64 {
+        self.next() % n
+    }
+
+    fn between(&mut self, min: u64, max: u64) -> u64 {
+        min + self.below(max - min + 1)
+    }
+}
+
+fn field(rng: &mut Rng, min: u64, max: u64, names: &[&str]) -> String {
+    // Steps may not exceed the unit's maximum.
+    let max_step = max.min(10);
+    let mut specifiers = Vec::new();
+    for _ in 0..rng.between(1, 3) {
+        let start = rng.between(min, max);
+        let end = rng.between(start, max);
+        let specifier = match rng.below(8) {
+            0 => "*".to_owned(),
+            1 => format!("*/{}", rng.between(1, max.min(100))),
+            2 => format!("{}-{}", start, end),
+            3 => format!("{}-{}/{}", start, end, rng.between(1, max_step)),
+            4 => format!("{}/{}", start, rng.between(1, max_step)),
+            5 if !names.is_empty() => names[rng.below(names.len() as u64) as usize].to_owned(),
+            _ => start.to_string(),
+        };
+        specifiers.push(specifier);
+    }
+    specifiers.join(",")
+}
+
+fn schedule(rng: &mut Rng) -> Schedule {
+    let expression = [
+        field(rng, 0, 59, &[]),
+        field(rng, 0, 59, &[]),
+        field(rng, 0, 23, &[]),
+        field(rng, 1, 31, &[]),
+        field(rng, 1, 12, &["Feb", "Jun", "Dec"]),
+        field(rng, 1, 7, &["Mon", "Sat", "Sun"]),
+        field(rng, 1970, 2100, &[]),
+    ]
+    .join(" ");
+    let schedule = Schedule::from_str(&expression)
+        .unwrap_or_else(|error| panic!("generated '{}': {}", expression, error));
+    if rng.below(4) == 0 {
+        schedule.with_unbounded_years()
+    } else {
+        schedule
+    }
+}
+
+fn instant(rng: &mut Rng, time_zone: &TimeZone) -> Zoned {
+    let (min, max) = (Timestamp::MIN.as_second(), Timestamp::MAX.as_second());
+    let second = match rng.below(4) {
+        // Anywhere jiff supports.
+        0 => min + rng.below((max - min) as u64) as i64,
+        // Close to the limits of what jiff supports.
+        1 => min + rng.below(86_400 * 3) as i64,
+        2 => max - rng.below(86_400 * 3) as i64,
+        // Within the years schedules cover.
+        _ => rng.between(0, 4_200_000_000) as i64,
+    };
+    let mut timestamp = Timestamp::from_second(second).unwrap();
+    // Move right next to a transition, from either side. Looking transitions
+    // up from thousands of years away is slow, so only do so from nearby.
+    if (0..4_200_000_000).contains(&second) && rng.below(2) == 0 {
+        if let Some(transition) = time_zone.following(timestamp).next() {
+            let nudge = SignedDuration::from_secs(rng.below(7_200) as i64 - 3_600);
+            timestamp = transition
+                .timestamp()
+                .checked_add(nudge)
+                .unwrap_or(timestamp);
+        }
+    }
+    timestamp.to_zoned(time_zone.clone())
+}
+
+#[test]
+fn test_fuzz_queries() {
+    let iterations = std::env::var("JIFF_CRON_FUZZ_ITERATIONS")
+        .ok()
+        .and_then(|iterations| iterations.parse().ok())
+        .unwrap_or(ITERATIONS);
+    let time_zones: Vec<_> = TIME_ZONES
+        .iter()
+        .map(|name| TimeZone::get(name).unwrap())
+        .collect();
+    for seed in 1..=iterations {
+        let mut rng = Rng(seed.wrapping_mul(0x9e37_79b9_7f4a_7c15));
+        let schedule = schedule(&mut rng);
+        let time_zone = &time_zones[rng.below(time_zones.len() as u64) as usize];
+        let start = instant(&mut rng, time_zone);
+        let context = format!("seed {}: '{}' from {}", seed, schedule, start);
+
+        let mut previous = start.clone();
+        for next in schedule.after(&start).take(3) {
+            assert!(next > previous, "{}: {} after {}", context, next, previous);
+            previous = next;
+        }
+        let mut previous = start.clone();
+        for prev in schedule.after(&start).rev().take(3) {
+            assert!(prev < previous, "{}: {} before {}", context, prev, previous);
+            previous = prev;
+        }
+        // Candidates are only passed over within a transition's shift, or
+        // when jiff can't represent them in the last days it supports.
+        let limit = rng.below(4) as usize;
+        let next = schedule.after(&start).next();
+        let prev = schedule.after(&start).next_back();
+        if let Ok(bounded) = schedule.try_next_after(&start, SearchBudget::candidates(limit)) {
+            assert_eq!(next, bounded, "{}: limit {}", context, limit);
+        }
+        if let Ok(bounded) = schedule.try_prev_before(&start, SearchBudget::candidates(limit)) {
+            assert_eq!(prev, bounded, "{}: limit {}", context, limit);
+        }
+        let limit = 200_000;
+        assert_eq!(
+            next,
+            schedule
+                .try_next_after(&start, SearchBudget::candidates(limit))
+                .unwrap(),
+            "{}",
+            context
+        );
+        assert_eq!(
+            prev,
+            schedule
+                .try_prev_before(&start, SearchBudget::candidates(limit))
+                .unwrap(),
+            "{}",
+            context
+        );
+
+        let n = rng.below(500) as usize;
+        let nth = schedule.after(&start).nth(n);
+        assert_eq!(
+            schedule
+                .after(&start)
+                .take(n + 1)
+                .last()
+                .filter(|_| nth.is_some()),
+            nth,
+            "{}: nth({})",
+            context,
+            n
+        );
+    }
+}
@@ -198,6 +198,42 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_weekday_and_weekend() {
+        let starting_date = date(2025, 1, 3)
+            .at(14, 29, 36, 0)
+            .to_zoned(TimeZone::UTC)
+            .unwrap();
+        let dates = |expression: &str| -> Vec<_> {
+            let schedule = Schedule::from_str(expression).unwrap();
+            schedule
+                .after(&starting_date)
+                .take(3)
+                .map(|event| event.datetime())
+                .collect()
+        };
+        assert_eq!(
+            vec![
+                date(2025, 1, 6).at(0, 0, 0, 0),
+                date(2025, 1, 7).at(0, 0, 0, 0),
+                date(2025, 1, 8).at(0, 0, 0, 0),
+            ],
+            dates("@weekday")
+        );
+        assert_eq!(
+            vec![
+                date(2025, 1, 4).at(0, 0, 0, 0),
+                date(2025, 1, 5).at(0, 0, 0, 0),
+                date(2025, 1, 11).at(0, 0, 0, 0),
+            ],
+            dates("@weekend")
+        );
+        assert_eq!(
+            Schedule::from_str("0 0 0 * * Sat,Sun").unwrap(),
+            Schedule::from_str("@weekend").unwrap()
+        );
+    }
+
     #[test]
     fn test_daily() {
         let expression = "@daily";
@@ -753,6 +789,42 @@ mod tests {
         }
     }
 
+    #[test]
+    fn test_period_values_offset() {
+        let schedule = Schedule::from_str("0 */15+5 9 * * ?").unwrap();
+        let dt = date(2020, 1, 1).at(0, 0, 0, 0).in_tz("UTC").unwrap();
+        let minutes: Vec<i8> = schedule.after(&dt).take(4).map(|dt| dt.minute()).collect();
+        assert_eq!(vec![5, 20, 35, 50], minutes);
+        assert_eq!(
+            Schedule::from_str("0 5/15 9 * * ?").unwrap(),
+            Schedule::from_str("0 */15+5 9 * * ?").unwrap()
+        );
+
+        // The offset counts from the start of the base, which is 1 for days.
+        let schedule = Schedule::from_str("0 0 0 */7+1 Feb ? 2021").unwrap();
+        let days: Vec<i8> = schedule.after(&dt).map(|dt| dt.day()).collect();
+        assert_eq!(vec![2, 9, 16, 23], days);
+
+        assert_eq!(
+            Schedule::from_str("0 0 0 ? * Tue,Thu").unwrap(),
+            Schedule::from_str("0 0 0 ? * Mon-Fri/2+1").unwrap()
+        );
+    }
+
+    #[test]
+    fn test_period_values_list() {
+        let schedule = Schedule::from_str("0 1-5/2,10,20-30/5 9 * * ?").unwrap();
+        let dt = date(2020, 1, 1).at(0, 0, 0, 0).in_tz("UTC").unwrap();
+        let minutes: Vec<i8> = schedule.after(&dt).take(7).map(|dt| dt.minute()).collect();
+        assert_eq!(vec![1, 3, 5, 10, 20, 25, 30], minutes);
+
+        let schedule = Schedule::from_str("0 0 0 ? * Mon-Fri/2,Sun").unwrap();
+        assert_eq!(
+            Schedule::from_str("0 0 0 ? * Sun,Mon,Wed,Fri").unwrap(),
+            schedule
+        );
+    }
+
     #[test]
     fn test_period_values_named_range() {
         let schedule = Schedule::from_str("0 0 0 1 January-April/2 ?").unwrap();
@@ -812,4 +884,111 @@ mod tests {
         assert!(schedule.includes(included));
         assert!(!schedule.includes(not_included));
     }
+
+    #[test]
+    fn test_into_iterator() {
+        let schedule = Schedule::from_str("0 0 9-11 * * Mon,Wed").unwrap();
+
+        let mut hours = Vec::new();
+        for hour in schedule.hours() {
+            hours.push(hour);
+        }
+        assert_eq!(vec![9, 10, 11], hours);
+
+        fn ordinals<'a, T>(field: &'a T) -> Vec<u32>
+        where
+            &'a T: IntoIterator<Item = u32>,
+        {
+            field.into_iter().collect()
+        }
+        assert_eq!(vec![2, 4], ordinals(schedule.days_of_week()));
+        assert_eq!(vec![0], ordinals(schedule.seconds()));
+    }
+
+    #[test]
+    fn test_containment() {
+        let policy = Schedule::from_str("* * 8-18 * * Mon-Fri").unwrap();
+        let schedule = Schedule::from_str("0 */15 9-17 * * Tue,Thu").unwrap();
+        assert!(schedule.hours().is_subset(policy.hours()));
+        assert!(schedule.days_of_week().is_subset(policy.days_of_week()));
+        assert!(policy.minutes().is_superset(schedule.minutes()));
+        assert!(!schedule.minutes().is_superset(policy.minutes()));
+        assert!(schedule.hours().contains_range(9..18));
+        assert!(!schedule.hours().contains_range(8..18));
+        assert!(schedule.days_of_month().contains_range(..));
+        assert!(!schedule.days_of_week().contains_range(..));
+        assert!(schedule.years().contains_range(2000..=3000));
+    }
+
+    #[test]
+    fn test_weekdays() {
+        use jiff::civil::Weekday;
+
+        let schedule = Schedule::from_str("0 0 0 * * Sun,Sat").unwrap();
+        assert!(schedule
+            .days_of_week()
+            .weekdays()
+            .eq([Weekday::Sunday, Weekday::Saturday]));
+
+        let weekend = jiff_cron::DaysOfWeek::from_weekdays([Weekday::Saturday, Weekday::Sunday]);
+        assert_eq!(schedule.days_of_week(), &weekend);
+        assert!(!weekend.includes_weekday(Weekday::Monday));
+    }
+
+    #[test]
+    fn test_civil_months() {
+        let schedule = Schedule::from_str("0 0 0 1 Jan,Dec *").unwrap();
+        assert!(schedule.months().civil_months().eq([1, 12]));
+        assert!(schedule.months().includes_civil_month(12));
+        assert!(!schedule.months().includes_civil_month(-1));
+
+        let months = jiff_cron::Months::from_civil_months([12, 1]).unwrap();
+        assert_eq!(schedule.months(), &months);
+        assert!(jiff_cron::Months::from_civil_months([0]).is_err());
+        assert!(jiff_cron::Months::from_civil_months([-3]).is_err());
+    }
+
+    #[test]
+    fn test_fields() {
+        let schedule = Schedule::from_str("0 30 9 * * Mon-Fri").unwrap();
+        let labels: Vec<_> = schedule
+            .fields()
+            .iter()
+            .map(|field| format!("{} {}-{}", field.unit_name(), field.min(), field.max()))
+            .collect();
+        assert_eq!(
+            vec![
+                "Seconds 0-59",
+                "Minutes 0-59",
+                "Hours 0-23",
+                "Days of Month 1-31",
+                "Months 1-12",
+                "Days of Week 1-7",
+                "Years 1970-2100",
+            ],
+            labels
+        );
+        let counts: Vec<_> = schedule
+            .fields()
+            .iter()
+            .map(|field| field.count())
+            .collect();
+        assert_eq!(vec![1, 1, 1, 31, 12, 5, 131], counts);
+    }
+
+    #[test]
+    fn test_seek_ordinals() {
+        let schedule = Schedule::from_str("0 0 0 1,15 * * 2020,2030").unwrap();
+        assert_eq!(Some(15), schedule.days_of_month().next_ordinal_after(1));
+        assert_eq!(None, schedule.days_of_month().next_ordinal_after(15));
+        assert_eq!(Some(1), schedule.days_of_month().prev_ordinal_before(15));
+        assert_eq!(Some(2030), schedule.years().next_ordinal_after(2025));
+        assert_eq!(Some(2020), schedule.years().prev_ordinal_before(2030));
+        assert_eq!(None, schedule.years().prev_ordinal_before(2020));
+        assert_eq!(
+            Some(0),
+            schedule.seconds().next_ordinal_after(0).or(Some(0))
+        );
+        assert_eq!(None, schedule.seconds().next_ordinal_after(u32::MAX));
+    }
 }